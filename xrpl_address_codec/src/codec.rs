@@ -0,0 +1,233 @@
+use crate::alloc::{format, string::String, vec::Vec};
+use crate::AddressCodecError;
+use sha2::{Digest, Sha256};
+
+/// Length of the checksum appended to the encoded data
+const CHECKSUM_LENGTH: usize = 4;
+
+/// Version prefix of account ids (addresses starting with `r`), see <https://xrpl.org/addresses.html#address-encoding>
+pub const ACCOUNT_ID_PREFIX: [u8; 1] = [0x00];
+
+/// Length of account ids <https://xrpl.org/addresses.html>
+pub const ACCOUNT_ID_LENGTH: usize = 20;
+
+/// Version prefix of node public keys (starting with `n`), as used for validator keys,
+/// see <https://xrpl.org/base58-encodings.html>
+pub const NODE_PUBLIC_KEY_PREFIX: [u8; 1] = [0x1C];
+
+/// Length of public keys in their 33 byte representation <https://xrpl.org/cryptographic-keys.html#public-key>
+pub const PUBLIC_KEY_LENGTH: usize = 33;
+
+/// Encodes the given payload with the version prefix and a checksum to base58 using the XRPL
+/// alphabet, see <https://xrpl.org/base58-encodings.html>
+pub fn encode_with_prefix(prefix: &[u8], payload: &[u8]) -> String {
+    let mut bytes = Vec::with_capacity(prefix.len() + payload.len() + CHECKSUM_LENGTH);
+    bytes.extend_from_slice(prefix);
+    bytes.extend_from_slice(payload);
+    let checksum = checksum(&bytes);
+    bytes.extend_from_slice(&checksum);
+    bs58::encode(bytes)
+        .with_alphabet(bs58::Alphabet::RIPPLE)
+        .into_string()
+}
+
+/// Decodes the given base58 string, verifies the checksum and the version prefix and returns the
+/// payload, see <https://xrpl.org/base58-encodings.html>
+pub fn decode_with_prefix(prefix: &[u8], encoded: &str) -> Result<Vec<u8>, AddressCodecError> {
+    let bytes = bs58::decode(encoded)
+        .with_alphabet(bs58::Alphabet::RIPPLE)
+        .into_vec()
+        .map_err(|err| AddressCodecError::InvalidEncoding(format!("{}", err)))?;
+
+    if bytes.len() < prefix.len() + CHECKSUM_LENGTH {
+        return Err(AddressCodecError::InvalidLength(format!(
+            "encoded data is only {} bytes",
+            bytes.len()
+        )));
+    }
+
+    let (data, expected_checksum) = bytes.split_at(bytes.len() - CHECKSUM_LENGTH);
+    let calculated_checksum = checksum(data);
+    if calculated_checksum != expected_checksum {
+        return Err(AddressCodecError::InvalidChecksum(format!(
+            "calculated checksum {:02X?}, expected checksum {:02X?}",
+            calculated_checksum, expected_checksum
+        )));
+    }
+
+    let (data_prefix, payload) = data.split_at(prefix.len());
+    if data_prefix != prefix {
+        return Err(AddressCodecError::InvalidPrefix(format!(
+            "found {:02X?}, expected {:02X?}",
+            data_prefix, prefix
+        )));
+    }
+
+    Ok(payload.to_vec())
+}
+
+/// Decodes the given base58 string like [`decode_with_prefix`] and additionally checks that the
+/// payload has length `N`
+pub fn decode_with_prefix_fixed<const N: usize>(
+    prefix: &[u8],
+    encoded: &str,
+) -> Result<[u8; N], AddressCodecError> {
+    let payload = decode_with_prefix(prefix, encoded)?;
+    payload.as_slice().try_into().map_err(|_| {
+        AddressCodecError::InvalidLength(format!(
+            "payload is {} bytes, expected {} bytes",
+            payload.len(),
+            N
+        ))
+    })
+}
+
+/// Encodes account id to (classic) address, see <https://xrpl.org/addresses.html#address-encoding>
+pub fn encode_account_id(account_id: &[u8; ACCOUNT_ID_LENGTH]) -> String {
+    encode_with_prefix(&ACCOUNT_ID_PREFIX, account_id)
+}
+
+/// Decodes account id from (classic) address, see <https://xrpl.org/addresses.html#address-encoding>
+pub fn decode_account_id(address: &str) -> Result<[u8; ACCOUNT_ID_LENGTH], AddressCodecError> {
+    decode_with_prefix_fixed(&ACCOUNT_ID_PREFIX, address)
+}
+
+/// Encodes node public key, e.g. the master or ephemeral key of a validator, in the format
+/// used in validator lists and the `manifest` method, see <https://xrpl.org/base58-encodings.html>
+pub fn encode_node_public_key(public_key: &[u8; PUBLIC_KEY_LENGTH]) -> String {
+    encode_with_prefix(&NODE_PUBLIC_KEY_PREFIX, public_key)
+}
+
+/// Decodes node public key, see [`encode_node_public_key`]
+pub fn decode_node_public_key(
+    node_public_key: &str,
+) -> Result<[u8; PUBLIC_KEY_LENGTH], AddressCodecError> {
+    decode_with_prefix_fixed(&NODE_PUBLIC_KEY_PREFIX, node_public_key)
+}
+
+/// Checks if the given string is a valid (classic) address
+pub fn is_valid_classic_address(address: &str) -> bool {
+    decode_account_id(address).is_ok()
+}
+
+/// First 4 bytes of double SHA-256, see <https://xrpl.org/addresses.html#address-encoding>
+fn checksum(data: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+    let hash = Sha256::digest(Sha256::digest(data));
+    hash[..CHECKSUM_LENGTH].try_into().expect("length 4")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn test_encode_account_id() {
+        let account_id = hex::decode("4b4e9c06f24296074f7bc48f92a97916c6dc5ea9")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            encode_account_id(&account_id),
+            "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"
+        );
+    }
+
+    #[test]
+    fn test_decode_account_id() {
+        let account_id = decode_account_id("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap();
+        assert_eq!(
+            hex::encode(account_id),
+            "4b4e9c06f24296074f7bc48f92a97916c6dc5ea9"
+        );
+    }
+
+    /// The account zero, see <https://xrpl.org/addresses.html#special-addresses>
+    #[test]
+    fn test_account_zero() {
+        assert_eq!(encode_account_id(&[0u8; 20]), "rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+        assert_eq!(
+            decode_account_id("rrrrrrrrrrrrrrrrrrrrrhoLvTp").unwrap(),
+            [0u8; 20]
+        );
+    }
+
+    #[test]
+    fn test_decode_account_id_invalid_checksum() {
+        let result = decode_account_id("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpm");
+        assert_matches!(result, Err(AddressCodecError::InvalidChecksum(_)));
+    }
+
+    #[test]
+    fn test_decode_account_id_invalid_prefix() {
+        let result = decode_account_id("XU8q4Ao1L1ggD6CAn9iA4oDoQZ7mXntZy");
+        assert_matches!(result, Err(AddressCodecError::InvalidPrefix(_)));
+    }
+
+    #[test]
+    fn test_decode_account_id_invalid_length() {
+        let result = decode_account_id("r3wVnsK");
+        assert_matches!(result, Err(AddressCodecError::InvalidLength(message)) => {
+            assert!(message.contains("payload is 1 bytes, expected 20 bytes"), "message: {message}")
+        });
+    }
+
+    #[test]
+    fn test_decode_account_id_too_short() {
+        let result = decode_account_id("rrr");
+        assert_matches!(result, Err(AddressCodecError::InvalidLength(_)));
+    }
+
+    /// The characters `0`, `O`, `I` and `l` are not part of the alphabet
+    #[test]
+    fn test_decode_account_id_invalid_character() {
+        let result = decode_account_id("r0f1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn");
+        assert_matches!(result, Err(AddressCodecError::InvalidEncoding(_)));
+    }
+
+    #[test]
+    fn test_encode_decode_with_prefix() {
+        let encoded = encode_with_prefix(&[0x01, 0xE1, 0x4B], &[0xAB; 16]);
+        assert!(encoded.starts_with("sEd"), "encoded: {encoded}");
+        assert_eq!(
+            decode_with_prefix(&[0x01, 0xE1, 0x4B], &encoded).unwrap(),
+            [0xAB; 16]
+        );
+        assert_matches!(
+            decode_with_prefix(&[0x21], &encoded),
+            Err(AddressCodecError::InvalidPrefix(_))
+        );
+    }
+
+    #[test]
+    fn test_encode_decode_node_public_key() {
+        let public_key =
+            hex::decode("EDE45B6D2E28772360E17018C319F19AF0A6C23CB2098B077647B3B52FB96CCC67")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let encoded = encode_node_public_key(&public_key);
+        assert_eq!(
+            encoded,
+            "nHDarQ7y9ntDeejBwyTHyArF1dHhFFqznibcXuz8ofgv3ttCKRPC"
+        );
+        assert_eq!(decode_node_public_key(&encoded).unwrap(), public_key);
+        assert_matches!(
+            decode_node_public_key("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"),
+            Err(AddressCodecError::InvalidPrefix(_))
+        );
+    }
+
+    #[test]
+    fn test_is_valid_classic_address() {
+        assert!(is_valid_classic_address(
+            "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"
+        ));
+        assert!(!is_valid_classic_address(
+            "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpm"
+        ));
+        assert!(!is_valid_classic_address(
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV18pX8yuPT7y4xaEHi"
+        ));
+    }
+}
@@ -0,0 +1,33 @@
+use crate::alloc::string::String;
+use core::fmt;
+
+/// Result type for address codec operations.
+pub type Result<T, E = AddressCodecError> = core::result::Result<T, E>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AddressCodecError {
+    InvalidEncoding(String),
+    InvalidChecksum(String),
+    InvalidPrefix(String),
+    InvalidLength(String),
+    InvalidTag(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AddressCodecError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+impl fmt::Display for AddressCodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidEncoding(s) => write!(f, "invalid base58 encoding: {}", s),
+            Self::InvalidChecksum(s) => write!(f, "invalid checksum: {}", s),
+            Self::InvalidPrefix(s) => write!(f, "invalid version prefix: {}", s),
+            Self::InvalidLength(s) => write!(f, "invalid payload length: {}", s),
+            Self::InvalidTag(s) => write!(f, "invalid tag: {}", s),
+        }
+    }
+}
@@ -1,3 +1,16 @@
-//! Serialization for XRPL Protocol addresses.
+//! Serialization for XRPL Protocol addresses. Implements the base58 encoding with
+//! version prefixes and checksums described at <https://xrpl.org/addresses.html#address-encoding>.
 
-// #TODO
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+extern crate core;
+
+mod codec;
+mod error;
+mod seed;
+mod x_address;
+
+pub use codec::*;
+pub use error::*;
+pub use seed::*;
+pub use x_address::*;
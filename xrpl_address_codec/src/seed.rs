@@ -0,0 +1,94 @@
+use crate::alloc::string::String;
+use crate::{decode_with_prefix_fixed, encode_with_prefix, AddressCodecError};
+
+/// Version prefix of secp256k1 family seeds (starting with `s`), see <https://xrpl.org/base58-encodings.html>
+pub const FAMILY_SEED_PREFIX: [u8; 1] = [0x21];
+
+/// Version prefix of ed25519 seeds (starting with `sEd`), see <https://xrpl.org/cryptographic-keys.html#key-derivation>
+pub const ED25519_SEED_PREFIX: [u8; 3] = [0x01, 0xE1, 0x4B];
+
+/// Length of seeds <https://xrpl.org/cryptographic-keys.html#seed>
+pub const SEED_LENGTH: usize = 16;
+
+/// Signing algorithm <https://xrpl.org/cryptographic-keys.html#signing-algorithms>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Algorithm {
+    Secp256k1,
+    Ed25519,
+}
+
+/// Encodes seed for the given algorithm, see <https://xrpl.org/cryptographic-keys.html#seed>
+pub fn encode_seed(seed: &[u8; SEED_LENGTH], algorithm: Algorithm) -> String {
+    match algorithm {
+        Algorithm::Secp256k1 => encode_with_prefix(&FAMILY_SEED_PREFIX, seed),
+        Algorithm::Ed25519 => encode_with_prefix(&ED25519_SEED_PREFIX, seed),
+    }
+}
+
+/// Decodes seed and the algorithm it is used with, see <https://xrpl.org/cryptographic-keys.html#seed>
+pub fn decode_seed(seed: &str) -> Result<([u8; SEED_LENGTH], Algorithm), AddressCodecError> {
+    match decode_with_prefix_fixed(&ED25519_SEED_PREFIX, seed) {
+        Ok(bytes) => Ok((bytes, Algorithm::Ed25519)),
+        Err(AddressCodecError::InvalidPrefix(_)) => Ok((
+            decode_with_prefix_fixed(&FAMILY_SEED_PREFIX, seed)?,
+            Algorithm::Secp256k1,
+        )),
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn test_encode_seed_secp256k1() {
+        let seed = hex::decode("CF2DE378FBDD7E2EE87D486DFB5A7BFF")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            encode_seed(&seed, Algorithm::Secp256k1),
+            "sn259rEFXrQrWyx3Q7XneWcwV6dfL"
+        );
+    }
+
+    #[test]
+    fn test_encode_seed_ed25519() {
+        let seed = hex::decode("4C3A1D213FBDFB14C7C28D609469B341")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            encode_seed(&seed, Algorithm::Ed25519),
+            "sEdTM1uX8pu2do5XvTnutH6HsouMaM2"
+        );
+    }
+
+    #[test]
+    fn test_decode_seed_secp256k1() {
+        let (seed, algorithm) = decode_seed("sn259rEFXrQrWyx3Q7XneWcwV6dfL").unwrap();
+        assert_eq!(hex::encode_upper(seed), "CF2DE378FBDD7E2EE87D486DFB5A7BFF");
+        assert_eq!(algorithm, Algorithm::Secp256k1);
+    }
+
+    #[test]
+    fn test_decode_seed_ed25519() {
+        let (seed, algorithm) = decode_seed("sEdTM1uX8pu2do5XvTnutH6HsouMaM2").unwrap();
+        assert_eq!(hex::encode_upper(seed), "4C3A1D213FBDFB14C7C28D609469B341");
+        assert_eq!(algorithm, Algorithm::Ed25519);
+    }
+
+    #[test]
+    fn test_decode_seed_invalid_prefix() {
+        let result = decode_seed("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn");
+        assert_matches!(result, Err(AddressCodecError::InvalidPrefix(_)));
+    }
+
+    #[test]
+    fn test_decode_seed_invalid_checksum() {
+        let result = decode_seed("sn259rEFXrQrWyx3Q7XneWcwV6dfM");
+        assert_matches!(result, Err(AddressCodecError::InvalidChecksum(_)));
+    }
+}
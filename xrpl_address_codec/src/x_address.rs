@@ -0,0 +1,207 @@
+use crate::alloc::{format, string::String};
+use crate::{decode_with_prefix, encode_with_prefix, AddressCodecError, ACCOUNT_ID_LENGTH};
+
+/// Version prefix of mainnet X-addresses (starting with `X`), see <https://github.com/XRPLF/XRPL-Standards/tree/master/XLS-0005-tagged-addresses>
+pub const X_ADDRESS_MAINNET_PREFIX: [u8; 2] = [0x05, 0x44];
+
+/// Version prefix of testnet X-addresses (starting with `T`), see <https://github.com/XRPLF/XRPL-Standards/tree/master/XLS-0005-tagged-addresses>
+pub const X_ADDRESS_TESTNET_PREFIX: [u8; 2] = [0x04, 0x93];
+
+/// Length of the X-address payload: account id, tag flag and 64 bit tag
+const X_ADDRESS_PAYLOAD_LENGTH: usize = ACCOUNT_ID_LENGTH + 1 + 8;
+
+/// Account id and optional tag encoded in an X-address, see <https://xrpl.org/addresses.html#x-addresses>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XAddress {
+    pub account_id: [u8; ACCOUNT_ID_LENGTH],
+    pub tag: Option<u32>,
+    pub is_test_network: bool,
+}
+
+/// Encodes account id and optional tag to an X-address, see <https://github.com/XRPLF/XRPL-Standards/tree/master/XLS-0005-tagged-addresses>
+pub fn encode_x_address(
+    account_id: &[u8; ACCOUNT_ID_LENGTH],
+    tag: Option<u32>,
+    is_test_network: bool,
+) -> String {
+    let mut payload = [0u8; X_ADDRESS_PAYLOAD_LENGTH];
+    payload[..ACCOUNT_ID_LENGTH].copy_from_slice(account_id);
+    if let Some(tag) = tag {
+        payload[ACCOUNT_ID_LENGTH] = 1;
+        payload[ACCOUNT_ID_LENGTH + 1..ACCOUNT_ID_LENGTH + 5].copy_from_slice(&tag.to_le_bytes());
+    }
+    let prefix = if is_test_network {
+        X_ADDRESS_TESTNET_PREFIX
+    } else {
+        X_ADDRESS_MAINNET_PREFIX
+    };
+    encode_with_prefix(&prefix, &payload)
+}
+
+/// Decodes an X-address into account id, optional tag and network flag, see <https://github.com/XRPLF/XRPL-Standards/tree/master/XLS-0005-tagged-addresses>
+pub fn decode_x_address(x_address: &str) -> Result<XAddress, AddressCodecError> {
+    let (payload, is_test_network) = match decode_with_prefix(&X_ADDRESS_MAINNET_PREFIX, x_address)
+    {
+        Ok(payload) => (payload, false),
+        Err(AddressCodecError::InvalidPrefix(_)) => (
+            decode_with_prefix(&X_ADDRESS_TESTNET_PREFIX, x_address)?,
+            true,
+        ),
+        Err(err) => return Err(err),
+    };
+
+    let payload: [u8; X_ADDRESS_PAYLOAD_LENGTH] = payload.as_slice().try_into().map_err(|_| {
+        AddressCodecError::InvalidLength(format!(
+            "payload is {} bytes, expected {} bytes",
+            payload.len(),
+            X_ADDRESS_PAYLOAD_LENGTH
+        ))
+    })?;
+
+    let account_id = payload[..ACCOUNT_ID_LENGTH].try_into().expect("length 20");
+    let tag_bytes = &payload[ACCOUNT_ID_LENGTH + 1..];
+    let tag = u64::from_le_bytes(tag_bytes.try_into().expect("length 8"));
+
+    // 64 bit tags are reserved for future use and not supported by the ledger
+    let tag = match payload[ACCOUNT_ID_LENGTH] {
+        0 if tag == 0 => None,
+        0 => {
+            return Err(AddressCodecError::InvalidTag(
+                "tag bytes must be zero when no tag is present".into(),
+            ))
+        }
+        1 => Some(u32::try_from(tag).map_err(|_| {
+            AddressCodecError::InvalidTag(format!("64 bit tags are not supported: {}", tag))
+        })?),
+        flag => {
+            return Err(AddressCodecError::InvalidTag(format!(
+                "unknown tag flag: {}",
+                flag
+            )))
+        }
+    };
+
+    Ok(XAddress {
+        account_id,
+        tag,
+        is_test_network,
+    })
+}
+
+/// Checks if the given string is a valid X-address
+pub fn is_valid_x_address(x_address: &str) -> bool {
+    decode_x_address(x_address).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode_account_id, encode_account_id};
+    use assert_matches::assert_matches;
+
+    /// Test vectors for `rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf`, see <https://xrpl.org/addresses.html#x-addresses>
+    const X_ADDRESS_VECTORS: [(Option<u32>, bool, &str); 6] = [
+        (
+            None,
+            false,
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb",
+        ),
+        (
+            None,
+            true,
+            "TVE26TYGhfLC7tQDno7G8dGtxSkYQn49b3qD26PK7FcGSKE",
+        ),
+        (
+            Some(1),
+            false,
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC",
+        ),
+        (
+            Some(1),
+            true,
+            "TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw",
+        ),
+        (
+            Some(u32::MAX),
+            false,
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV18pX8yuPT7y4xaEHi",
+        ),
+        (
+            Some(u32::MAX),
+            true,
+            "TVE26TYGhfLC7tQDno7G8dGtxSkYQnXoy6kSDh6rZzApc69",
+        ),
+    ];
+
+    fn account_id() -> [u8; ACCOUNT_ID_LENGTH] {
+        decode_account_id("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf").unwrap()
+    }
+
+    #[test]
+    fn test_encode_x_address() {
+        for (tag, is_test_network, x_address) in X_ADDRESS_VECTORS {
+            assert_eq!(
+                encode_x_address(&account_id(), tag, is_test_network),
+                x_address
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_x_address() {
+        for (tag, is_test_network, x_address) in X_ADDRESS_VECTORS {
+            let decoded = decode_x_address(x_address).unwrap();
+            assert_eq!(
+                encode_account_id(&decoded.account_id),
+                "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf"
+            );
+            assert_eq!(decoded.tag, tag);
+            assert_eq!(decoded.is_test_network, is_test_network);
+        }
+    }
+
+    #[test]
+    fn test_decode_x_address_classic_address() {
+        let result = decode_x_address("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf");
+        assert_matches!(result, Err(AddressCodecError::InvalidPrefix(_)));
+    }
+
+    #[test]
+    fn test_decode_x_address_invalid_checksum() {
+        let result = decode_x_address("XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXc");
+        assert_matches!(result, Err(AddressCodecError::InvalidChecksum(_)));
+    }
+
+    /// X-address with unknown tag flag 2
+    #[test]
+    fn test_decode_x_address_invalid_flag() {
+        let result = decode_x_address("XVLhHMPHU98es4dbozjVtdWzVrDjtV1o3rjp93oom1hDqo1");
+        assert_matches!(result, Err(AddressCodecError::InvalidTag(message)) => {
+            assert!(message.contains("unknown tag flag"), "message: {message}")
+        });
+    }
+
+    /// X-address with a tag that does not fit in 32 bits
+    #[test]
+    fn test_decode_x_address_64_bit_tag() {
+        let result = decode_x_address("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAzDqKP5");
+        assert_matches!(result, Err(AddressCodecError::InvalidTag(message)) => {
+            assert!(message.contains("64 bit tags are not supported"), "message: {message}")
+        });
+    }
+
+    /// X-address with tag flag not set but non-zero tag bytes
+    #[test]
+    fn test_decode_x_address_tag_without_flag() {
+        let result = decode_x_address("XVLhHMPHU98es4dbozjVtdWzVrDjtV53jSo8mAyvfybtDtz");
+        assert_matches!(result, Err(AddressCodecError::InvalidTag(_)));
+    }
+
+    #[test]
+    fn test_is_valid_x_address() {
+        assert!(is_valid_x_address(
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV18pX8yuPT7y4xaEHi"
+        ));
+        assert!(!is_valid_x_address("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf"));
+    }
+}
@@ -48,9 +48,45 @@ pub struct TxResponse {
     pub tx: Transaction,
 }
 
+/// A `tx` request for the binary representation of the transaction, i.e. with `"binary": true`
+#[derive(Debug, Clone, Serialize)]
+pub struct TxBinaryRequest(TxRequest);
+
+impl Request for TxBinaryRequest {
+    type Response = TxBinaryResponse;
+
+    fn method(&self) -> String {
+        "tx".to_owned()
+    }
+}
+
+impl TxBinaryRequest {
+    pub fn new(transaction: &str) -> Self {
+        Self(TxRequest::new(transaction).binary(true))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TxBinaryResponse {
+    /// The transaction, as hexadecimal.
+    #[serde(rename = "tx")]
+    pub tx_blob: String,
+    /// The transaction metadata, as hexadecimal. Omitted if the transaction is not in a closed
+    /// ledger.
+    #[serde(rename = "meta")]
+    pub meta_blob: Option<String>,
+    /// The identifying hash of the transaction.
+    pub hash: String,
+    /// The ledger index of the ledger that includes this transaction.
+    pub ledger_index: Option<u32>,
+    /// If true, this data comes from a validated ledger version.
+    pub validated: Option<bool>,
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use assert_matches::assert_matches;
 
     #[test]
     fn test_deserialize_tx_response() {
@@ -171,6 +207,67 @@ mod test {
 }
 "#;
 
-        let _tx_response: TxResponse = serde_json::from_str(json).unwrap();
+        let tx_response: TxResponse = serde_json::from_str(json).unwrap();
+        assert_matches!(tx_response.tx, Transaction::OfferCreate(_));
+
+        let result: Result<TxBinaryResponse, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_deserialize_tx_response_unknown_transaction_type() {
+        let json = r#"
+{
+    "Account": "rhhh49pFH96roGyuC4E5P4CHaNjS1k8gzM",
+    "Fee": "12",
+    "Sequence": 5037710,
+    "TransactionType": "AMMDeposit",
+    "hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9"
+}
+"#;
+
+        let result: Result<TxResponse, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_deserialize_tx_binary_response() {
+        let json = r#"
+{
+    "date": 648248020,
+    "hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9",
+    "inLedger": 56865245,
+    "ledger_index": 56865245,
+    "meta": "201C00000000F8E5110061E72200000000E1E1F1031000",
+    "tx": "120007220000000024004CDE8E201900",
+    "validated": true
+}
+"#;
+
+        let binary: TxBinaryResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(binary.tx_blob, "120007220000000024004CDE8E201900");
+        assert_eq!(
+            binary.meta_blob.as_deref(),
+            Some("201C00000000F8E5110061E72200000000E1E1F1031000")
+        );
+        assert_eq!(binary.ledger_index, Some(56865245));
+        assert_eq!(binary.validated, Some(true));
+
+        let result: Result<TxResponse, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_serialize_tx_binary_request() {
+        let request = TxBinaryRequest::new(
+            "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9",
+        );
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "transaction": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9",
+                "binary": true
+            })
+        );
     }
 }
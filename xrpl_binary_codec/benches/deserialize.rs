@@ -0,0 +1,168 @@
+//! Decoding a ledger's worth of binary transactions with the owned deserializer, which copies
+//! variable length values and looks up field names, compared to the borrowed deserializer.
+//! Run with `cargo bench -p xrpl_binary_codec`
+
+use ascii::AsciiChar;
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use xrpl_binary_codec::deserialize::deserialize;
+use xrpl_binary_codec::deserializer::borrowed::{
+    ArrayDeserializer, Deserializer, FieldAccessor, FieldId, TypeCode, Visitor,
+};
+use xrpl_binary_codec::serialize::serialize;
+use xrpl_binary_codec::BinaryCodecError;
+use xrpl_types::{
+    AccountId, AccountSetTransaction, Amount, Blob, CurrencyCode, DropsAmount, IssuedValue, Memo,
+    Object, OfferCreateTransaction, PaymentTransaction, Transaction, TransactionCommon,
+};
+
+const TRANSACTION_COUNT: usize = 300;
+
+fn set_common(common: &mut TransactionCommon, sequence: u32) {
+    common.fee = Some(DropsAmount::from_drops(12).unwrap());
+    common.sequence = Some(sequence);
+    common.last_ledger_sequence = Some(80_000_000);
+    common.signing_pub_key = Some(Blob(vec![0x02; 33]));
+    common.txn_signature = Some(Blob(vec![0x30; 71]));
+}
+
+/// Mix of payments, offers and account settings with memos, serialized
+fn transactions() -> Vec<Vec<u8>> {
+    let account = AccountId::from_address("rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys").unwrap();
+    let destination = AccountId::from_address("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe").unwrap();
+    let issuer = AccountId::from_address("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B").unwrap();
+    let usd = CurrencyCode::standard([AsciiChar::U, AsciiChar::S, AsciiChar::D]).unwrap();
+    let usd_amount = Amount::issued(
+        IssuedValue::from_mantissa_exponent(12345, -2).unwrap(),
+        usd,
+        issuer,
+    )
+    .unwrap();
+
+    (0..TRANSACTION_COUNT as u32)
+        .map(|sequence| match sequence % 3 {
+            0 => {
+                let mut txn = PaymentTransaction::new(
+                    account,
+                    Amount::drops(1_000_000).unwrap(),
+                    destination,
+                );
+                set_common(&mut txn.common, sequence);
+                txn.destination_tag = Some(sequence);
+                serialize(&txn).unwrap()
+            }
+            1 => {
+                let mut txn = OfferCreateTransaction::new(
+                    account,
+                    Amount::drops(5_000_000).unwrap(),
+                    usd_amount,
+                );
+                set_common(&mut txn.common, sequence);
+                serialize(&txn).unwrap()
+            }
+            _ => {
+                let mut txn = AccountSetTransaction::new(account);
+                set_common(&mut txn.common, sequence);
+                txn.common.memos.push(Memo {
+                    memo_type: Some(Blob(b"text/plain".to_vec())),
+                    memo_data: Some(Blob(vec![0x42; 128])),
+                    memo_format: None,
+                    unknown_fields: Object::default(),
+                });
+                serialize(&txn).unwrap()
+            }
+        })
+        .collect()
+}
+
+/// Reads every field with the accessor for its type, borrowing blobs, and sums the number of fields and blob bytes
+#[derive(Default)]
+struct SumVisitor {
+    fields: usize,
+    blob_bytes: usize,
+}
+
+impl<'a> Visitor<'a> for SumVisitor {
+    fn visit_field(
+        &mut self,
+        _field_id: FieldId,
+        field_accessor: FieldAccessor<'a>,
+    ) -> Result<(), BinaryCodecError> {
+        self.fields += 1;
+        match field_accessor.type_code() {
+            TypeCode::Blob => {
+                self.blob_bytes += field_accessor.deserialize_blob()?.as_bytes().len();
+            }
+            TypeCode::AccountId => {
+                black_box(field_accessor.deserialize_account_id()?);
+            }
+            TypeCode::Amount => {
+                black_box(field_accessor.deserialize_amount()?);
+            }
+            TypeCode::UInt16 => {
+                black_box(field_accessor.deserialize_uint16()?);
+            }
+            TypeCode::UInt32 => {
+                black_box(field_accessor.deserialize_uint32()?);
+            }
+            _ => {
+                black_box(field_accessor.deserialize_any()?);
+            }
+        }
+        Ok(())
+    }
+
+    fn visit_object(
+        &mut self,
+        _field_id: FieldId,
+        deserializer: &mut Deserializer<'a>,
+    ) -> Result<(), BinaryCodecError> {
+        deserializer.deserialize(self)
+    }
+
+    fn visit_array(
+        &mut self,
+        _field_id: FieldId,
+        array_deserializer: &mut ArrayDeserializer<'a>,
+    ) -> Result<(), BinaryCodecError> {
+        while let Some((_field_id, deserializer)) = array_deserializer.next_object()? {
+            deserializer.deserialize(self)?;
+        }
+        Ok(())
+    }
+}
+
+fn bench_deserialize(c: &mut Criterion) {
+    let transactions = transactions();
+
+    let mut group = c.benchmark_group("deserialize_transactions");
+    group.throughput(Throughput::Elements(transactions.len() as u64));
+    group.bench_function("owned_transaction", |b| {
+        b.iter(|| {
+            for bytes in &transactions {
+                black_box(deserialize::<Transaction>(black_box(bytes)).unwrap());
+            }
+        })
+    });
+    group.bench_function("owned_object", |b| {
+        b.iter(|| {
+            for bytes in &transactions {
+                black_box(deserialize::<Object>(black_box(bytes)).unwrap());
+            }
+        })
+    });
+    group.bench_function("borrowed_visitor", |b| {
+        b.iter(|| {
+            for bytes in &transactions {
+                let mut visitor = SumVisitor::default();
+                Deserializer::new(black_box(bytes))
+                    .deserialize(&mut visitor)
+                    .unwrap();
+                black_box((visitor.fields, visitor.blob_bytes));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_deserialize);
+criterion_main!(benches);
@@ -0,0 +1,67 @@
+//! Serializing signed offers with the sorting serializer, which buffers and sorts the fields,
+//! compared to the single-pass canonical serializer writing directly to a reused buffer.
+//! Run with `cargo bench -p xrpl_binary_codec`
+
+use ascii::AsciiChar;
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use xrpl_binary_codec::serialize::{serialize, serialize_into};
+use xrpl_types::{
+    AccountId, Amount, Blob, CurrencyCode, DropsAmount, IssuedValue, OfferCreateTransaction,
+};
+
+const TRANSACTION_COUNT: usize = 300;
+
+fn offers() -> Vec<OfferCreateTransaction> {
+    let account = AccountId::from_address("rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys").unwrap();
+    let issuer = AccountId::from_address("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B").unwrap();
+    let usd = CurrencyCode::standard([AsciiChar::U, AsciiChar::S, AsciiChar::D]).unwrap();
+
+    (0..TRANSACTION_COUNT as u32)
+        .map(|sequence| {
+            let mut txn = OfferCreateTransaction::new(
+                account,
+                Amount::drops(5_000_000 + sequence as u64).unwrap(),
+                Amount::issued(
+                    IssuedValue::from_mantissa_exponent(12345 + sequence as i64, -2).unwrap(),
+                    usd,
+                    issuer,
+                )
+                .unwrap(),
+            );
+            txn.common.fee = Some(DropsAmount::from_drops(12).unwrap());
+            txn.common.sequence = Some(sequence);
+            txn.common.last_ledger_sequence = Some(80_000_000);
+            txn.common.signing_pub_key = Some(Blob(vec![0x02; 33]));
+            txn.common.txn_signature = Some(Blob(vec![0x30; 71]));
+            txn
+        })
+        .collect()
+}
+
+fn bench_serialize(c: &mut Criterion) {
+    let offers = offers();
+
+    let mut group = c.benchmark_group("serialize_offers");
+    group.throughput(Throughput::Elements(offers.len() as u64));
+    group.bench_function("sorting", |b| {
+        b.iter(|| {
+            for offer in &offers {
+                black_box(serialize(black_box(offer)).unwrap());
+            }
+        })
+    });
+    group.bench_function("canonical_into", |b| {
+        let mut buffer = Vec::with_capacity(512);
+        b.iter(|| {
+            for offer in &offers {
+                buffer.clear();
+                serialize_into(black_box(offer), &mut buffer).unwrap();
+                black_box(&buffer);
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_serialize);
+criterion_main!(benches);
@@ -0,0 +1,657 @@
+use crate::alloc::collections::BTreeMap;
+use crate::alloc::format;
+use crate::alloc::string::{String, ToString};
+use crate::alloc::vec::Vec;
+use crate::error::BinaryCodecError;
+use crate::field::{field_info, FieldCode, FieldId, TypeCode};
+use hashbrown::HashMap;
+
+/// Definitions of fields, transaction types, ledger entry types and transaction results used by the codec.
+/// The built-in definitions are used by default. Definitions for other networks, or definitions including fields
+/// added by new amendments, can be parsed from a `definitions.json`
+/// <https://github.com/XRPLF/xrpl.js/blob/main/packages/ripple-binary-codec/src/enums/definitions.json>
+/// or from the result of the `server_definitions` method.
+///
+/// Only field names and field codes are configurable for the typed models in `xrpl_types`: the
+/// definitions determine the fields that can be serialized and deserialized, and their field ids.
+/// Transaction types, ledger entry types and transaction results are only used by the
+/// [`json`](crate::json) codec, to convert between names and codes. Typed models dispatch on the
+/// fixed `TransactionType` and `LedgerEntryType` enums, and decode types missing from them as their
+/// `Unknown` variant. Type codes are the fixed [`TypeCode`] enum, and fields of other types are
+/// left out of the definitions.
+#[derive(Debug, Clone)]
+pub struct Definitions {
+    field_ids: HashMap<String, FieldId>,
+    field_names: HashMap<FieldId, String>,
+    transaction_types: HashMap<String, u16>,
+    transaction_type_names: HashMap<u16, String>,
+    ledger_entry_types: HashMap<String, u16>,
+    ledger_entry_type_names: HashMap<u16, String>,
+    transaction_results: HashMap<String, i32>,
+    transaction_result_names: HashMap<i32, String>,
+}
+
+#[cfg(feature = "std")]
+static BUILTIN_DEFINITIONS: std::sync::OnceLock<Definitions> = std::sync::OnceLock::new();
+
+#[cfg(not(feature = "std"))]
+static BUILTIN_DEFINITIONS: spin::Once<Definitions> = spin::Once::new();
+
+impl Default for Definitions {
+    fn default() -> Self {
+        Self::new(
+            field_info::create_field_name_to_field_id_map(),
+            to_map(TRANSACTION_TYPES),
+            to_map(LEDGER_ENTRY_TYPES),
+            to_map(TRANSACTION_RESULTS),
+        )
+        .expect("built-in definitions have no duplicate codes")
+    }
+}
+
+impl Definitions {
+    /// Fails if two names have the same field id or code
+    fn new(
+        field_ids: HashMap<String, FieldId>,
+        transaction_types: HashMap<String, u16>,
+        ledger_entry_types: HashMap<String, u16>,
+        transaction_results: HashMap<String, i32>,
+    ) -> Result<Self, BinaryCodecError> {
+        Ok(Self {
+            field_names: reverse_map(&field_ids, "Field id")?,
+            field_ids,
+            transaction_type_names: reverse_map(&transaction_types, "Transaction type code")?,
+            transaction_types,
+            ledger_entry_type_names: reverse_map(&ledger_entry_types, "Ledger entry type code")?,
+            ledger_entry_types,
+            transaction_result_names: reverse_map(&transaction_results, "Transaction result code")?,
+            transaction_results,
+        })
+    }
+
+    /// Built-in definitions, used by default
+    #[cfg(feature = "std")]
+    pub fn builtin() -> &'static Definitions {
+        BUILTIN_DEFINITIONS.get_or_init(Definitions::default)
+    }
+
+    /// Built-in definitions, used by default
+    #[cfg(not(feature = "std"))]
+    pub fn builtin() -> &'static Definitions {
+        BUILTIN_DEFINITIONS.call_once(Definitions::default)
+    }
+
+    /// Parses definitions in the format of `definitions.json`. The result of the `server_definitions`
+    /// method is accepted too, also when wrapped in a JSON-RPC response object.
+    /// Fields that are not serialized, or that have a type not supported by the codec, are left out.
+    /// Fails if a field name, field id, transaction type code, ledger entry type code or transaction
+    /// result code is defined twice.
+    pub fn from_json(json: &str) -> Result<Self, BinaryCodecError> {
+        let mut value: serde_json::Value = serde_json::from_str(json)
+            .map_err(|err| BinaryCodecError::InvalidDefinitions(err.to_string()))?;
+        if let Some(result) = value.get_mut("result") {
+            value = result.take();
+        }
+        let json: DefinitionsJson = serde_json::from_value(value)
+            .map_err(|err| BinaryCodecError::InvalidDefinitions(err.to_string()))?;
+
+        let mut field_ids = HashMap::new();
+        for (field_name, field) in json.fields {
+            if !field.is_serialized {
+                continue;
+            }
+            let type_code = json
+                .types
+                .get(&field.type_name)
+                .and_then(|code| u8::try_from(*code).ok())
+                .and_then(TypeCode::from_discriminant_opt);
+            let (Some(type_code), Ok(field_code)) = (type_code, u8::try_from(field.nth)) else {
+                continue;
+            };
+            let field_id = FieldId::from_type_field(type_code, FieldCode(field_code));
+            if field_ids.insert(field_name.clone(), field_id).is_some() {
+                return Err(BinaryCodecError::InvalidDefinitions(format!(
+                    "Field with name {} defined twice",
+                    field_name
+                )));
+            }
+        }
+
+        Self::new(
+            field_ids,
+            non_negative_codes(json.transaction_types),
+            non_negative_codes(json.ledger_entry_types),
+            json.transaction_results.into_iter().collect(),
+        )
+    }
+
+    pub(crate) fn field_id(&self, field_name: &str) -> Option<FieldId> {
+        self.field_ids.get(field_name).copied()
+    }
+
+    pub(crate) fn field_name(&self, field_id: FieldId) -> Option<&str> {
+        self.field_names.get(&field_id).map(|s| s.as_str())
+    }
+
+    /// Whether a serialized field with the given name is defined
+    pub fn has_field(&self, field_name: &str) -> bool {
+        self.field_ids.contains_key(field_name)
+    }
+
+    /// Transaction type code for the given name, e.g. 0 for `Payment`
+    pub fn transaction_type_code(&self, name: &str) -> Option<u16> {
+        self.transaction_types.get(name).copied()
+    }
+
+    /// Transaction type name for the given code, e.g. `Payment` for 0
+    pub fn transaction_type_name(&self, code: u16) -> Option<&str> {
+        self.transaction_type_names.get(&code).map(|s| s.as_str())
+    }
+
+    /// Ledger entry type code for the given name, e.g. 97 for `AccountRoot`
+    pub fn ledger_entry_type_code(&self, name: &str) -> Option<u16> {
+        self.ledger_entry_types.get(name).copied()
+    }
+
+    /// Ledger entry type name for the given code, e.g. `AccountRoot` for 97
+    pub fn ledger_entry_type_name(&self, code: u16) -> Option<&str> {
+        self.ledger_entry_type_names.get(&code).map(|s| s.as_str())
+    }
+
+    /// Transaction result code for the given name, e.g. 0 for `tesSUCCESS`
+    pub fn transaction_result_code(&self, name: &str) -> Option<i32> {
+        self.transaction_results.get(name).copied()
+    }
+
+    /// Transaction result name for the given code, e.g. `tesSUCCESS` for 0
+    pub fn transaction_result_name(&self, code: i32) -> Option<&str> {
+        self.transaction_result_names.get(&code).map(|s| s.as_str())
+    }
+}
+
+/// Format of `definitions.json`
+#[derive(serde::Deserialize)]
+struct DefinitionsJson {
+    #[serde(rename = "TYPES")]
+    types: BTreeMap<String, i32>,
+    #[serde(rename = "FIELDS")]
+    fields: Vec<(String, FieldJson)>,
+    #[serde(rename = "TRANSACTION_TYPES")]
+    transaction_types: BTreeMap<String, i32>,
+    #[serde(rename = "LEDGER_ENTRY_TYPES")]
+    ledger_entry_types: BTreeMap<String, i32>,
+    #[serde(rename = "TRANSACTION_RESULTS")]
+    transaction_results: BTreeMap<String, i32>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FieldJson {
+    nth: i32,
+    is_serialized: bool,
+    #[serde(rename = "type")]
+    type_name: String,
+}
+
+/// Negative codes are used for pseudo types like `Invalid` that never appear in serialized objects
+fn non_negative_codes(codes: BTreeMap<String, i32>) -> HashMap<String, u16> {
+    codes
+        .into_iter()
+        .filter_map(|(name, code)| Some((name, u16::try_from(code).ok()?)))
+        .collect()
+}
+
+fn to_map<T: Copy>(entries: &[(&str, T)]) -> HashMap<String, T> {
+    entries
+        .iter()
+        .map(|(name, code)| (name.to_string(), *code))
+        .collect()
+}
+
+/// Maps codes back to names. Fails if two names have the same code, as the code would be decoded
+/// as either name
+fn reverse_map<V: Copy + Eq + core::hash::Hash + core::fmt::Debug>(
+    map: &HashMap<String, V>,
+    code_description: &str,
+) -> Result<HashMap<V, String>, BinaryCodecError> {
+    let mut reversed = HashMap::with_capacity(map.len());
+    for (name, code) in map {
+        if let Some(other_name) = reversed.insert(*code, name.clone()) {
+            let (first, second) = if other_name < *name {
+                (&other_name, name)
+            } else {
+                (name, &other_name)
+            };
+            return Err(BinaryCodecError::InvalidDefinitions(format!(
+                "{} {:?} defined for both {} and {}",
+                code_description, code, first, second
+            )));
+        }
+    }
+    Ok(reversed)
+}
+
+/// Transaction types taken from TRANSACTION_TYPES in <https://github.com/XRPLF/xrpl.js/blob/main/packages/ripple-binary-codec/src/enums/definitions.json>
+const TRANSACTION_TYPES: &[(&str, u16)] = &[
+    ("Payment", 0),
+    ("EscrowCreate", 1),
+    ("EscrowFinish", 2),
+    ("AccountSet", 3),
+    ("EscrowCancel", 4),
+    ("SetRegularKey", 5),
+    ("NickNameSet", 6),
+    ("OfferCreate", 7),
+    ("OfferCancel", 8),
+    ("Contract", 9),
+    ("TicketCreate", 10),
+    ("TicketCancel", 11),
+    ("SignerListSet", 12),
+    ("PaymentChannelCreate", 13),
+    ("PaymentChannelFund", 14),
+    ("PaymentChannelClaim", 15),
+    ("CheckCreate", 16),
+    ("CheckCash", 17),
+    ("CheckCancel", 18),
+    ("DepositPreauth", 19),
+    ("TrustSet", 20),
+    ("AccountDelete", 21),
+    ("SetHook", 22),
+    ("NFTokenMint", 25),
+    ("NFTokenBurn", 26),
+    ("NFTokenCreateOffer", 27),
+    ("NFTokenCancelOffer", 28),
+    ("NFTokenAcceptOffer", 29),
+    ("Clawback", 30),
+    ("AMMCreate", 35),
+    ("AMMDeposit", 36),
+    ("AMMWithdraw", 37),
+    ("AMMVote", 38),
+    ("AMMBid", 39),
+    ("AMMDelete", 40),
+    ("XChainCreateClaimID", 41),
+    ("XChainCommit", 42),
+    ("XChainClaim", 43),
+    ("XChainAccountCreateCommit", 44),
+    ("XChainAddClaimAttestation", 45),
+    ("XChainAddAccountCreateAttestation", 46),
+    ("XChainModifyBridge", 47),
+    ("XChainCreateBridge", 48),
+    ("DIDSet", 49),
+    ("DIDDelete", 50),
+    ("EnableAmendment", 100),
+    ("SetFee", 101),
+    ("UNLModify", 102),
+];
+
+/// Ledger entry types taken from LEDGER_ENTRY_TYPES in <https://github.com/XRPLF/xrpl.js/blob/main/packages/ripple-binary-codec/src/enums/definitions.json>
+const LEDGER_ENTRY_TYPES: &[(&str, u16)] = &[
+    ("NFTokenOffer", 55),
+    ("Check", 67),
+    ("DID", 73),
+    ("NegativeUNL", 78),
+    ("NFTokenPage", 80),
+    ("SignerList", 83),
+    ("Ticket", 84),
+    ("AccountRoot", 97),
+    ("DirectoryNode", 100),
+    ("Amendments", 102),
+    ("LedgerHashes", 104),
+    ("Bridge", 105),
+    ("Offer", 111),
+    ("DepositPreauth", 112),
+    ("XChainOwnedClaimID", 113),
+    ("RippleState", 114),
+    ("FeeSettings", 115),
+    ("XChainOwnedCreateAccountClaimID", 116),
+    ("Escrow", 117),
+    ("PayChannel", 120),
+    ("AMM", 121),
+];
+
+/// Transaction results taken from TRANSACTION_RESULTS in <https://github.com/XRPLF/xrpl.js/blob/main/packages/ripple-binary-codec/src/enums/definitions.json>
+const TRANSACTION_RESULTS: &[(&str, i32)] = &[
+    ("telLOCAL_ERROR", -399),
+    ("telBAD_DOMAIN", -398),
+    ("telBAD_PATH_COUNT", -397),
+    ("telBAD_PUBLIC_KEY", -396),
+    ("telFAILED_PROCESSING", -395),
+    ("telINSUF_FEE_P", -394),
+    ("telNO_DST_PARTIAL", -393),
+    ("telCAN_NOT_QUEUE", -392),
+    ("telCAN_NOT_QUEUE_BALANCE", -391),
+    ("telCAN_NOT_QUEUE_BLOCKS", -390),
+    ("telCAN_NOT_QUEUE_BLOCKED", -389),
+    ("telCAN_NOT_QUEUE_FEE", -388),
+    ("telCAN_NOT_QUEUE_FULL", -387),
+    ("telWRONG_NETWORK", -386),
+    ("telREQUIRES_NETWORK_ID", -385),
+    ("telNETWORK_ID_MAKES_TX_NON_CANONICAL", -384),
+    ("temMALFORMED", -299),
+    ("temBAD_AMOUNT", -298),
+    ("temBAD_CURRENCY", -297),
+    ("temBAD_EXPIRATION", -296),
+    ("temBAD_FEE", -295),
+    ("temBAD_ISSUER", -294),
+    ("temBAD_LIMIT", -293),
+    ("temBAD_OFFER", -292),
+    ("temBAD_PATH", -291),
+    ("temBAD_PATH_LOOP", -290),
+    ("temBAD_REGKEY", -289),
+    ("temBAD_SEND_XRP_LIMIT", -288),
+    ("temBAD_SEND_XRP_MAX", -287),
+    ("temBAD_SEND_XRP_NO_DIRECT", -286),
+    ("temBAD_SEND_XRP_PARTIAL", -285),
+    ("temBAD_SEND_XRP_PATHS", -284),
+    ("temBAD_SEQUENCE", -283),
+    ("temBAD_SIGNATURE", -282),
+    ("temBAD_SRC_ACCOUNT", -281),
+    ("temBAD_TRANSFER_RATE", -280),
+    ("temDST_IS_SRC", -279),
+    ("temDST_NEEDED", -278),
+    ("temINVALID", -277),
+    ("temINVALID_FLAG", -276),
+    ("temREDUNDANT", -275),
+    ("temRIPPLE_EMPTY", -274),
+    ("temDISABLED", -273),
+    ("temBAD_SIGNER", -272),
+    ("temBAD_QUORUM", -271),
+    ("temBAD_WEIGHT", -270),
+    ("temBAD_TICK_SIZE", -269),
+    ("temINVALID_ACCOUNT_ID", -268),
+    ("temCANNOT_PREAUTH_SELF", -267),
+    ("temINVALID_COUNT", -266),
+    ("temUNCERTAIN", -265),
+    ("temUNKNOWN", -264),
+    ("temSEQ_AND_TICKET", -263),
+    ("temBAD_NFTOKEN_TRANSFER_FEE", -262),
+    ("temBAD_AMM_TOKENS", -261),
+    ("tefFAILURE", -199),
+    ("tefALREADY", -198),
+    ("tefBAD_ADD_AUTH", -197),
+    ("tefBAD_AUTH", -196),
+    ("tefBAD_LEDGER", -195),
+    ("tefCREATED", -194),
+    ("tefEXCEPTION", -193),
+    ("tefINTERNAL", -192),
+    ("tefNO_AUTH_REQUIRED", -191),
+    ("tefPAST_SEQ", -190),
+    ("tefWRONG_PRIOR", -189),
+    ("tefMASTER_DISABLED", -188),
+    ("tefMAX_LEDGER", -187),
+    ("tefBAD_SIGNATURE", -186),
+    ("tefBAD_QUORUM", -185),
+    ("tefNOT_MULTI_SIGNING", -184),
+    ("tefBAD_AUTH_MASTER", -183),
+    ("tefINVARIANT_FAILED", -182),
+    ("tefTOO_BIG", -181),
+    ("tefNO_TICKET", -180),
+    ("tefNFTOKEN_IS_NOT_TRANSFERABLE", -179),
+    ("terRETRY", -99),
+    ("terFUNDS_SPENT", -98),
+    ("terINSUF_FEE_B", -97),
+    ("terNO_ACCOUNT", -96),
+    ("terNO_AUTH", -95),
+    ("terNO_LINE", -94),
+    ("terOWNERS", -93),
+    ("terPRE_SEQ", -92),
+    ("terLAST", -91),
+    ("terNO_RIPPLE", -90),
+    ("terQUEUED", -89),
+    ("terPRE_TICKET", -88),
+    ("terNO_AMM", -87),
+    ("tesSUCCESS", 0),
+    ("tecCLAIM", 100),
+    ("tecPATH_PARTIAL", 101),
+    ("tecUNFUNDED_ADD", 102),
+    ("tecUNFUNDED_OFFER", 103),
+    ("tecUNFUNDED_PAYMENT", 104),
+    ("tecFAILED_PROCESSING", 105),
+    ("tecDIR_FULL", 121),
+    ("tecINSUF_RESERVE_LINE", 122),
+    ("tecINSUF_RESERVE_OFFER", 123),
+    ("tecNO_DST", 124),
+    ("tecNO_DST_INSUF_XRP", 125),
+    ("tecNO_LINE_INSUF_RESERVE", 126),
+    ("tecNO_LINE_REDUNDANT", 127),
+    ("tecPATH_DRY", 128),
+    ("tecUNFUNDED", 129),
+    ("tecNO_ALTERNATIVE_KEY", 130),
+    ("tecNO_REGULAR_KEY", 131),
+    ("tecOWNERS", 132),
+    ("tecNO_ISSUER", 133),
+    ("tecNO_AUTH", 134),
+    ("tecNO_LINE", 135),
+    ("tecINSUFF_FEE", 136),
+    ("tecFROZEN", 137),
+    ("tecNO_TARGET", 138),
+    ("tecNO_PERMISSION", 139),
+    ("tecNO_ENTRY", 140),
+    ("tecINSUFFICIENT_RESERVE", 141),
+    ("tecNEED_MASTER_KEY", 142),
+    ("tecDST_TAG_NEEDED", 143),
+    ("tecINTERNAL", 144),
+    ("tecOVERSIZE", 145),
+    ("tecCRYPTOCONDITION_ERROR", 146),
+    ("tecINVARIANT_FAILED", 147),
+    ("tecEXPIRED", 148),
+    ("tecDUPLICATE", 149),
+    ("tecKILLED", 150),
+    ("tecHAS_OBLIGATIONS", 151),
+    ("tecTOO_SOON", 152),
+    ("tecHOOK_REJECTED", 153),
+    ("tecMAX_SEQUENCE_REACHED", 154),
+    ("tecNO_SUITABLE_NFTOKEN_PAGE", 155),
+    ("tecNFTOKEN_BUY_SELL_MISMATCH", 156),
+    ("tecNFTOKEN_OFFER_TYPE_MISMATCH", 157),
+    ("tecCANT_ACCEPT_OWN_NFTOKEN_OFFER", 158),
+    ("tecINSUFFICIENT_FUNDS", 159),
+    ("tecOBJECT_NOT_FOUND", 160),
+    ("tecINSUFFICIENT_PAYMENT", 161),
+    ("tecUNFUNDED_AMM", 162),
+    ("tecAMM_BALANCE", 163),
+    ("tecAMM_FAILED", 164),
+    ("tecAMM_INVALID_TOKENS", 165),
+    ("tecAMM_EMPTY", 166),
+    ("tecAMM_NOT_EMPTY", 167),
+    ("tecAMM_ACCOUNT", 168),
+    ("tecINCOMPLETE", 169),
+    ("tecXCHAIN_BAD_TRANSFER_ISSUE", 170),
+    ("tecXCHAIN_NO_CLAIM_ID", 171),
+    ("tecXCHAIN_BAD_CLAIM_ID", 172),
+    ("tecXCHAIN_CLAIM_NO_QUORUM", 173),
+    ("tecXCHAIN_PROOF_UNKNOWN_KEY", 174),
+    ("tecXCHAIN_CREATE_ACCOUNT_NONXRP_ISSUE", 175),
+    ("tecXCHAIN_WRONG_CHAIN", 176),
+    ("tecXCHAIN_REWARD_MISMATCH", 177),
+    ("tecXCHAIN_NO_SIGNERS_LIST", 178),
+    ("tecXCHAIN_SENDING_ACCOUNT_MISMATCH", 179),
+    ("tecXCHAIN_INSUFF_CREATE_AMOUNT", 180),
+    ("tecXCHAIN_ACCOUNT_CREATE_PAST", 181),
+    ("tecXCHAIN_ACCOUNT_CREATE_TOO_MANY", 182),
+    ("tecXCHAIN_PAYMENT_FAILED", 183),
+    ("tecXCHAIN_SELF_COMMIT", 184),
+    ("tecXCHAIN_BAD_PUBLIC_KEY_ACCOUNT_PAIR", 185),
+    ("tecXCHAIN_CREATE_ACCOUNT_DISABLED", 186),
+    ("tecEMPTY_DID", 187),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{deserialize, serialize};
+    use assert_matches::assert_matches;
+    use xrpl_types::deserialize::{Deserialize, Deserializer, FieldAccessor};
+    use xrpl_types::serialize::{Serialize, Serializer};
+    use xrpl_types::UInt32;
+
+    const DEFINITIONS_JSON: &str = r#"{
+        "TYPES": {
+            "Done": -1,
+            "Unknown": -2,
+            "NotPresent": 0,
+            "UInt16": 1,
+            "UInt32": 2,
+            "STObject": 14,
+            "Number": 9
+        },
+        "LEDGER_ENTRY_TYPES": {
+            "Invalid": -1,
+            "AccountRoot": 97
+        },
+        "FIELDS": [
+            ["Generic", {"nth": 0, "isVLEncoded": false, "isSerialized": false, "isSigningField": false, "type": "Unknown"}],
+            ["TransactionType", {"nth": 2, "isVLEncoded": false, "isSerialized": true, "isSigningField": true, "type": "UInt16"}],
+            ["Flags", {"nth": 2, "isVLEncoded": false, "isSerialized": true, "isSigningField": true, "type": "UInt32"}],
+            ["HookCustomField", {"nth": 99, "isVLEncoded": false, "isSerialized": true, "isSigningField": true, "type": "UInt32"}],
+            ["AssetPrice", {"nth": 1, "isVLEncoded": false, "isSerialized": true, "isSigningField": true, "type": "Number"}],
+            ["ObjectEndMarker", {"nth": 1, "isVLEncoded": false, "isSerialized": true, "isSigningField": true, "type": "STObject"}],
+            ["hash", {"nth": 257, "isVLEncoded": false, "isSerialized": false, "isSigningField": false, "type": "Hash256"}]
+        ],
+        "TRANSACTION_RESULTS": {
+            "telLOCAL_ERROR": -399,
+            "tesSUCCESS": 0
+        },
+        "TRANSACTION_TYPES": {
+            "Invalid": -1,
+            "Payment": 0,
+            "CustomTransaction": 95
+        }
+    }"#;
+
+    #[derive(Debug)]
+    struct CustomObject {
+        custom_field: UInt32,
+    }
+
+    impl Serialize for CustomObject {
+        fn serialize<S: Serializer>(&self, serializer: &mut S) -> Result<(), S::Error> {
+            serializer.serialize_uint32("HookCustomField", self.custom_field)
+        }
+    }
+
+    impl Deserialize for CustomObject {
+        fn deserialize<S: Deserializer>(mut deserializer: S) -> Result<Self, S::Error> {
+            Ok(Self {
+                custom_field: deserializer
+                    .deserialize_single_field("HookCustomField")?
+                    .deserialize_uint32()?,
+            })
+        }
+    }
+
+    #[test]
+    fn test_builtin() {
+        let definitions = Definitions::builtin();
+        assert_eq!(
+            definitions.field_id("Flags"),
+            Some(FieldId::from_type_field(TypeCode::UInt32, FieldCode(2)))
+        );
+        assert_eq!(
+            definitions.field_name(FieldId::from_type_field(TypeCode::UInt32, FieldCode(2))),
+            Some("Flags")
+        );
+        assert_eq!(definitions.transaction_type_code("OfferCreate"), Some(7));
+        assert_eq!(definitions.transaction_type_name(7), Some("OfferCreate"));
+        assert_eq!(definitions.ledger_entry_type_code("RippleState"), Some(114));
+        assert_eq!(definitions.ledger_entry_type_name(97), Some("AccountRoot"));
+        assert_eq!(
+            definitions.transaction_result_code("tecPATH_DRY"),
+            Some(128)
+        );
+        assert_eq!(definitions.transaction_result_name(0), Some("tesSUCCESS"));
+    }
+
+    #[test]
+    fn test_from_json() {
+        let definitions = Definitions::from_json(DEFINITIONS_JSON).unwrap();
+        assert_eq!(
+            definitions.field_id("HookCustomField"),
+            Some(FieldId::from_type_field(TypeCode::UInt32, FieldCode(99)))
+        );
+        assert_eq!(
+            definitions.field_id("TransactionType"),
+            Some(FieldId::from_type_field(TypeCode::UInt16, FieldCode(2)))
+        );
+        // not serialized
+        assert!(!definitions.has_field("Generic"));
+        assert!(!definitions.has_field("hash"));
+        // type not supported by the codec
+        assert!(!definitions.has_field("AssetPrice"));
+        // not part of the loaded definitions
+        assert!(!definitions.has_field("Account"));
+        assert_eq!(
+            definitions.transaction_type_code("CustomTransaction"),
+            Some(95)
+        );
+        assert_eq!(definitions.transaction_type_code("Invalid"), None);
+        assert_eq!(definitions.ledger_entry_type_name(97), Some("AccountRoot"));
+        assert_eq!(
+            definitions.transaction_result_name(-399),
+            Some("telLOCAL_ERROR")
+        );
+    }
+
+    #[test]
+    fn test_from_json_server_definitions_response() {
+        let json = format!(r#"{{"result": {}}}"#, DEFINITIONS_JSON);
+        let definitions = Definitions::from_json(&json).unwrap();
+        assert!(definitions.has_field("HookCustomField"));
+    }
+
+    #[test]
+    fn test_from_json_invalid() {
+        let result = Definitions::from_json(r#"{"TYPES": {}}"#);
+        assert_matches!(result, Err(BinaryCodecError::InvalidDefinitions(message)) => {
+            assert!(message.contains("FIELDS"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_from_json_duplicate_field_id() {
+        let json = DEFINITIONS_JSON.replace(r#"{"nth": 99,"#, r#"{"nth": 2,"#);
+        let result = Definitions::from_json(&json);
+        assert_matches!(result, Err(BinaryCodecError::InvalidDefinitions(message)) => {
+            assert!(message.contains("Field id"), "message: {}", message);
+            assert!(message.contains("for both Flags and HookCustomField"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_from_json_duplicate_codes() {
+        let json =
+            DEFINITIONS_JSON.replace(r#""CustomTransaction": 95"#, r#""CustomTransaction": 0"#);
+        let result = Definitions::from_json(&json);
+        assert_matches!(result, Err(BinaryCodecError::InvalidDefinitions(message)) => {
+            assert!(message.contains("Transaction type code 0 defined for both CustomTransaction and Payment"), "message: {}", message);
+        });
+
+        let json = DEFINITIONS_JSON.replace(r#""tesSUCCESS": 0"#, r#""tesSUCCESS": -399"#);
+        let result = Definitions::from_json(&json);
+        assert_matches!(result, Err(BinaryCodecError::InvalidDefinitions(message)) => {
+            assert!(message.contains("Transaction result code -399"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_serialize_with_definitions() {
+        let definitions = Definitions::from_json(DEFINITIONS_JSON).unwrap();
+        let object = CustomObject { custom_field: 12 };
+
+        let bytes = serialize::serialize_with_definitions(&object, &definitions).unwrap();
+        assert_eq!(bytes, [0x20, 99, 0, 0, 0, 12]);
+
+        let object: CustomObject =
+            deserialize::deserialize_with_definitions(&bytes, &definitions).unwrap();
+        assert_eq!(object.custom_field, 12);
+
+        // the field is not known in the built-in definitions
+        let result = serialize::serialize(&object);
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("HookCustomField is not known"), "message: {}", message);
+        });
+        let result: Result<CustomObject, _> = deserialize::deserialize(&bytes);
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("is not known"), "message: {}", message);
+        });
+    }
+}
@@ -1,9 +1,36 @@
+use crate::alloc::string::ToString;
+use crate::definitions::Definitions;
 use crate::deserializer::Deserializer;
-use crate::BinaryCodecError;
+use crate::{serialize, BinaryCodecError};
 use xrpl_types::deserialize::Deserialize;
+use xrpl_types::serialize::Serialize;
 
 /// Deserializes the given bytes in the canonical binary format <https://xrpl.org/serialization.html> to `T`
 pub fn deserialize<T: Deserialize>(bytes: &[u8]) -> Result<T, BinaryCodecError> {
     let d = Deserializer::new(bytes);
     T::deserialize(d)
 }
+
+/// Deserializes the given bytes in the canonical binary format <https://xrpl.org/serialization.html> to `T`,
+/// looking up fields in the given definitions
+pub fn deserialize_with_definitions<T: Deserialize>(
+    bytes: &[u8],
+    definitions: &Definitions,
+) -> Result<T, BinaryCodecError> {
+    let d = Deserializer::with_definitions(bytes, definitions);
+    T::deserialize(d)
+}
+
+/// Deserializes the given bytes to `T` and checks that serializing `T` again gives the same bytes,
+/// i.e. that the bytes are canonical and contain no fields that are not part of `T`
+pub(crate) fn deserialize_canonical<T: Deserialize + Serialize>(
+    bytes: &[u8],
+) -> Result<T, BinaryCodecError> {
+    let object: T = deserialize(bytes)?;
+    if serialize::serialize(&object)? != bytes {
+        return Err(BinaryCodecError::InvalidField(
+            "bytes are not canonical or contain fields that are not modelled".to_string(),
+        ));
+    }
+    Ok(object)
+}
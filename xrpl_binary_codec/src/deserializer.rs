@@ -6,19 +6,37 @@ use ascii::AsciiChar;
 use bytes::Buf;
 use core::fmt::Display;
 use xrpl_types::{
-    deserialize, AccountId, Amount, Blob, CurrencyCode, DropsAmount, Hash128, Hash160, Hash256,
-    IssuedAmount, IssuedValue, UInt16, UInt32, UInt64, UInt8,
+    deserialize, AccountId, Amount, Blob, Currency, CurrencyCode, DropsAmount, FieldValue, Hash128,
+    Hash160, Hash256, IssuedAmount, IssuedValue, Path, PathSet, PathStep, UInt16, UInt32, UInt64,
+    UInt8, XChainBridge,
 };
 
-use crate::alloc::string::ToString;
-use crate::field::{field_info, FieldCode, FieldId, TypeCode};
+use crate::alloc::string::{String, ToString};
+use crate::definitions::Definitions;
+use crate::field::{
+    FieldCode, FieldId, TypeCode, PATH_SEPARATOR_BYTE, PATH_SET_END_BYTE, PATH_STEP_ACCOUNT,
+    PATH_STEP_CURRENCY, PATH_STEP_ISSUER,
+};
 use xrpl_types::deserialize::{DeserError, Deserialize, Visitor};
 
-#[derive(Debug, Clone, Default)]
-pub struct Deserializer<B> {
+/// Zero-copy deserialization, borrowing variable length values from the input bytes
+pub mod borrowed;
+/// Inspection of binary blobs, reading fields with their byte offsets for diagnostics
+pub mod inspect;
+
+#[derive(Debug, Clone)]
+pub struct Deserializer<'d, B> {
     bytes: B,
     object_deserializer: bool,
     previous_field_id: Option<FieldId>,
+    /// Definitions used to look up fields
+    definitions: &'d Definitions,
+}
+
+impl<B: Buf + Default> Default for Deserializer<'_, B> {
+    fn default() -> Self {
+        Self::new(B::default())
+    }
 }
 
 impl DeserError for BinaryCodecError {
@@ -35,100 +53,77 @@ impl DeserError for BinaryCodecError {
     }
 }
 
-impl<B: Buf> deserialize::Deserializer for Deserializer<B> {
+impl<B: Buf> deserialize::Deserializer for Deserializer<'_, B> {
     type Error = BinaryCodecError;
 
     fn deserialize<V: Visitor>(mut self, visitor: &mut V) -> Result<(), Self::Error> {
-        loop {
-            if self.bytes.remaining() == 0 {
-                return Ok(());
-            }
-
-            let field_id = self.read_field_id()?;
-            let field_name = get_field_name(field_id)?;
+        (&mut self).deserialize(visitor)
+    }
 
-            if field_id == FieldId::from_type_field(TypeCode::Object, FieldCode(1))
-                && self.object_deserializer
-            {
-                return Ok(());
-            }
+    fn deserialize_single_field(
+        &mut self,
+        expected_field_name: &str,
+    ) -> Result<impl deserialize::FieldAccessor<Error = BinaryCodecError>, Self::Error> {
+        self.single_field(expected_field_name)
+    }
+}
 
-            self.set_and_check_field_order(field_id)?;
+/// Deserializer for inner objects, see [`Deserializer::read_inner_object`]
+impl<B: Buf> deserialize::Deserializer for &mut Deserializer<'_, B> {
+    type Error = BinaryCodecError;
 
+    fn deserialize<V: Visitor>(self, visitor: &mut V) -> Result<(), Self::Error> {
+        while let Some((field_id, field_name)) = self.next_field()? {
             if field_id.type_code == TypeCode::Array {
                 let array_deserializer = ArrayDeserializer {
-                    deserializer: &mut self,
+                    deserializer: &mut *self,
                 };
                 visitor.visit_array(field_name, array_deserializer)?;
+            } else if field_id.type_code == TypeCode::Object {
+                self.read_inner_object(|object_deserializer| {
+                    visitor.visit_object(field_name, object_deserializer)
+                })?;
             } else {
                 visitor.visit_field(
                     field_name,
                     FieldAccessor {
-                        deserializer: &mut self,
+                        deserializer: &mut *self,
                         type_code: field_id.type_code,
                     },
                 )?;
             }
         }
+        Ok(())
     }
 
     fn deserialize_single_field(
         &mut self,
         expected_field_name: &str,
     ) -> Result<impl deserialize::FieldAccessor<Error = BinaryCodecError>, Self::Error> {
-        let field_id = self.read_field_id()?;
-        let field_name = get_field_name(field_id)?;
-        self.set_and_check_field_order(field_id)?;
-
-        if field_name != expected_field_name {
-            return Err(BinaryCodecError::InvalidField(format!(
-                "Expected field {}, found {}",
-                expected_field_name, field_name
-            )));
-        }
-
-        Ok(FieldAccessor {
-            deserializer: self,
-            type_code: field_id.type_code,
-        })
+        self.single_field(expected_field_name)
     }
 }
 
 #[derive(Debug)]
-pub struct ArrayDeserializer<'a, B> {
-    deserializer: &'a mut Deserializer<B>,
+pub struct ArrayDeserializer<'a, 'd, B> {
+    deserializer: &'a mut Deserializer<'d, B>,
 }
 
-impl<'a, B: Buf> deserialize::ArrayDeserializer for ArrayDeserializer<'a, B> {
+impl<B: Buf> deserialize::ArrayDeserializer for ArrayDeserializer<'_, '_, B> {
     type Error = BinaryCodecError;
 
     fn deserialize_object<T: Deserialize>(
         &mut self,
         expected_field_name: &str,
     ) -> Result<Option<T>, Self::Error> {
-        let field_id = self.deserializer.read_field_id()?;
-
-        if field_id == FieldId::from_type_field(TypeCode::Array, FieldCode(1)) {
+        let Some(field_name) = self.deserializer.next_array_element()? else {
             return Ok(None);
-        }
-
-        if field_id.type_code != TypeCode::Object {
-            return Err(BinaryCodecError::InvalidField(format!(
-                "Expected object type, found {}",
-                field_id.type_code
-            )));
-        }
-
-        let field_name = get_field_name(field_id)?;
+        };
 
         if field_name == expected_field_name {
-            let object_deserializer = Deserializer {
-                bytes: &mut self.deserializer.bytes,
-                object_deserializer: true,
-                previous_field_id: None,
-            };
-
-            let object = T::deserialize(object_deserializer)?;
+            let object = self
+                .deserializer
+                .read_inner_object(|deserializer| T::deserialize(deserializer))?;
 
             Ok(Some(object))
         } else {
@@ -138,15 +133,29 @@ impl<'a, B: Buf> deserialize::ArrayDeserializer for ArrayDeserializer<'a, B> {
             )))
         }
     }
+
+    fn deserialize_any_object<T: Deserialize>(
+        &mut self,
+    ) -> Result<Option<(String, T)>, Self::Error> {
+        let Some(field_name) = self.deserializer.next_array_element()? else {
+            return Ok(None);
+        };
+
+        let object = self
+            .deserializer
+            .read_inner_object(|deserializer| T::deserialize(deserializer))?;
+
+        Ok(Some((field_name.to_string(), object)))
+    }
 }
 
 #[derive(Debug)]
-struct FieldAccessor<'a, B> {
-    deserializer: &'a mut Deserializer<B>,
+struct FieldAccessor<'a, 'd, B> {
+    deserializer: &'a mut Deserializer<'d, B>,
     type_code: TypeCode,
 }
 
-impl<'a, B> FieldAccessor<'a, B> {
+impl<B> FieldAccessor<'_, '_, B> {
     fn check_type(&self, expected_type_code: TypeCode) -> Result<(), BinaryCodecError> {
         if self.type_code != expected_type_code {
             Err(BinaryCodecError::InvalidField(format!(
@@ -159,7 +168,7 @@ impl<'a, B> FieldAccessor<'a, B> {
     }
 }
 
-impl<'a, B: Buf> deserialize::FieldAccessor for FieldAccessor<'a, B> {
+impl<B: Buf> deserialize::FieldAccessor for FieldAccessor<'_, '_, B> {
     type Error = BinaryCodecError;
 
     fn deserialize_account_id(self) -> Result<AccountId, Self::Error> {
@@ -211,15 +220,175 @@ impl<'a, B: Buf> deserialize::FieldAccessor for FieldAccessor<'a, B> {
         self.check_type(TypeCode::UInt64)?;
         self.deserializer.read_uint64()
     }
+
+    fn deserialize_path_set(self) -> Result<PathSet, Self::Error> {
+        self.check_type(TypeCode::PathSet)?;
+        self.deserializer.read_path_set()
+    }
+
+    fn deserialize_vector256(self) -> Result<Vec<Hash256>, Self::Error> {
+        self.check_type(TypeCode::Vector256)?;
+        self.deserializer.read_vector256()
+    }
+
+    fn deserialize_issue(self) -> Result<Currency, Self::Error> {
+        self.check_type(TypeCode::Issue)?;
+        self.deserializer.read_issue()
+    }
+
+    fn deserialize_xchain_bridge(self) -> Result<XChainBridge, Self::Error> {
+        self.check_type(TypeCode::XChainBridge)?;
+        self.deserializer.read_xchain_bridge()
+    }
+
+    fn deserialize_any(self) -> Result<FieldValue, Self::Error> {
+        let deserializer = self.deserializer;
+        Ok(match self.type_code {
+            TypeCode::AccountId => FieldValue::AccountId(deserializer.read_account_id()?),
+            TypeCode::Amount => FieldValue::Amount(deserializer.read_amount()?),
+            TypeCode::Blob => FieldValue::Blob(deserializer.read_blob()?),
+            TypeCode::Hash128 => FieldValue::Hash128(deserializer.read_h128()?),
+            TypeCode::Hash160 => FieldValue::Hash160(deserializer.read_h160()?),
+            TypeCode::Hash256 => FieldValue::Hash256(deserializer.read_h256()?),
+            TypeCode::UInt8 => FieldValue::UInt8(deserializer.read_uint8()?),
+            TypeCode::UInt16 => FieldValue::UInt16(deserializer.read_uint16()?),
+            TypeCode::UInt32 => FieldValue::UInt32(deserializer.read_uint32()?),
+            TypeCode::UInt64 => FieldValue::UInt64(deserializer.read_uint64()?),
+            TypeCode::PathSet => FieldValue::PathSet(deserializer.read_path_set()?),
+            TypeCode::Vector256 => FieldValue::Vector256(deserializer.read_vector256()?),
+            TypeCode::Issue => FieldValue::Issue(deserializer.read_issue()?),
+            TypeCode::XChainBridge => FieldValue::XChainBridge(deserializer.read_xchain_bridge()?),
+            TypeCode::Object | TypeCode::Array => {
+                return Err(BinaryCodecError::InvalidField(format!(
+                    "Field of type {} cannot be deserialized as single value",
+                    self.type_code
+                )))
+            }
+        })
+    }
 }
 
-impl<B: Buf> Deserializer<B> {
+impl<'d, B: Buf> Deserializer<'d, B> {
     pub fn new(bytes: B) -> Self {
+        Self::with_definitions(bytes, Definitions::builtin())
+    }
+
+    /// Creates deserializer that looks up fields in the given definitions
+    pub fn with_definitions(bytes: B, definitions: &'d Definitions) -> Self {
         Self {
             bytes,
             object_deserializer: false,
             previous_field_id: None,
+            definitions,
+        }
+    }
+
+    /// Reads inner object with the given closure, that is given this deserializer to read the
+    /// fields until the object end marker. Reusing the deserializer, instead of wrapping it in a
+    /// deserializer for the inner object, keeps the deserializer type the same for objects nested
+    /// to any depth
+    fn read_inner_object<T>(
+        &mut self,
+        read: impl FnOnce(&mut Self) -> Result<T, BinaryCodecError>,
+    ) -> Result<T, BinaryCodecError> {
+        let object_deserializer = core::mem::replace(&mut self.object_deserializer, true);
+        let previous_field_id = self.previous_field_id.take();
+        let result = read(self);
+        self.object_deserializer = object_deserializer;
+        self.previous_field_id = previous_field_id;
+        result
+    }
+
+    /// Reads the next field, that must have the given name, see
+    /// [`deserialize::Deserializer::deserialize_single_field`]
+    fn single_field(
+        &mut self,
+        expected_field_name: &str,
+    ) -> Result<FieldAccessor<'_, 'd, B>, BinaryCodecError> {
+        let field_id = self.read_field_id()?;
+        let field_name = self.field_name(field_id)?;
+        self.set_and_check_field_order(field_id)?;
+
+        if field_name != expected_field_name {
+            return Err(BinaryCodecError::InvalidField(format!(
+                "Expected field {}, found {}",
+                expected_field_name, field_name
+            )));
+        }
+
+        Ok(FieldAccessor {
+            deserializer: self,
+            type_code: field_id.type_code,
+        })
+    }
+
+    /// Reads the id of the next field and checks the field order. Returns `None` at the end of the
+    /// bytes, or at the object end marker if deserializing an inner object
+    fn next_field(&mut self) -> Result<Option<(FieldId, &'d str)>, BinaryCodecError> {
+        let Some(field_id) = self.next_field_id()? else {
+            return Ok(None);
+        };
+        let field_name = self.field_name(field_id)?;
+        Ok(Some((field_id, field_name)))
+    }
+
+    /// Reads the id of the next field and checks the field order, without looking up the field name
+    fn next_field_id(&mut self) -> Result<Option<FieldId>, BinaryCodecError> {
+        if self.bytes.remaining() == 0 {
+            if self.object_deserializer {
+                return Err(BinaryCodecError::InsufficientBytes(
+                    "Object end marker missing".to_string(),
+                ));
+            }
+            return Ok(None);
+        }
+
+        let field_id = self.read_field_id()?;
+        if field_id == FieldId::from_type_field(TypeCode::Object, FieldCode(1)) {
+            if self.object_deserializer {
+                return Ok(None);
+            }
+            return Err(BinaryCodecError::InvalidField(
+                "Object end marker outside object".to_string(),
+            ));
         }
+
+        self.set_and_check_field_order(field_id)?;
+        Ok(Some(field_id))
+    }
+
+    /// Reads the field id of the next object in an array and returns the field name.
+    /// Returns `None` at the array end marker
+    fn next_array_element(&mut self) -> Result<Option<&'d str>, BinaryCodecError> {
+        let Some(field_id) = self.next_array_element_id()? else {
+            return Ok(None);
+        };
+        self.field_name(field_id).map(Some)
+    }
+
+    /// Reads the field id of the next object in an array, without looking up the field name.
+    /// Returns `None` at the array end marker
+    fn next_array_element_id(&mut self) -> Result<Option<FieldId>, BinaryCodecError> {
+        let field_id = self.read_field_id()?;
+
+        if field_id == FieldId::from_type_field(TypeCode::Array, FieldCode(1)) {
+            return Ok(None);
+        }
+
+        if field_id.type_code != TypeCode::Object {
+            return Err(BinaryCodecError::InvalidField(format!(
+                "Expected object type, found {}",
+                field_id.type_code
+            )));
+        }
+
+        Ok(Some(field_id))
+    }
+
+    fn field_name(&self, field_id: FieldId) -> Result<&'d str, BinaryCodecError> {
+        self.definitions.field_name(field_id).ok_or_else(|| {
+            BinaryCodecError::InvalidField(format!("Field with id {:?} is not known", field_id))
+        })
     }
 
     fn set_and_check_field_order(&mut self, new_field_id: FieldId) -> Result<(), BinaryCodecError> {
@@ -409,6 +578,84 @@ impl<B: Buf> Deserializer<B> {
         Ok(AccountId(array))
     }
 
+    /// <https://xrpl.org/serialization.html#pathset-fields>
+    fn read_path_set(&mut self) -> Result<PathSet, BinaryCodecError> {
+        let mut path_set = PathSet::default();
+        let mut path = Path::default();
+        loop {
+            match self.read_u8()? {
+                PATH_SET_END_BYTE => {
+                    path_set.0.push(path);
+                    return Ok(path_set);
+                }
+                PATH_SEPARATOR_BYTE => {
+                    path_set.0.push(core::mem::take(&mut path));
+                }
+                step_type => {
+                    path.0.push(self.read_path_step(step_type)?);
+                }
+            }
+        }
+    }
+
+    /// <https://xrpl.org/serialization.html#vector256-fields>
+    fn read_vector256(&mut self) -> Result<Vec<Hash256>, BinaryCodecError> {
+        let len = self.read_vl_prefix()?;
+        if len % 32 != 0 {
+            return Err(BinaryCodecError::InvalidLength(format!(
+                "Vector256 length must be a multiple of 32: {}",
+                len
+            )));
+        }
+        (0..len / 32).map(|_| self.read_h256()).collect()
+    }
+
+    /// <https://xrpl.org/serialization.html#issue-fields>
+    fn read_issue(&mut self) -> Result<Currency, BinaryCodecError> {
+        let currency_code = self.read_currency_code()?;
+        if currency_code.is_xrp() {
+            return Ok(Currency::Xrp);
+        }
+        let issuer = self.read_account_id_no_length_prefix()?;
+        Currency::issued(currency_code, issuer)
+            .map_err(|err| BinaryCodecError::OutOfRange(err.to_string()))
+    }
+
+    /// <https://xrpl.org/serialization.html#xchainbridge-fields>
+    fn read_xchain_bridge(&mut self) -> Result<XChainBridge, BinaryCodecError> {
+        let locking_chain_door = self.read_account_id()?;
+        let locking_chain_issue = self.read_issue()?;
+        let issuing_chain_door = self.read_account_id()?;
+        let issuing_chain_issue = self.read_issue()?;
+        Ok(XChainBridge {
+            locking_chain_door,
+            locking_chain_issue,
+            issuing_chain_door,
+            issuing_chain_issue,
+        })
+    }
+
+    /// <https://xrpl.org/serialization.html#pathset-fields>
+    fn read_path_step(&mut self, step_type: u8) -> Result<PathStep, BinaryCodecError> {
+        if step_type & !(PATH_STEP_ACCOUNT | PATH_STEP_CURRENCY | PATH_STEP_ISSUER) != 0 {
+            return Err(BinaryCodecError::OutOfRange(format!(
+                "Invalid path step type: {}",
+                step_type
+            )));
+        }
+        let mut step = PathStep::default();
+        if step_type & PATH_STEP_ACCOUNT != 0 {
+            step.account = Some(self.read_account_id_no_length_prefix()?);
+        }
+        if step_type & PATH_STEP_CURRENCY != 0 {
+            step.currency = Some(self.read_currency_code()?);
+        }
+        if step_type & PATH_STEP_ISSUER != 0 {
+            step.issuer = Some(self.read_account_id_no_length_prefix()?);
+        }
+        Ok(step)
+    }
+
     /// <https://xrpl.org/docs/references/protocol/binary-format#field-ids>
     fn read_field_id(&mut self) -> Result<FieldId, BinaryCodecError> {
         let byte = self.read_u8()?;
@@ -454,12 +701,6 @@ fn ascii(byte: u8) -> Result<AsciiChar, BinaryCodecError> {
         .map_err(|_err| BinaryCodecError::OutOfRange(format!("Not valid ASCII char: {}", byte)))
 }
 
-pub fn get_field_name(field_id: FieldId) -> Result<&'static str, BinaryCodecError> {
-    field_info::field_name_by_id(field_id).ok_or_else(|| {
-        BinaryCodecError::InvalidField(format!("Field with id {:?} is not known", field_id))
-    })
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -467,13 +708,14 @@ mod tests {
     use crate::serialize;
     use ascii::AsciiChar;
     use assert_matches::assert_matches;
-    use enumflags2::BitFlags;
     use xrpl_types::deserialize::{Deserialize, Deserializer, FieldAccessor};
     use xrpl_types::{
-        AccountSetTransaction, DropsAmount, Memo, OfferCreateTransaction, Transaction,
+        AccountRootFlags, AccountSetTransaction, AffectedNode, DropsAmount, FieldValue, Flags,
+        LedgerEntry, LedgerEntryType, Memo, Object, OfferCreateTransaction, PaymentFlags,
+        PaymentTransaction, RippleStateFlags, Transaction, TransactionMetaData, TransactionType,
     };
 
-    fn deserializer(bytes: &[u8]) -> super::Deserializer<&[u8]> {
+    fn deserializer(bytes: &[u8]) -> super::Deserializer<'static, &[u8]> {
         super::Deserializer::new(bytes)
     }
 
@@ -534,6 +776,7 @@ mod tests {
     #[derive(Default)]
     pub struct ObjectWithArrayVisitor {
         objects: Vec<TestObject>,
+        object: Option<TestObject>,
         tick_size: Option<UInt8>,
     }
 
@@ -569,6 +812,20 @@ mod tests {
             }
             Ok(())
         }
+
+        fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+            &mut self,
+            field_name: &str,
+            deserializer: D,
+        ) -> Result<(), E> {
+            match field_name {
+                "Memo" => {
+                    self.object = Some(TestObject::deserialize(deserializer)?);
+                }
+                _ => return Err(E::unexpected_field(field_name)),
+            }
+            Ok(())
+        }
     }
 
     #[test]
@@ -862,6 +1119,115 @@ mod tests {
         assert_eq!(amount, expected_amount);
     }
 
+    #[test]
+    fn test_read_path_set() {
+        let mut bytes = vec![0x01];
+        bytes.extend_from_slice(&[0x11; 20]);
+        bytes.push(0xFF);
+        bytes.push(0x30);
+        bytes.extend_from_slice(&[0; 12]);
+        bytes.extend_from_slice(b"USD");
+        bytes.extend_from_slice(&[0; 5]);
+        bytes.extend_from_slice(&[0x22; 20]);
+        bytes.push(0x10);
+        bytes.extend_from_slice(&[0; 20]);
+        bytes.push(0x00);
+        let mut s = deserializer(&bytes);
+        let path_set = s.read_path_set().unwrap();
+        assert_eq!(
+            path_set,
+            PathSet(vec![
+                Path(vec![PathStep::account(AccountId([0x11; 20]))]),
+                Path(vec![
+                    PathStep::currency(
+                        CurrencyCode::standard([AsciiChar::U, AsciiChar::S, AsciiChar::D]).unwrap(),
+                        Some(AccountId([0x22; 20]))
+                    ),
+                    PathStep::currency(CurrencyCode::xrp(), None),
+                ]),
+            ])
+        );
+        assert_eq!(s.bytes.len(), 0);
+    }
+
+    #[test]
+    fn test_read_path_step_invalid_type() {
+        let mut s = deserializer(&[0x02, 0x00]);
+        let result = s.read_path_set();
+        assert_matches!(result, Err(BinaryCodecError::OutOfRange(message)) => {
+            assert!(message.contains("Invalid path step type"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_read_vector256() {
+        let mut bytes = vec![64];
+        bytes.extend_from_slice(&[0x11; 32]);
+        bytes.extend_from_slice(&[0x22; 32]);
+        let mut s = deserializer(&bytes);
+        let value = s.read_vector256().unwrap();
+        assert_eq!(value, vec![Hash256([0x11; 32]), Hash256([0x22; 32])]);
+        assert_eq!(s.bytes.len(), 0);
+    }
+
+    #[test]
+    fn test_read_vector256_invalid_length() {
+        let mut bytes = vec![33];
+        bytes.extend_from_slice(&[0x11; 33]);
+        let mut s = deserializer(&bytes);
+        let result = s.read_vector256();
+        assert_matches!(result, Err(BinaryCodecError::InvalidLength(message)) => {
+            assert!(message.contains("multiple of 32"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_read_issue_xrp() {
+        let mut s = deserializer(&[0; 20]);
+        let value = s.read_issue().unwrap();
+        assert_eq!(value, Currency::xrp());
+        assert_eq!(s.bytes.len(), 0);
+    }
+
+    #[test]
+    fn test_read_issue_issued() {
+        let mut bytes = vec![0; 12];
+        bytes.extend_from_slice(b"USD");
+        bytes.extend_from_slice(&[0; 5]);
+        bytes.extend_from_slice(&[0x22; 20]);
+        let mut s = deserializer(&bytes);
+        let value = s.read_issue().unwrap();
+        let usd = CurrencyCode::standard([AsciiChar::U, AsciiChar::S, AsciiChar::D]).unwrap();
+        assert_eq!(value, Currency::issued(usd, AccountId([0x22; 20])).unwrap());
+        assert_eq!(s.bytes.len(), 0);
+    }
+
+    #[test]
+    fn test_read_xchain_bridge() {
+        let mut bytes = vec![0x14];
+        bytes.extend_from_slice(&[0x11; 20]);
+        bytes.extend_from_slice(&[0; 20]);
+        bytes.push(0x14);
+        bytes.extend_from_slice(&[0x22; 20]);
+        bytes.extend_from_slice(&[0; 12]);
+        bytes.extend_from_slice(b"USD");
+        bytes.extend_from_slice(&[0; 5]);
+        bytes.extend_from_slice(&[0x33; 20]);
+        let mut s = deserializer(&bytes);
+        let value = s.read_xchain_bridge().unwrap();
+        let usd = CurrencyCode::standard([AsciiChar::U, AsciiChar::S, AsciiChar::D]).unwrap();
+        assert_eq!(
+            value,
+            XChainBridge {
+                locking_chain_door: AccountId([0x11; 20]),
+                locking_chain_issue: Currency::xrp(),
+                issuing_chain_door: AccountId([0x22; 20]),
+                issuing_chain_issue: Currency::issued(usd, AccountId([0x33; 20])).unwrap(),
+            }
+        );
+        assert_eq!(s.bytes.len(), 0);
+    }
+
     #[test]
     fn test_read_field_id_4bit_type_4bit_field() {
         let mut s = deserializer(&[0b0010_0100]);
@@ -982,6 +1348,69 @@ mod tests {
         assert_eq!(visitor.tick_size, Some(12));
     }
 
+    /// Test read inner object as a field
+    #[test]
+    fn test_read_object() {
+        let s = deserializer(&[
+            0b1110_1010,
+            0b0010_0001,
+            0,
+            0,
+            0,
+            12,
+            0b0010_0010,
+            0,
+            0,
+            0,
+            23,
+            0b1110_0001,
+            0b0000_0000,
+            0b0001_0000,
+            0b0001_0000,
+            12,
+        ]);
+
+        let mut visitor = ObjectWithArrayVisitor::default();
+        s.deserialize(&mut visitor).unwrap();
+        let object = visitor.object.unwrap();
+        assert_eq!(object.field1, 12);
+        assert_eq!(object.field2, 23);
+        assert_eq!(visitor.tick_size, Some(12));
+    }
+
+    #[test]
+    fn test_read_object_missing_end_marker() {
+        let s = deserializer(&[0b1110_1010, 0b0010_0001, 0, 0, 0, 12]);
+
+        let mut visitor = ObjectWithArrayVisitor::default();
+        let result = s.deserialize(&mut visitor);
+        assert_matches!(result, Err(BinaryCodecError::InsufficientBytes(message)) => {
+            assert!(message.contains("Object end marker missing"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_read_object_end_marker_outside_object() {
+        let s = deserializer(&[0b1110_0001]);
+
+        let mut visitor = ObjectWithArrayVisitor::default();
+        let result = s.deserialize(&mut visitor);
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("Object end marker outside object"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_read_unexpected_object() {
+        let s = deserializer(&[0b1110_1100, 0b1110_0001]);
+
+        let mut visitor = ObjectWithArrayVisitor::default();
+        let result = s.deserialize(&mut visitor);
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("Unexpected field: NFToken"), "message: {}", message);
+        });
+    }
+
     /// Test deserialize fields one by one (in order)
     #[test]
     fn test_deserialize_fields() {
@@ -1225,7 +1654,7 @@ mod tests {
         );
         assert_eq!(txn.common.txn_signature, Some(Blob(hex::decode("30440220143759437C04F7B61F012563AFE90D8DAFC46E86035E1D965A9CED282C97D4CE02204CFD241E86F17E011298FC1A39B63386C74306A5DE047E213B0F29EFA4571C2C").unwrap())));
         assert_eq!(txn.expiration, Some(595640108));
-        assert_eq!(txn.flags, BitFlags::from_bits(524288).unwrap());
+        assert_eq!(txn.flags, Some(Flags::from_bits(524288)));
         assert_eq!(txn.offer_sequence, Some(1752791));
     }
 
@@ -1236,14 +1665,16 @@ mod tests {
             AccountId::from_address("rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys").unwrap(),
         );
         txn_orig.common.memos.push(Memo {
-            memo_type: Blob(vec![0, 1]),
-            memo_data: Blob(vec![2, 3]),
+            memo_type: Some(Blob(vec![0, 1])),
+            memo_data: Some(Blob(vec![2, 3])),
             memo_format: None,
+            unknown_fields: Object::default(),
         });
         txn_orig.common.memos.push(Memo {
-            memo_type: Blob(vec![4, 5]),
-            memo_data: Blob(vec![6, 7]),
+            memo_type: Some(Blob(vec![4, 5])),
+            memo_data: Some(Blob(vec![6, 7])),
             memo_format: None,
+            unknown_fields: Object::default(),
         });
 
         let txn: AccountSetTransaction =
@@ -1255,6 +1686,56 @@ mod tests {
         );
     }
 
+    /// Deserialize transaction with a memo that has only `MemoData`
+    #[test]
+    fn test_deserialize_transaction_memo_data_only() {
+        let json = serde_json::json!({
+            "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+            "Fee": "10",
+            "Memos": [{"Memo": {"MemoData": "0203"}}],
+            "Sequence": 1,
+            "TransactionType": "AccountSet"
+        });
+        let bytes = crate::json::encode(&json).unwrap();
+
+        let txn: AccountSetTransaction = crate::deserialize::deserialize(&bytes).unwrap();
+        assert_eq!(txn.common.memos.len(), 1);
+        assert_eq!(txn.common.memos[0].memo_type, None);
+        assert_eq!(txn.common.memos[0].memo_data, Some(Blob(vec![2, 3])));
+        assert_eq!(serialize::serialize(&txn).unwrap(), bytes);
+    }
+
+    /// Deserialize transaction with memos and signers with fields that are not modelled
+    #[test]
+    fn test_deserialize_transaction_memo_signer_unknown_fields() {
+        let json = serde_json::json!({
+            "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+            "Fee": "10",
+            "Memos": [{"Memo": {"MemoData": "0203", "SourceTag": 5}}],
+            "Sequence": 1,
+            "Signers": [{"Signer": {
+                "Account": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+                "SigningPubKey": "0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020",
+                "SourceTag": 6,
+                "TxnSignature": "3045"
+            }}],
+            "SigningPubKey": "",
+            "TransactionType": "AccountSet"
+        });
+        let bytes = crate::json::encode(&json).unwrap();
+
+        let txn: AccountSetTransaction = crate::deserialize::deserialize(&bytes).unwrap();
+        assert_eq!(
+            txn.common.memos[0].unknown_fields.get("SourceTag"),
+            Some(&FieldValue::UInt32(5))
+        );
+        assert_eq!(
+            txn.common.signers[0].unknown_fields.get("SourceTag"),
+            Some(&FieldValue::UInt32(6))
+        );
+        assert_eq!(serialize::serialize(&txn).unwrap(), bytes);
+    }
+
     /// Deserialize to `Transaction` enum type
     #[test]
     fn test_deserialize_as_transaction() {
@@ -1267,4 +1748,515 @@ mod tests {
             assert_eq!(txn.common.account, AccountId::from_address("rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys").unwrap());
         });
     }
+
+    /// Deserialize and serialize transactions again, which must give the same bytes
+    #[test]
+    fn test_deserialize_serialize_transaction_lossless() {
+        let account = AccountId::from_address("rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys").unwrap();
+        let offer_create = hex::decode("120007220008000024001ABED82A2380BF2C2019001ABED764D55920AC9391400000000000000000000000000055534400000000000A20B3C85F482532A9578DBB3950B85CA06594D165400000037E11D60068400000000000000A732103EE83BB432547885C219634A1BC407A9DB0474145D69737D09CCDC63E1DEE7FE3744630440220143759437C04F7B61F012563AFE90D8DAFC46E86035E1D965A9CED282C97D4CE02204CFD241E86F17E011298FC1A39B63386C74306A5DE047E213B0F29EFA4571C2C8114DD76483FACDEE26E60D8A586BB58D09F27045C46").unwrap();
+        // Payment without `Flags` and with a field not modelled by `PaymentTransaction`
+        let payment = serialize::serialize(&Object(vec![
+            ("TransactionType".to_string(), FieldValue::UInt16(0)),
+            ("Account".to_string(), FieldValue::AccountId(account)),
+            ("Destination".to_string(), FieldValue::AccountId(account)),
+            (
+                "Amount".to_string(),
+                FieldValue::Amount(Amount::drops(10).unwrap()),
+            ),
+            ("Sequence".to_string(), FieldValue::UInt32(5)),
+            ("DestinationNode".to_string(), FieldValue::UInt64(7)),
+        ]))
+        .unwrap();
+        // Transaction type modelled by the common fields only
+        let check_cash = serialize::serialize(&Object(vec![
+            ("TransactionType".to_string(), FieldValue::UInt16(17)),
+            ("Account".to_string(), FieldValue::AccountId(account)),
+            ("Flags".to_string(), FieldValue::UInt32(0)),
+            ("CheckID".to_string(), FieldValue::Hash256(Hash256([1; 32]))),
+            (
+                "Amount".to_string(),
+                FieldValue::Amount(Amount::drops(10).unwrap()),
+            ),
+        ]))
+        .unwrap();
+
+        for bytes in [offer_create, payment, check_cash] {
+            let txn: Transaction = crate::deserialize::deserialize(&bytes).unwrap();
+            assert_eq!(serialize::serialize(&txn).unwrap(), bytes);
+        }
+    }
+
+    /// Deserialize transaction with flag bits that are not modelled
+    #[test]
+    fn test_deserialize_transaction_unknown_flags() {
+        let account = AccountId::from_address("rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys").unwrap();
+        let bytes = serialize::serialize(&Object(vec![
+            ("TransactionType".to_string(), FieldValue::UInt16(0)),
+            ("Account".to_string(), FieldValue::AccountId(account)),
+            ("Destination".to_string(), FieldValue::AccountId(account)),
+            (
+                "Amount".to_string(),
+                FieldValue::Amount(Amount::drops(10).unwrap()),
+            ),
+            ("Flags".to_string(), FieldValue::UInt32(0x0002_0001)),
+        ]))
+        .unwrap();
+
+        let txn: Transaction = crate::deserialize::deserialize(&bytes).unwrap();
+        assert_matches!(&txn, Transaction::Payment(txn) => {
+            let flags = txn.flags.unwrap();
+            assert_eq!(flags.flags(), PaymentFlags::PartialPayment);
+            assert_eq!(flags.unknown_bits(), 0x0000_0001);
+        });
+        assert_eq!(serialize::serialize(&txn).unwrap(), bytes);
+    }
+
+    /// Deserialize transaction of a type that is not modelled
+    #[test]
+    fn test_deserialize_unknown_transaction_type() {
+        let account = AccountId::from_address("rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys").unwrap();
+        for transaction_type in [TransactionType::AMMVote as u16, 88] {
+            let bytes = serialize::serialize(&Object(vec![
+                (
+                    "TransactionType".to_string(),
+                    FieldValue::UInt16(transaction_type),
+                ),
+                ("Account".to_string(), FieldValue::AccountId(account)),
+                ("TradingFee".to_string(), FieldValue::UInt16(500)),
+                (
+                    "Memos".to_string(),
+                    FieldValue::Array(vec![(
+                        "Memo".to_string(),
+                        Object(vec![
+                            ("MemoType".to_string(), FieldValue::Blob(Blob(vec![1]))),
+                            ("MemoData".to_string(), FieldValue::Blob(Blob(vec![2]))),
+                        ]),
+                    )]),
+                ),
+            ]))
+            .unwrap();
+
+            let txn: Transaction = crate::deserialize::deserialize(&bytes).unwrap();
+            assert_matches!(&txn, Transaction::Unknown(txn) => {
+                assert_eq!(txn.transaction_type, transaction_type);
+                assert_eq!(txn.common.account, account);
+                assert_eq!(
+                    txn.common.unknown_fields.get("TradingFee"),
+                    Some(&FieldValue::UInt16(500))
+                );
+            });
+            assert_eq!(serialize::serialize(&txn).unwrap(), bytes);
+        }
+    }
+
+    /// Serialize and deserialize `Payment` with `Paths`
+    #[test]
+    fn test_deserialize_payment_with_paths() {
+        let usd = CurrencyCode::standard([AsciiChar::U, AsciiChar::S, AsciiChar::D]).unwrap();
+        let issuer = AccountId::from_address("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B").unwrap();
+        let mut txn_orig = PaymentTransaction::new(
+            AccountId::from_address("rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys").unwrap(),
+            Amount::issued(
+                IssuedValue::from_mantissa_exponent(1, 0).unwrap(),
+                usd,
+                issuer,
+            )
+            .unwrap(),
+            AccountId::from_address("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe").unwrap(),
+        );
+        txn_orig.send_max = Some(Amount::drops(1_000_000).unwrap());
+        txn_orig.paths = Some(PathSet(vec![
+            Path(vec![PathStep::currency(usd, Some(issuer))]),
+            Path(vec![
+                PathStep::account(issuer),
+                PathStep::currency(usd, Some(issuer)),
+            ]),
+        ]));
+
+        let bytes = serialize::serialize(&txn_orig).unwrap();
+        // Paths field id (type 18, field 1) followed by the path set, which is the last field
+        assert!(
+            hex::encode_upper(&bytes).contains("0112300000000000000000000000005553440000000000")
+        );
+        assert_eq!(*bytes.last().unwrap(), 0x00);
+
+        let txn: PaymentTransaction = crate::deserialize::deserialize(&bytes).unwrap();
+        assert_eq!(txn.paths, txn_orig.paths);
+        assert_eq!(serialize::serialize(&txn).unwrap(), bytes);
+    }
+
+    /// Deserialize transaction metadata, with inner objects of any ledger entry type
+    #[test]
+    fn test_deserialize_transaction_metadata() {
+        let json = serde_json::json!({
+            "AffectedNodes": [
+                {
+                    "ModifiedNode": {
+                        "FinalFields": {
+                            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                            "Balance": "99999990",
+                            "Flags": 0,
+                            "OwnerCount": 1,
+                            "Sequence": 361
+                        },
+                        "LedgerEntryType": "AccountRoot",
+                        "LedgerIndex": "1E7E658C2D3DF91EFAE5A12573284AD6F526B8F64DF3E0E2E5C16F2F5F33C0F9",
+                        "PreviousFields": {
+                            "Balance": "100000000",
+                            "Sequence": 360
+                        },
+                        "PreviousTxnID": "4D5D90890F8D49519E4151938601EF3D0B30B16CD6A519D9C99102C9FA77F7E0",
+                        "PreviousTxnLgrSeq": 86513000
+                    }
+                },
+                {
+                    "CreatedNode": {
+                        "LedgerEntryType": "RippleState",
+                        "LedgerIndex": "9C0C9E4B2B4C8F0B5AB6EF6F0A1B5D5E2F9F8A4D8A9E8B1A6C9B7E3D2C1F0A9B",
+                        "NewFields": {
+                            "Balance": {
+                                "currency": "USD",
+                                "issuer": "rrrrrrrrrrrrrrrrrrrrBZbvji",
+                                "value": "-1"
+                            },
+                            "Flags": 131072,
+                            "HighLimit": {
+                                "currency": "USD",
+                                "issuer": "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq",
+                                "value": "0"
+                            },
+                            "LowLimit": {
+                                "currency": "USD",
+                                "issuer": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+                                "value": "1000"
+                            }
+                        }
+                    }
+                },
+                {
+                    "DeletedNode": {
+                        "FinalFields": {
+                            "Account": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+                            "Flags": 0,
+                            "Sequence": 7,
+                            "TakerGets": "1000000",
+                            "TakerPays": {
+                                "currency": "USD",
+                                "issuer": "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq",
+                                "value": "1"
+                            }
+                        },
+                        "LedgerEntryType": "Offer",
+                        "LedgerIndex": "5B3B2E3E5CF4A1C8D9E0F1A2B3C4D5E6F708192A3B4C5D6E7F8091A2B3C4D5E6"
+                    }
+                }
+            ],
+            "DeliveredAmount": "1000000",
+            "TransactionIndex": 12,
+            "TransactionResult": "tesSUCCESS"
+        });
+        let bytes = crate::json::encode(&json).unwrap();
+
+        let meta: TransactionMetaData = crate::deserialize::deserialize(&bytes).unwrap();
+        assert!(meta.is_success());
+        assert_eq!(meta.transaction_index, 12);
+        assert_eq!(
+            meta.delivered_amount,
+            Some(Amount::drops(1_000_000).unwrap())
+        );
+        assert_eq!(meta.affected_nodes.len(), 3);
+        assert_matches!(&meta.affected_nodes[0], AffectedNode::ModifiedNode {
+            ledger_entry_type,
+            final_fields: Some(final_fields),
+            previous_fields: Some(previous_fields),
+            previous_txn_lgr_seq,
+            ..
+        } => {
+            assert_eq!(*ledger_entry_type, 0x0061);
+            assert_eq!(final_fields.get("Sequence"), Some(&FieldValue::UInt32(361)));
+            assert_eq!(previous_fields.get("Balance"), Some(&FieldValue::Amount(Amount::drops(100_000_000).unwrap())));
+            assert_eq!(*previous_txn_lgr_seq, Some(86513000));
+        });
+        assert_matches!(&meta.affected_nodes[1], AffectedNode::CreatedNode {
+            ledger_entry_type,
+            new_fields: Some(new_fields),
+            ..
+        } => {
+            assert_eq!(*ledger_entry_type, 0x0072);
+            assert_eq!(new_fields.0.len(), 4);
+        });
+        assert_matches!(&meta.affected_nodes[2], AffectedNode::DeletedNode {
+            ledger_entry_type,
+            final_fields: Some(_),
+            previous_fields: None,
+            ..
+        } => {
+            assert_eq!(*ledger_entry_type, 0x006F);
+        });
+
+        assert_eq!(serialize::serialize(&meta).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_deserialize_transaction_metadata_missing_field() {
+        // ModifiedNode without LedgerIndex
+        let bytes = hex::decode("201C00000003F8E5110061E72200000000E1E1F1031000").unwrap();
+        let result: Result<TransactionMetaData, _> = crate::deserialize::deserialize(&bytes);
+        assert_matches!(result, Err(BinaryCodecError::MissingField(field)) => {
+            assert_eq!(field, "LedgerIndex");
+        });
+    }
+
+    #[test]
+    fn test_deserialize_transaction_metadata_unknown_node() {
+        // Memo object as element in AffectedNodes
+        let bytes = hex::decode("201C00000003F8EAE1F1031000").unwrap();
+        let result: Result<TransactionMetaData, _> = crate::deserialize::deserialize(&bytes);
+        assert_matches!(result, Err(BinaryCodecError::OutOfRange(message)) => {
+            assert!(message.contains("Unknown affected node: Memo"), "message: {}", message);
+        });
+    }
+
+    /// Deserialize ledger entry, like the `node_binary` of a `ledger_entry` response
+    #[test]
+    fn test_deserialize_ledger_entry_account_root() {
+        let json = serde_json::json!({
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "AccountTxnID": "0D5FB50FA65C9FE1538FD7E398FFFE9D1908DFA4576D8D7A020040686F93C77D",
+            "Balance": "148446663",
+            "Domain": "6D64756F31332E636F6D",
+            "EmailHash": "98B4375E1D753E5B91627516F6D70977",
+            "Flags": 8388608,
+            "LedgerEntryType": "AccountRoot",
+            "MessageKey": "0000000000000000000000070000000300",
+            "OwnerCount": 3,
+            "PreviousTxnID": "0D5FB50FA65C9FE1538FD7E398FFFE9D1908DFA4576D8D7A020040686F93C77D",
+            "PreviousTxnLgrSeq": 14091160,
+            "Sequence": 336,
+            "TransferRate": 1004999999,
+            "index": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8"
+        });
+        let bytes = crate::json::encode(&json).unwrap();
+
+        let entry: LedgerEntry = crate::deserialize::deserialize(&bytes).unwrap();
+        assert_eq!(
+            entry.ledger_entry_type(),
+            Some(LedgerEntryType::AccountRoot)
+        );
+        assert_matches!(&entry, LedgerEntry::AccountRoot(account_root) => {
+            assert_eq!(account_root.account, AccountId::from_address("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap());
+            assert_eq!(account_root.balance, DropsAmount::from_drops(148446663).unwrap());
+            assert_eq!(account_root.flags.flags(), AccountRootFlags::DefaultRipple);
+            assert_eq!(account_root.owner_count, 3);
+            assert_eq!(account_root.sequence, 336);
+            assert_eq!(account_root.previous_txn_lgr_seq, 14091160);
+            assert_eq!(account_root.transfer_rate, Some(1004999999));
+            assert_eq!(account_root.domain, Some(Blob(b"mduo13.com".to_vec())));
+            assert_eq!(account_root.regular_key, None);
+        });
+        assert_eq!(serialize::serialize(&entry).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_deserialize_ledger_entry_ripple_state() {
+        let json = serde_json::json!({
+            "Balance": {
+                "currency": "USD",
+                "issuer": "rrrrrrrrrrrrrrrrrrrrBZbvji",
+                "value": "-10"
+            },
+            "Flags": 393216,
+            "HighLimit": {
+                "currency": "USD",
+                "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "value": "110"
+            },
+            "HighNode": "0000000000000000",
+            "LedgerEntryType": "RippleState",
+            "LowLimit": {
+                "currency": "USD",
+                "issuer": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+                "value": "0"
+            },
+            "LowNode": "0000000000000000",
+            "PreviousTxnID": "E3FE6EA3D48F0C2B639448020EA4F03D4F4F8FFDB243A852A0F59177921B4879",
+            "PreviousTxnLgrSeq": 14090896
+        });
+        let bytes = crate::json::encode(&json).unwrap();
+
+        let entry: LedgerEntry = crate::deserialize::deserialize(&bytes).unwrap();
+        assert_matches!(&entry, LedgerEntry::RippleState(ripple_state) => {
+            assert_eq!(ripple_state.flags.flags(), RippleStateFlags::HighReserve | RippleStateFlags::LowAuth);
+            assert_eq!(ripple_state.balance.value(), IssuedValue::from_mantissa_exponent(-10, 0).unwrap());
+            assert_eq!(ripple_state.high_limit.issuer(), AccountId::from_address("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap());
+            assert_eq!(ripple_state.low_node, Some(0));
+            assert_eq!(ripple_state.high_quality_in, None);
+        });
+        assert_eq!(serialize::serialize(&entry).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_deserialize_ledger_entry_directory_node() {
+        let json = serde_json::json!({
+            "ExchangeRate": "4F069BA8FF484000",
+            "Flags": 0,
+            "Indexes": [
+                "AD7EAE148287EF12D213A251015F86E6D4BD34B3C4A0A1ED9A17198373F908AD"
+            ],
+            "LedgerEntryType": "DirectoryNode",
+            "RootIndex": "1BBEF97EDE88D40CEE2ADE6FEF121166AFE80D99EBADB01A4F069BA8FF484000",
+            "TakerGetsCurrency": "0000000000000000000000000000000000000000",
+            "TakerGetsIssuer": "0000000000000000000000000000000000000000",
+            "TakerPaysCurrency": "0000000000000000000000004A50590000000000",
+            "TakerPaysIssuer": "5BBC0F22F61D9224A110650CFE21CC0C4BE13098"
+        });
+        let bytes = crate::json::encode(&json).unwrap();
+
+        let entry: LedgerEntry = crate::deserialize::deserialize(&bytes).unwrap();
+        assert_matches!(&entry, LedgerEntry::DirectoryNode(directory_node) => {
+            assert_eq!(directory_node.exchange_rate, Some(0x4F069BA8FF484000));
+            assert_eq!(directory_node.indexes.len(), 1);
+            assert_eq!(directory_node.owner, None);
+            assert_eq!(directory_node.previous_txn_id, None);
+        });
+        assert_eq!(serialize::serialize(&entry).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_deserialize_ledger_entry_amm() {
+        let json = serde_json::json!({
+            "Account": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S",
+            "Asset": {
+                "currency": "XRP"
+            },
+            "Asset2": {
+                "currency": "TST",
+                "issuer": "rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd"
+            },
+            "AuctionSlot": {
+                "Account": "rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm",
+                "AuthAccounts": [
+                    {
+                        "AuthAccount": {
+                            "Account": "rMKXGCbJ5d8LbrqthdG46q3f969MVK2Qeg"
+                        }
+                    }
+                ],
+                "DiscountedFee": 60,
+                "Expiration": 721870180,
+                "Price": {
+                    "currency": "039C99CD9AB0B70B32ECDA51EAAE471625608EA2",
+                    "issuer": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S",
+                    "value": "0.8696263565463045"
+                }
+            },
+            "Flags": 0,
+            "LPTokenBalance": {
+                "currency": "039C99CD9AB0B70B32ECDA51EAAE471625608EA2",
+                "issuer": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S",
+                "value": "71150.53584131501"
+            },
+            "LedgerEntryType": "AMM",
+            "OwnerNode": "0000000000000000",
+            "TradingFee": 600,
+            "VoteSlots": [
+                {
+                    "VoteEntry": {
+                        "Account": "rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm",
+                        "TradingFee": 600,
+                        "VoteWeight": 100000
+                    }
+                }
+            ]
+        });
+        let bytes = crate::json::encode(&json).unwrap();
+
+        let entry: LedgerEntry = crate::deserialize::deserialize(&bytes).unwrap();
+        assert_matches!(&entry, LedgerEntry::AMM(amm) => {
+            assert!(amm.asset.is_xrp());
+            assert!(amm.asset2.is_issued());
+            assert_eq!(amm.trading_fee, 600);
+            assert_eq!(amm.vote_slots.len(), 1);
+            assert_eq!(amm.vote_slots[0].vote_weight, 100000);
+            assert_matches!(&amm.auction_slot, Some(auction_slot) => {
+                assert_eq!(auction_slot.discounted_fee, 60);
+                assert_eq!(auction_slot.auth_accounts, vec![AccountId::from_address("rMKXGCbJ5d8LbrqthdG46q3f969MVK2Qeg").unwrap()]);
+            });
+        });
+        assert_eq!(serialize::serialize(&entry).unwrap(), bytes);
+    }
+
+    /// Deserialize ledger entry with a field that is not modelled
+    #[test]
+    fn test_deserialize_ledger_entry_unknown_field() {
+        let json = serde_json::json!({
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "Balance": "148446663",
+            "Flags": 0,
+            "HookStateCount": 2,
+            "LedgerEntryType": "AccountRoot",
+            "OwnerCount": 3,
+            "PreviousTxnID": "0D5FB50FA65C9FE1538FD7E398FFFE9D1908DFA4576D8D7A020040686F93C77D",
+            "PreviousTxnLgrSeq": 14091160,
+            "Sequence": 336
+        });
+        let bytes = crate::json::encode(&json).unwrap();
+
+        let entry: LedgerEntry = crate::deserialize::deserialize(&bytes).unwrap();
+        assert_matches!(&entry, LedgerEntry::AccountRoot(account_root) => {
+            assert_eq!(account_root.sequence, 336);
+            assert_eq!(account_root.unknown_fields.get("HookStateCount"), Some(&FieldValue::UInt32(2)));
+        });
+        assert_eq!(serialize::serialize(&entry).unwrap(), bytes);
+    }
+
+    /// Deserialize ledger entry with flag bits that are not modelled
+    #[test]
+    fn test_deserialize_ledger_entry_unknown_flags() {
+        let json = serde_json::json!({
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "Balance": "148446663",
+            "Flags": 0x0080_0001,
+            "LedgerEntryType": "AccountRoot",
+            "OwnerCount": 3,
+            "PreviousTxnID": "0D5FB50FA65C9FE1538FD7E398FFFE9D1908DFA4576D8D7A020040686F93C77D",
+            "PreviousTxnLgrSeq": 14091160,
+            "Sequence": 336
+        });
+        let bytes = crate::json::encode(&json).unwrap();
+
+        let entry: LedgerEntry = crate::deserialize::deserialize(&bytes).unwrap();
+        assert_matches!(&entry, LedgerEntry::AccountRoot(account_root) => {
+            assert_eq!(account_root.flags.flags(), AccountRootFlags::DefaultRipple);
+            assert_eq!(account_root.flags.unknown_bits(), 0x0000_0001);
+        });
+        assert_eq!(serialize::serialize(&entry).unwrap(), bytes);
+    }
+
+    /// Deserialize ledger entry of a type that is not modelled
+    #[test]
+    fn test_deserialize_ledger_entry_unknown_type() {
+        // Bridge ledger entry, which is not modelled
+        let bytes = hex::decode("1100692200000000").unwrap();
+        let entry: LedgerEntry = crate::deserialize::deserialize(&bytes).unwrap();
+        assert_eq!(entry.ledger_entry_type(), Some(LedgerEntryType::Bridge));
+        assert_matches!(&entry, LedgerEntry::Unknown { ledger_entry_type, fields } => {
+            assert_eq!(*ledger_entry_type, LedgerEntryType::Bridge as u16);
+            assert_eq!(fields, &Object(vec![("Flags".to_string(), FieldValue::UInt32(0))]));
+        });
+        assert_eq!(serialize::serialize(&entry).unwrap(), bytes);
+
+        // Ledger entry type that is not known
+        let bytes = hex::decode("1100012200000000").unwrap();
+        let entry: LedgerEntry = crate::deserialize::deserialize(&bytes).unwrap();
+        assert_eq!(entry.ledger_entry_type(), None);
+        assert_matches!(
+            &entry,
+            LedgerEntry::Unknown {
+                ledger_entry_type: 1,
+                ..
+            }
+        );
+        assert_eq!(serialize::serialize(&entry).unwrap(), bytes);
+    }
 }
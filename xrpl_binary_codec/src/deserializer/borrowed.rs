@@ -0,0 +1,777 @@
+use crate::alloc::format;
+use crate::alloc::vec::Vec;
+use crate::error::BinaryCodecError;
+pub use crate::field::{FieldCode, FieldId, TypeCode};
+use xrpl_types::deserialize::FieldAccessor as _;
+use xrpl_types::{
+    AccountId, Amount, Currency, FieldValue, Hash128, Hash160, Hash256, PathSet, UInt16, UInt32,
+    UInt64, UInt8, XChainBridge,
+};
+
+/// Deserializer reading fields directly from a byte slice. Fields are passed to the visitor by
+/// [`FieldId`], without looking up field names, and variable length values are borrowed from the
+/// input instead of copied. Only fields in the built-in definitions are accepted, looked up in a
+/// static table.
+#[derive(Debug, Clone)]
+pub struct Deserializer<'a> {
+    reader: super::Deserializer<'static, &'a [u8]>,
+    /// All fields have been read, including the object end marker of an inner object
+    ended: bool,
+}
+
+/// Visitor receiving the fields of an object in the order they appear in the bytes
+pub trait Visitor<'a> {
+    fn visit_field(
+        &mut self,
+        field_id: FieldId,
+        field_accessor: FieldAccessor<'a>,
+    ) -> Result<(), BinaryCodecError>;
+
+    /// Visits an inner object. If the object is not deserialized, it is skipped
+    fn visit_object(
+        &mut self,
+        _field_id: FieldId,
+        _deserializer: &mut Deserializer<'a>,
+    ) -> Result<(), BinaryCodecError> {
+        Ok(())
+    }
+
+    /// Visits an array of objects. Objects that are not deserialized are skipped
+    fn visit_array(
+        &mut self,
+        _field_id: FieldId,
+        _array_deserializer: &mut ArrayDeserializer<'a>,
+    ) -> Result<(), BinaryCodecError> {
+        Ok(())
+    }
+}
+
+impl<'a> Deserializer<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            reader: super::Deserializer::new(bytes),
+            ended: false,
+        }
+    }
+
+    /// Passes the remaining fields to the visitor. For an inner object, reads until the object
+    /// end marker
+    pub fn deserialize<V: Visitor<'a>>(&mut self, visitor: &mut V) -> Result<(), BinaryCodecError> {
+        while let Some(field_id) = self.next_field()? {
+            match field_id.type_code {
+                TypeCode::Array => {
+                    let mut element = self.object_deserializer();
+                    element.ended = true;
+                    let mut array_deserializer = ArrayDeserializer {
+                        element,
+                        ended: false,
+                    };
+                    visitor.visit_array(field_id, &mut array_deserializer)?;
+                    array_deserializer.skip_remaining()?;
+                    self.reader.bytes = array_deserializer.element.reader.bytes;
+                }
+                TypeCode::Object => {
+                    let mut object_deserializer = self.object_deserializer();
+                    visitor.visit_object(field_id, &mut object_deserializer)?;
+                    object_deserializer.skip_remaining()?;
+                    self.reader.bytes = object_deserializer.reader.bytes;
+                }
+                type_code => {
+                    let bytes = self.read_value_bytes(type_code)?;
+                    visitor.visit_field(field_id, FieldAccessor { type_code, bytes })?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Deserializer for inner object, starting at the current position and reading until the
+    /// object end marker
+    fn object_deserializer(&self) -> Deserializer<'a> {
+        Deserializer {
+            reader: super::Deserializer {
+                bytes: self.reader.bytes,
+                object_deserializer: true,
+                previous_field_id: None,
+                definitions: self.reader.definitions,
+            },
+            ended: false,
+        }
+    }
+
+    fn next_field(&mut self) -> Result<Option<FieldId>, BinaryCodecError> {
+        if self.ended {
+            return Ok(None);
+        }
+        let Some(field_id) = self.reader.next_field_id()? else {
+            self.ended = true;
+            return Ok(None);
+        };
+        check_known_field(field_id)?;
+        Ok(Some(field_id))
+    }
+
+    fn skip_remaining(&mut self) -> Result<(), BinaryCodecError> {
+        self.deserialize(&mut SkipVisitor)
+    }
+
+    /// Reads past the value of a field of the given type and returns the encoded value
+    fn read_value_bytes(&mut self, type_code: TypeCode) -> Result<&'a [u8], BinaryCodecError> {
+        let bytes = self.reader.bytes;
+        skip_value(&mut self.reader, type_code)?;
+        Ok(&bytes[..bytes.len() - self.reader.bytes.len()])
+    }
+}
+
+/// Deserializer for the objects in an array field
+#[derive(Debug)]
+pub struct ArrayDeserializer<'a> {
+    /// Deserializer for the current object, reused for each object in the array
+    element: Deserializer<'a>,
+    ended: bool,
+}
+
+impl<'a> ArrayDeserializer<'a> {
+    /// Returns the field id of the next object in the array and a deserializer for it. Returns
+    /// `None` at the array end marker. The remaining fields of the previous object are skipped
+    pub fn next_object(
+        &mut self,
+    ) -> Result<Option<(FieldId, &mut Deserializer<'a>)>, BinaryCodecError> {
+        if self.ended {
+            return Ok(None);
+        }
+        self.element.skip_remaining()?;
+        let Some(field_id) = self.element.reader.next_array_element_id()? else {
+            self.ended = true;
+            return Ok(None);
+        };
+        check_known_field(field_id)?;
+        self.element.reader.previous_field_id = None;
+        self.element.ended = false;
+        Ok(Some((field_id, &mut self.element)))
+    }
+
+    fn skip_remaining(&mut self) -> Result<(), BinaryCodecError> {
+        while self.next_object()?.is_some() {}
+        Ok(())
+    }
+}
+
+/// Accessor for the value of a field. The value is decoded when accessed
+#[derive(Debug, Clone, Copy)]
+pub struct FieldAccessor<'a> {
+    type_code: TypeCode,
+    bytes: &'a [u8],
+}
+
+impl<'a> FieldAccessor<'a> {
+    pub fn type_code(&self) -> TypeCode {
+        self.type_code
+    }
+
+    /// Encoded value, including the length prefix of variable length values
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    fn reader(
+        &self,
+        expected_type_code: TypeCode,
+    ) -> Result<super::Deserializer<'static, &'a [u8]>, BinaryCodecError> {
+        if self.type_code != expected_type_code {
+            return Err(BinaryCodecError::InvalidField(format!(
+                "Expected type {}, found {}",
+                expected_type_code, self.type_code
+            )));
+        }
+        Ok(super::Deserializer::new(self.bytes))
+    }
+
+    pub fn deserialize_account_id(self) -> Result<AccountId, BinaryCodecError> {
+        self.reader(TypeCode::AccountId)?.read_account_id()
+    }
+
+    pub fn deserialize_amount(self) -> Result<Amount, BinaryCodecError> {
+        self.reader(TypeCode::Amount)?.read_amount()
+    }
+
+    /// Blob borrowed from the deserialized bytes
+    pub fn deserialize_blob(self) -> Result<Blob<'a>, BinaryCodecError> {
+        let mut reader = self.reader(TypeCode::Blob)?;
+        reader.read_vl_prefix()?;
+        Ok(Blob(reader.bytes))
+    }
+
+    pub fn deserialize_hash128(self) -> Result<Hash128, BinaryCodecError> {
+        self.reader(TypeCode::Hash128)?.read_h128()
+    }
+
+    pub fn deserialize_hash160(self) -> Result<Hash160, BinaryCodecError> {
+        self.reader(TypeCode::Hash160)?.read_h160()
+    }
+
+    pub fn deserialize_hash256(self) -> Result<Hash256, BinaryCodecError> {
+        self.reader(TypeCode::Hash256)?.read_h256()
+    }
+
+    pub fn deserialize_uint8(self) -> Result<UInt8, BinaryCodecError> {
+        self.reader(TypeCode::UInt8)?.read_uint8()
+    }
+
+    pub fn deserialize_uint16(self) -> Result<UInt16, BinaryCodecError> {
+        self.reader(TypeCode::UInt16)?.read_uint16()
+    }
+
+    pub fn deserialize_uint32(self) -> Result<UInt32, BinaryCodecError> {
+        self.reader(TypeCode::UInt32)?.read_uint32()
+    }
+
+    pub fn deserialize_uint64(self) -> Result<UInt64, BinaryCodecError> {
+        self.reader(TypeCode::UInt64)?.read_uint64()
+    }
+
+    pub fn deserialize_path_set(self) -> Result<PathSet, BinaryCodecError> {
+        self.reader(TypeCode::PathSet)?.read_path_set()
+    }
+
+    /// Hashes in the vector, decoded from the deserialized bytes while iterating
+    pub fn deserialize_vector256(
+        self,
+    ) -> Result<impl ExactSizeIterator<Item = Hash256> + 'a, BinaryCodecError> {
+        let mut reader = self.reader(TypeCode::Vector256)?;
+        let len = reader.read_vl_prefix()?;
+        if len % 32 != 0 {
+            return Err(BinaryCodecError::InvalidLength(format!(
+                "Vector256 length must be a multiple of 32: {}",
+                len
+            )));
+        }
+        Ok(reader.bytes.chunks_exact(32).map(|chunk| {
+            let mut hash = Hash256([0; 32]);
+            hash.0.copy_from_slice(chunk);
+            hash
+        }))
+    }
+
+    pub fn deserialize_issue(self) -> Result<Currency, BinaryCodecError> {
+        self.reader(TypeCode::Issue)?.read_issue()
+    }
+
+    pub fn deserialize_xchain_bridge(self) -> Result<XChainBridge, BinaryCodecError> {
+        self.reader(TypeCode::XChainBridge)?.read_xchain_bridge()
+    }
+
+    /// Owned value of the field. Variable length values are copied
+    pub fn deserialize_any(self) -> Result<FieldValue, BinaryCodecError> {
+        super::FieldAccessor {
+            deserializer: &mut super::Deserializer::new(self.bytes),
+            type_code: self.type_code,
+        }
+        .deserialize_any()
+    }
+}
+
+/// Variable length value borrowed from the deserialized bytes <https://xrpl.org/serialization.html#blob-fields>
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Blob<'a>(pub &'a [u8]);
+
+impl<'a> Blob<'a> {
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+}
+
+impl AsRef<[u8]> for Blob<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+impl From<Blob<'_>> for xrpl_types::Blob {
+    fn from(blob: Blob<'_>) -> Self {
+        xrpl_types::Blob(blob.0.to_vec())
+    }
+}
+
+/// Field of a serialized object, split from the bytes without decoding the value or looking up
+/// the field. Unlike [`Deserializer`], fields that are not in the definitions are accepted
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct RawField<'a> {
+    pub field_id: FieldId,
+    /// Encoded field, including the field header
+    pub bytes: &'a [u8],
+    /// Encoded value, including the length prefix of variable length values and the end marker
+    /// of inner objects and arrays
+    pub value: &'a [u8],
+}
+
+impl<'a> RawField<'a> {
+    /// Accessor for the value of a field that is neither an inner object nor an array
+    pub fn field_accessor(&self) -> FieldAccessor<'a> {
+        FieldAccessor {
+            type_code: self.field_id.type_code,
+            bytes: self.value,
+        }
+    }
+
+    /// Fields of an inner object, or objects of an array
+    pub fn inner_fields(&self) -> Result<Vec<RawField<'a>>, BinaryCodecError> {
+        let mut reader = super::Deserializer::new(self.value);
+        match self.field_id.type_code {
+            TypeCode::Object => {
+                reader.object_deserializer = true;
+                read_raw_fields(&mut reader)
+            }
+            TypeCode::Array => {
+                let mut elements = Vec::new();
+                loop {
+                    let bytes = reader.bytes;
+                    let Some(field_id) = reader.next_array_element_id()? else {
+                        return Ok(elements);
+                    };
+                    let value = reader.bytes;
+                    skip_any_value(&mut reader, field_id.type_code)?;
+                    elements.push(RawField::new(field_id, bytes, value, reader.bytes));
+                }
+            }
+            type_code => Err(BinaryCodecError::InvalidField(format!(
+                "Field of type {} has no inner fields",
+                type_code
+            ))),
+        }
+    }
+
+    /// Field starting at `bytes`, with the value starting at `value` and ending at `rest`
+    fn new(field_id: FieldId, bytes: &'a [u8], value: &'a [u8], rest: &[u8]) -> Self {
+        Self {
+            field_id,
+            bytes: &bytes[..bytes.len() - rest.len()],
+            value: &value[..value.len() - rest.len()],
+        }
+    }
+}
+
+/// Splits the serialized object into its top level fields. The field order is checked, but values
+/// are only read as far as needed to find their length, such that the fields can be re-emitted
+/// exactly as they were serialized
+pub fn raw_fields(bytes: &[u8]) -> Result<Vec<RawField<'_>>, BinaryCodecError> {
+    read_raw_fields(&mut super::Deserializer::new(bytes))
+}
+
+fn read_raw_fields<'a>(
+    reader: &mut super::Deserializer<'static, &'a [u8]>,
+) -> Result<Vec<RawField<'a>>, BinaryCodecError> {
+    let mut fields = Vec::new();
+    loop {
+        let bytes = reader.bytes;
+        let Some(field_id) = reader.next_field_id()? else {
+            return Ok(fields);
+        };
+        let value = reader.bytes;
+        skip_any_value(reader, field_id.type_code)?;
+        fields.push(RawField::new(field_id, bytes, value, reader.bytes));
+    }
+}
+
+/// Reads past a value of the given type, including inner objects and arrays with their end marker
+fn skip_any_value(
+    reader: &mut super::Deserializer<'static, &[u8]>,
+    type_code: TypeCode,
+) -> Result<(), BinaryCodecError> {
+    match type_code {
+        TypeCode::Object => {
+            let mut object_reader = super::Deserializer::new(reader.bytes);
+            object_reader.object_deserializer = true;
+            while let Some(field_id) = object_reader.next_field_id()? {
+                skip_any_value(&mut object_reader, field_id.type_code)?;
+            }
+            reader.bytes = object_reader.bytes;
+            Ok(())
+        }
+        TypeCode::Array => {
+            while let Some(field_id) = reader.next_array_element_id()? {
+                skip_any_value(reader, field_id.type_code)?;
+            }
+            Ok(())
+        }
+        type_code => skip_value(reader, type_code),
+    }
+}
+
+/// Visitor skipping all fields
+struct SkipVisitor;
+
+impl<'a> Visitor<'a> for SkipVisitor {
+    fn visit_field(
+        &mut self,
+        _field_id: FieldId,
+        _field_accessor: FieldAccessor<'a>,
+    ) -> Result<(), BinaryCodecError> {
+        Ok(())
+    }
+}
+
+fn check_known_field(field_id: FieldId) -> Result<(), BinaryCodecError> {
+    match field_id.name() {
+        Some(_) => Ok(()),
+        None => Err(BinaryCodecError::InvalidField(format!(
+            "Field with id {:?} is not known",
+            field_id
+        ))),
+    }
+}
+
+/// Reads past a value of the given type, only decoding the parts that determine the length
+/// <https://xrpl.org/serialization.html#type-list>
+fn skip_value(
+    reader: &mut super::Deserializer<&[u8]>,
+    type_code: TypeCode,
+) -> Result<(), BinaryCodecError> {
+    match type_code {
+        TypeCode::UInt8 => skip_bytes(reader, 1),
+        TypeCode::UInt16 => skip_bytes(reader, 2),
+        TypeCode::UInt32 => skip_bytes(reader, 4),
+        TypeCode::UInt64 => skip_bytes(reader, 8),
+        TypeCode::Hash128 => skip_bytes(reader, 16),
+        TypeCode::Hash160 => skip_bytes(reader, 20),
+        TypeCode::Hash256 => skip_bytes(reader, 32),
+        TypeCode::AccountId | TypeCode::Blob | TypeCode::Vector256 => {
+            let len = reader.read_vl_prefix()?;
+            skip_bytes(reader, len)
+        }
+        TypeCode::Amount => {
+            // Issued amounts have the first bit set and are followed by currency code and issuer
+            // <https://xrpl.org/serialization.html#amount-fields>
+            let issued = reader.bytes.first().is_some_and(|byte| byte & 0x80 != 0);
+            skip_bytes(reader, if issued { 48 } else { 8 })
+        }
+        TypeCode::Issue => {
+            // XRP has no issuer <https://xrpl.org/serialization.html#issue-fields>
+            let xrp = reader.bytes.get(..20).is_some_and(|code| code == [0; 20]);
+            skip_bytes(reader, if xrp { 20 } else { 40 })
+        }
+        TypeCode::XChainBridge => {
+            skip_value(reader, TypeCode::AccountId)?;
+            skip_value(reader, TypeCode::Issue)?;
+            skip_value(reader, TypeCode::AccountId)?;
+            skip_value(reader, TypeCode::Issue)
+        }
+        TypeCode::PathSet => loop {
+            match reader.read_u8()? {
+                crate::field::PATH_SET_END_BYTE => return Ok(()),
+                crate::field::PATH_SEPARATOR_BYTE => {}
+                step_type => {
+                    reader.read_path_step(step_type)?;
+                }
+            }
+        },
+        TypeCode::Object | TypeCode::Array => Err(BinaryCodecError::InvalidField(format!(
+            "Field of type {} cannot be deserialized as single value",
+            type_code
+        ))),
+    }
+}
+
+fn skip_bytes(reader: &mut super::Deserializer<&[u8]>, len: usize) -> Result<(), BinaryCodecError> {
+    reader.check_remaining(len, "skip_bytes")?;
+    reader.bytes = &reader.bytes[len..];
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alloc::vec;
+    use crate::alloc::vec::Vec;
+    use crate::serialize;
+    use ascii::AsciiChar;
+    use assert_matches::assert_matches;
+    use xrpl_types::{
+        AccountSetTransaction, CurrencyCode, DropsAmount, IssuedValue, Memo, Object, Path,
+        PathStep, PaymentTransaction, Transaction,
+    };
+
+    const SEQUENCE: FieldId = FieldId::from_type_field(TypeCode::UInt32, FieldCode(4));
+    const MEMO: FieldId = FieldId::from_type_field(TypeCode::Object, FieldCode(10));
+    const MEMO_DATA: FieldId = FieldId::from_type_field(TypeCode::Blob, FieldCode(13));
+    const MEMOS: FieldId = FieldId::from_type_field(TypeCode::Array, FieldCode(9));
+    const PATHS: FieldId = FieldId::from_type_field(TypeCode::PathSet, FieldCode(1));
+
+    /// Collects all top level field values, and the `MemoData` of memos
+    #[derive(Default)]
+    struct TestVisitor<'a> {
+        fields: Vec<(FieldId, FieldValue)>,
+        memo_data: Vec<Blob<'a>>,
+    }
+
+    impl<'a> Visitor<'a> for TestVisitor<'a> {
+        fn visit_field(
+            &mut self,
+            field_id: FieldId,
+            field_accessor: FieldAccessor<'a>,
+        ) -> Result<(), BinaryCodecError> {
+            self.fields
+                .push((field_id, field_accessor.deserialize_any()?));
+            Ok(())
+        }
+
+        fn visit_array(
+            &mut self,
+            field_id: FieldId,
+            array_deserializer: &mut ArrayDeserializer<'a>,
+        ) -> Result<(), BinaryCodecError> {
+            assert_eq!(field_id, MEMOS);
+            while let Some((field_id, deserializer)) = array_deserializer.next_object()? {
+                assert_eq!(field_id, MEMO);
+                deserializer.deserialize(&mut MemoDataVisitor(&mut self.memo_data))?;
+            }
+            Ok(())
+        }
+    }
+
+    struct MemoDataVisitor<'a, 'b>(&'b mut Vec<Blob<'a>>);
+
+    impl<'a> Visitor<'a> for MemoDataVisitor<'a, '_> {
+        fn visit_field(
+            &mut self,
+            field_id: FieldId,
+            field_accessor: FieldAccessor<'a>,
+        ) -> Result<(), BinaryCodecError> {
+            if field_id == MEMO_DATA {
+                self.0.push(field_accessor.deserialize_blob()?);
+            }
+            Ok(())
+        }
+    }
+
+    /// Records the ids of top level fields only
+    #[derive(Default)]
+    struct FieldIdVisitor(Vec<FieldId>);
+
+    impl<'a> Visitor<'a> for FieldIdVisitor {
+        fn visit_field(
+            &mut self,
+            field_id: FieldId,
+            _field_accessor: FieldAccessor<'a>,
+        ) -> Result<(), BinaryCodecError> {
+            self.0.push(field_id);
+            Ok(())
+        }
+
+        fn visit_array(
+            &mut self,
+            field_id: FieldId,
+            _array_deserializer: &mut ArrayDeserializer<'a>,
+        ) -> Result<(), BinaryCodecError> {
+            self.0.push(field_id);
+            Ok(())
+        }
+    }
+
+    fn transaction_with_memos() -> AccountSetTransaction {
+        let mut txn = AccountSetTransaction::new(
+            AccountId::from_address("rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys").unwrap(),
+        );
+        txn.common.fee = Some(DropsAmount::from_drops(12).unwrap());
+        txn.common.sequence = Some(5);
+        txn.common.memos.push(Memo {
+            memo_type: Some(xrpl_types::Blob(vec![0, 1])),
+            memo_data: Some(xrpl_types::Blob(vec![2, 3])),
+            memo_format: None,
+            unknown_fields: Object::default(),
+        });
+        txn.common.memos.push(Memo {
+            memo_type: Some(xrpl_types::Blob(vec![4, 5])),
+            memo_data: Some(xrpl_types::Blob(vec![6, 7])),
+            memo_format: None,
+            unknown_fields: Object::default(),
+        });
+        txn
+    }
+
+    #[test]
+    fn test_field_id_name() {
+        assert_eq!(
+            FieldId::from_type_field(TypeCode::Blob, FieldCode(3)).name(),
+            Some("SigningPubKey")
+        );
+        assert_eq!(
+            FieldId::from_type_field(TypeCode::UInt32, FieldCode(200)).name(),
+            None
+        );
+    }
+
+    /// Field values are the same as with the owned deserializer, and blobs in inner objects are
+    /// borrowed from the input bytes
+    #[test]
+    fn test_deserialize_borrowed() {
+        let bytes = serialize::serialize(&transaction_with_memos()).unwrap();
+
+        let mut visitor = TestVisitor::default();
+        Deserializer::new(&bytes).deserialize(&mut visitor).unwrap();
+
+        let names: Vec<_> = visitor
+            .fields
+            .iter()
+            .map(|(field_id, _)| field_id.name().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            ["TransactionType", "Flags", "Sequence", "Fee", "Account"]
+        );
+        assert_eq!(visitor.fields[2], (SEQUENCE, FieldValue::UInt32(5)));
+        assert_eq!(visitor.memo_data, [Blob(&[2, 3]), Blob(&[6, 7])]);
+        for blob in &visitor.memo_data {
+            assert!(bytes.as_ptr_range().contains(&blob.as_bytes().as_ptr()));
+        }
+
+        let txn: Transaction = crate::deserialize::deserialize(&bytes).unwrap();
+        assert_matches!(txn, Transaction::AccountSet(txn) => {
+            assert_eq!(txn.common.memos[1].memo_data, Some(xrpl_types::Blob::from(visitor.memo_data[1])));
+        });
+    }
+
+    /// Arrays that are not visited are skipped, such that fields after the array are read
+    #[test]
+    fn test_deserialize_skip_array() {
+        let usd = CurrencyCode::standard([AsciiChar::U, AsciiChar::S, AsciiChar::D]).unwrap();
+        let issuer = AccountId::from_address("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B").unwrap();
+        let mut txn = PaymentTransaction::new(
+            AccountId::from_address("rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys").unwrap(),
+            Amount::issued(
+                IssuedValue::from_mantissa_exponent(1, 0).unwrap(),
+                usd,
+                issuer,
+            )
+            .unwrap(),
+            AccountId::from_address("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe").unwrap(),
+        );
+        txn.common.memos = transaction_with_memos().common.memos;
+        txn.paths = Some(PathSet(vec![Path(vec![PathStep::currency(
+            usd,
+            Some(issuer),
+        )])]));
+        let bytes = serialize::serialize(&txn).unwrap();
+
+        let mut visitor = FieldIdVisitor::default();
+        Deserializer::new(&bytes).deserialize(&mut visitor).unwrap();
+        assert_eq!(visitor.0[visitor.0.len() - 2..], [MEMOS, PATHS]);
+
+        let mut visitor = TestVisitor::default();
+        Deserializer::new(&bytes).deserialize(&mut visitor).unwrap();
+        assert_eq!(
+            visitor.fields.last(),
+            Some(&(PATHS, FieldValue::PathSet(txn.paths.unwrap())))
+        );
+        assert_eq!(visitor.memo_data.len(), 2);
+    }
+
+    #[test]
+    fn test_deserialize_unknown_field() {
+        let mut visitor = FieldIdVisitor::default();
+        let result = Deserializer::new(&[0x20, 0xC8, 0, 0, 0, 1]).deserialize(&mut visitor);
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("not known"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_raw_fields() {
+        let bytes = serialize::serialize(&transaction_with_memos()).unwrap();
+        let fields = raw_fields(&bytes).unwrap();
+        assert_eq!(
+            fields
+                .iter()
+                .map(|field| field.bytes)
+                .collect::<Vec<_>>()
+                .concat(),
+            bytes
+        );
+
+        let memos = fields.iter().find(|field| field.field_id == MEMOS).unwrap();
+        let elements = memos.inner_fields().unwrap();
+        assert_eq!(elements.len(), 2);
+        assert_eq!(elements[1].field_id, MEMO);
+        let memo_fields = elements[1].inner_fields().unwrap();
+        let memo_data = memo_fields
+            .iter()
+            .find(|field| field.field_id == MEMO_DATA)
+            .unwrap();
+        assert_eq!(memo_data.bytes, [0x7D, 2, 6, 7]);
+        assert_eq!(
+            memo_data.field_accessor().deserialize_blob().unwrap(),
+            Blob(&[6, 7])
+        );
+    }
+
+    #[test]
+    fn test_raw_fields_unknown_field() {
+        let bytes = [0x20, 0xC8, 0, 0, 0, 1];
+        let fields = raw_fields(&bytes).unwrap();
+        assert_eq!(
+            fields,
+            [RawField {
+                field_id: FieldId::from_type_field(TypeCode::UInt32, FieldCode(200)),
+                bytes: &bytes,
+                value: &bytes[2..],
+            }]
+        );
+
+        let result = raw_fields(&bytes[..5]);
+        assert_matches!(result, Err(BinaryCodecError::InsufficientBytes(_)));
+    }
+
+    #[test]
+    fn test_deserialize_insufficient_bytes() {
+        let mut visitor = FieldIdVisitor::default();
+        // SigningPubKey with length 5, but only 2 bytes
+        let result = Deserializer::new(&[0x73, 0x05, 1, 2]).deserialize(&mut visitor);
+        assert_matches!(result, Err(BinaryCodecError::InsufficientBytes(_)));
+    }
+
+    #[test]
+    fn test_deserialize_field_wrong_type() {
+        struct Uint16Visitor;
+
+        impl<'a> Visitor<'a> for Uint16Visitor {
+            fn visit_field(
+                &mut self,
+                _field_id: FieldId,
+                field_accessor: FieldAccessor<'a>,
+            ) -> Result<(), BinaryCodecError> {
+                field_accessor.deserialize_uint16()?;
+                Ok(())
+            }
+        }
+
+        // Sequence of type UInt32
+        let result = Deserializer::new(&[0x24, 0, 0, 0, 5]).deserialize(&mut Uint16Visitor);
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert_eq!(message, "Expected type UInt16, found UInt32");
+        });
+    }
+
+    #[test]
+    fn test_deserialize_vector256() {
+        struct Vector256Visitor(Vec<Hash256>);
+
+        impl<'a> Visitor<'a> for Vector256Visitor {
+            fn visit_field(
+                &mut self,
+                _field_id: FieldId,
+                field_accessor: FieldAccessor<'a>,
+            ) -> Result<(), BinaryCodecError> {
+                self.0.extend(field_accessor.deserialize_vector256()?);
+                Ok(())
+            }
+        }
+
+        // Indexes with two hashes
+        let mut bytes = vec![0x01, 0x13, 64];
+        bytes.extend([1; 32]);
+        bytes.extend([2; 32]);
+        let mut visitor = Vector256Visitor(Vec::new());
+        Deserializer::new(&bytes).deserialize(&mut visitor).unwrap();
+        assert_eq!(visitor.0, [Hash256([1; 32]), Hash256([2; 32])]);
+    }
+}
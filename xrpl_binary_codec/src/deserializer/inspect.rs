@@ -0,0 +1,401 @@
+use crate::alloc::vec::Vec;
+use crate::definitions::Definitions;
+use crate::error::BinaryCodecError;
+pub use crate::field::{FieldCode, FieldId, TypeCode};
+use core::fmt;
+use xrpl_types::deserialize::FieldAccessor as _;
+use xrpl_types::FieldValue;
+
+/// Result of inspecting a binary blob: the fields read, with their byte offsets, and the error
+/// that stopped reading the blob, if any. Formatting with [`fmt::Display`] prints one line per
+/// field with the offset, the header and length prefix bytes, the field name and the value
+#[derive(Debug, Clone, PartialEq)]
+pub struct Inspection<'a> {
+    /// The inspected blob
+    pub bytes: &'a [u8],
+    /// Fields read before any error. Inner objects and arrays that the error occurred in hold
+    /// the fields read before the error
+    pub fields: Vec<InspectedField<'a>>,
+    pub error: Option<InspectError>,
+}
+
+/// Field in an inspected blob
+#[derive(Debug, Clone, PartialEq)]
+pub struct InspectedField<'a> {
+    /// Byte offset of the field header <https://xrpl.org/serialization.html#field-ids>
+    pub offset: usize,
+    /// Type code and field code read from the field header
+    pub field_id: FieldId,
+    /// Length of the field header in bytes
+    pub header_length: usize,
+    /// Name of the field in the definitions, `None` if the field is not known
+    pub field_name: Option<&'a str>,
+    /// Length given by the length prefix of variable length values
+    /// <https://xrpl.org/serialization.html#length-prefixing>
+    pub vl_length: Option<usize>,
+    /// Byte offset of the value, after the header and the length prefix
+    pub value_offset: usize,
+    /// Byte offset after the field, including the end marker of inner objects and arrays
+    pub end_offset: usize,
+    pub value: InspectedValue<'a>,
+}
+
+/// Decoded value of an inspected field
+#[derive(Debug, Clone, PartialEq)]
+pub enum InspectedValue<'a> {
+    Value(FieldValue),
+    /// Fields of an inner object
+    Object(Vec<InspectedField<'a>>),
+    /// Elements of an array, each an object field
+    Array(Vec<InspectedField<'a>>),
+}
+
+/// Error reading an inspected blob
+#[derive(Debug, Clone, PartialEq)]
+pub struct InspectError {
+    /// Byte offset of the field in which the error occurred, or of the position at which a field
+    /// header or end marker was expected
+    pub offset: usize,
+    pub error: BinaryCodecError,
+}
+
+/// Reads all fields in the given blob, looking up field names in the built-in definitions.
+/// Fields are read until the end of the blob or until the first error, such that malformed
+/// blobs can be inspected up to the point where they are malformed. Fields not in the
+/// definitions are decoded by their type code
+pub fn inspect(bytes: &[u8]) -> Inspection<'_> {
+    inspect_with_definitions(bytes, Definitions::builtin())
+}
+
+/// Reads all fields in the given blob, looking up field names in the given definitions
+pub fn inspect_with_definitions<'a>(
+    bytes: &'a [u8],
+    definitions: &'a Definitions,
+) -> Inspection<'a> {
+    let inspector = Inspector { bytes, definitions };
+    let mut reader = super::Deserializer::with_definitions(bytes, definitions);
+    let mut fields = Vec::new();
+    let error = inspector.read_fields(&mut reader, &mut fields).err();
+    Inspection {
+        bytes,
+        fields,
+        error,
+    }
+}
+
+struct Inspector<'a> {
+    bytes: &'a [u8],
+    definitions: &'a Definitions,
+}
+
+impl<'a> Inspector<'a> {
+    fn offset(&self, reader: &super::Deserializer<'a, &'a [u8]>) -> usize {
+        self.bytes.len() - reader.bytes.len()
+    }
+
+    /// Reads fields until the end of the bytes, or until the object end marker if the reader
+    /// is an object deserializer
+    fn read_fields(
+        &self,
+        reader: &mut super::Deserializer<'a, &'a [u8]>,
+        fields: &mut Vec<InspectedField<'a>>,
+    ) -> Result<(), InspectError> {
+        loop {
+            let offset = self.offset(reader);
+            let Some(field_id) = reader
+                .next_field_id()
+                .map_err(|error| InspectError { offset, error })?
+            else {
+                return Ok(());
+            };
+            self.read_field(reader, offset, field_id, fields)?;
+        }
+    }
+
+    /// Reads the objects in an array until the array end marker
+    fn read_array_elements(
+        &self,
+        reader: &mut super::Deserializer<'a, &'a [u8]>,
+        elements: &mut Vec<InspectedField<'a>>,
+    ) -> Result<(), InspectError> {
+        loop {
+            let offset = self.offset(reader);
+            let Some(field_id) = reader
+                .next_array_element_id()
+                .map_err(|error| InspectError { offset, error })?
+            else {
+                return Ok(());
+            };
+            self.read_field(reader, offset, field_id, elements)?;
+        }
+    }
+
+    /// Reads the value of the field whose header has been read, and adds the field. Inner
+    /// objects and arrays are added before their fields are read, such that they are kept
+    /// with the fields read before an error
+    fn read_field(
+        &self,
+        reader: &mut super::Deserializer<'a, &'a [u8]>,
+        offset: usize,
+        field_id: FieldId,
+        fields: &mut Vec<InspectedField<'a>>,
+    ) -> Result<(), InspectError> {
+        let header_length = self.offset(reader) - offset;
+        let map_error = |error| InspectError { offset, error };
+
+        let vl_length = match field_id.type_code {
+            TypeCode::AccountId | TypeCode::Blob | TypeCode::Vector256 => {
+                // Read the length prefix ahead, the value is read including the prefix
+                let mut prefix_reader = reader.clone();
+                let vl_length = prefix_reader.read_vl_prefix().map_err(map_error)?;
+                Some((vl_length, self.offset(&prefix_reader)))
+            }
+            _ => None,
+        };
+        let value_offset =
+            vl_length.map_or(offset + header_length, |(_, value_offset)| value_offset);
+
+        let value = match field_id.type_code {
+            TypeCode::Object => InspectedValue::Object(Vec::new()),
+            TypeCode::Array => InspectedValue::Array(Vec::new()),
+            type_code => {
+                let field_accessor = super::FieldAccessor {
+                    deserializer: &mut *reader,
+                    type_code,
+                };
+                InspectedValue::Value(field_accessor.deserialize_any().map_err(map_error)?)
+            }
+        };
+
+        fields.push(InspectedField {
+            offset,
+            field_id,
+            header_length,
+            field_name: self.definitions.field_name(field_id),
+            vl_length: vl_length.map(|(vl_length, _)| vl_length),
+            value_offset,
+            end_offset: self.offset(reader),
+            value,
+        });
+        let field = fields.last_mut().expect("field was just added");
+
+        match &mut field.value {
+            InspectedValue::Object(object_fields) => {
+                let mut object_reader = super::Deserializer {
+                    bytes: reader.bytes,
+                    object_deserializer: true,
+                    previous_field_id: None,
+                    definitions: self.definitions,
+                };
+                let result = self.read_fields(&mut object_reader, object_fields);
+                reader.bytes = object_reader.bytes;
+                result?;
+            }
+            InspectedValue::Array(elements) => {
+                self.read_array_elements(reader, elements)?;
+            }
+            InspectedValue::Value(_) => {}
+        }
+        field.end_offset = self.offset(reader);
+        Ok(())
+    }
+}
+
+impl fmt::Display for Inspection<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for field in &self.fields {
+            fmt_field(f, self.bytes, field, 0)?;
+        }
+        if let Some(error) = &self.error {
+            writeln!(f, "{:04X}  error: {}", error.offset, error.error)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the field as a line with offset, header and length prefix bytes, name and value,
+/// indented by the nesting depth. Inner objects and arrays are followed by their fields and a
+/// line with the end marker
+fn fmt_field(
+    f: &mut fmt::Formatter<'_>,
+    bytes: &[u8],
+    field: &InspectedField<'_>,
+    depth: usize,
+) -> fmt::Result {
+    write!(
+        f,
+        "{:04X}  {:<8}  {:indent$}{} ({} {})",
+        field.offset,
+        hex::encode_upper(&bytes[field.offset..field.value_offset]),
+        "",
+        field.field_name.unwrap_or("<unknown field>"),
+        field.field_id.type_code,
+        field.field_id.field_code,
+        indent = depth * 2
+    )?;
+    if let Some(vl_length) = field.vl_length {
+        write!(f, ", length {}", vl_length)?;
+    }
+    let elements = match &field.value {
+        InspectedValue::Value(value) => return writeln!(f, ": {:?}", value),
+        InspectedValue::Object(elements) | InspectedValue::Array(elements) => elements,
+    };
+    writeln!(f)?;
+    for element in elements {
+        fmt_field(f, bytes, element, depth + 1)?;
+    }
+    // The end marker is read unless an error occurred inside the object or array
+    if field.end_offset > field.value_offset {
+        writeln!(
+            f,
+            "{:04X}  {:<8}  {:indent$}end of {}",
+            field.end_offset - 1,
+            hex::encode_upper(&bytes[field.end_offset - 1..field.end_offset]),
+            "",
+            field.field_name.unwrap_or("<unknown field>"),
+            indent = depth * 2
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alloc::string::ToString;
+    use crate::alloc::vec;
+    use crate::serialize;
+    use assert_matches::assert_matches;
+    use xrpl_types::{AccountId, AccountSetTransaction, Blob, Memo, Object};
+
+    fn transaction_with_memo() -> Vec<u8> {
+        let mut txn = AccountSetTransaction::new(
+            AccountId::from_address("rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys").unwrap(),
+        );
+        txn.common.sequence = Some(5);
+        txn.common.memos.push(Memo {
+            memo_type: Some(Blob(vec![0x01])),
+            memo_data: Some(Blob(vec![0x02, 0x03])),
+            memo_format: None,
+            unknown_fields: Object::default(),
+        });
+        serialize::serialize(&txn).unwrap()
+    }
+
+    #[test]
+    fn test_inspect() {
+        let bytes = transaction_with_memo();
+        let inspection = inspect(&bytes);
+
+        assert_eq!(inspection.error, None);
+        let names: Vec<_> = inspection
+            .fields
+            .iter()
+            .map(|field| field.field_name.unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            ["TransactionType", "Flags", "Sequence", "Account", "Memos"]
+        );
+
+        let transaction_type = &inspection.fields[0];
+        assert_eq!(transaction_type.offset, 0);
+        assert_eq!(
+            transaction_type.field_id,
+            FieldId::from_type_field(TypeCode::UInt16, FieldCode(2))
+        );
+        assert_eq!(transaction_type.header_length, 1);
+        assert_eq!(transaction_type.vl_length, None);
+        assert_eq!(transaction_type.value_offset, 1);
+        assert_eq!(transaction_type.end_offset, 3);
+        assert_eq!(
+            transaction_type.value,
+            InspectedValue::Value(FieldValue::UInt16(3))
+        );
+
+        let account = &inspection.fields[3];
+        assert_eq!(account.offset, 13);
+        assert_eq!(account.vl_length, Some(20));
+        assert_eq!(account.value_offset, 15);
+        assert_eq!(account.end_offset, 35);
+
+        let memos = &inspection.fields[4];
+        assert_eq!(memos.offset, 35);
+        assert_eq!(memos.end_offset, bytes.len());
+        assert_matches!(&memos.value, InspectedValue::Array(elements) => {
+            assert_eq!(elements.len(), 1);
+            assert_eq!(elements[0].field_name, Some("Memo"));
+            assert_eq!(elements[0].offset, 36);
+            assert_matches!(&elements[0].value, InspectedValue::Object(fields) => {
+                assert_eq!(fields.len(), 2);
+                assert_eq!(fields[1].field_name, Some("MemoData"));
+                assert_eq!(fields[1].offset, 40);
+                assert_eq!(fields[1].vl_length, Some(2));
+                assert_eq!(fields[1].value, InspectedValue::Value(FieldValue::Blob(Blob(vec![0x02, 0x03]))));
+            });
+        });
+    }
+
+    /// Tests that fields before the error are kept, including inner objects and arrays
+    #[test]
+    fn test_inspect_truncated() {
+        let bytes = transaction_with_memo();
+        let inspection = inspect(&bytes[..bytes.len() - 3]);
+
+        assert_eq!(inspection.fields.len(), 5);
+        assert_matches!(&inspection.fields[4].value, InspectedValue::Array(elements) => {
+            assert_matches!(&elements[0].value, InspectedValue::Object(fields) => {
+                assert_eq!(fields.len(), 1);
+                assert_eq!(fields[0].field_name, Some("MemoType"));
+            });
+        });
+        assert_matches!(
+            inspection.error,
+            Some(InspectError {
+                offset: 40,
+                error: BinaryCodecError::InsufficientBytes(_)
+            })
+        );
+    }
+
+    #[test]
+    fn test_inspect_unknown_field() {
+        // UInt32 with field code 200, which is not defined
+        let bytes = [0x20, 0xC8, 0, 0, 0, 7];
+        let inspection = inspect(&bytes);
+
+        assert_eq!(inspection.error, None);
+        assert_eq!(inspection.fields.len(), 1);
+        assert_eq!(inspection.fields[0].field_name, None);
+        assert_eq!(inspection.fields[0].header_length, 2);
+        assert_eq!(
+            inspection.fields[0].value,
+            InspectedValue::Value(FieldValue::UInt32(7))
+        );
+    }
+
+    #[test]
+    fn test_inspect_display() {
+        let bytes = transaction_with_memo();
+        let inspection = inspect(&bytes[..bytes.len() - 3]);
+
+        assert_eq!(
+            inspection.to_string(),
+            "0000  12        TransactionType (UInt16 2): UInt16(3)\n\
+             0003  22        Flags (UInt32 2): UInt32(0)\n\
+             0008  24        Sequence (UInt32 4): UInt32(5)\n\
+             000D  8114      Account (AccountId 1), length 20: AccountId(DD76483FACDEE26E60D8A586BB58D09F27045C46)\n\
+             0023  F9        Memos (Array 9)\n\
+             0024  EA          Memo (Object 10)\n\
+             0025  7C01          MemoType (Blob 12), length 1: Blob(01)\n\
+             0028  error: Insufficient bytes to decode: read_bytes\n"
+        );
+
+        let inspection = inspect(&bytes);
+        assert!(inspection.to_string().ends_with(
+            "0028  7D02          MemoData (Blob 13), length 2: Blob(0203)\n\
+             002C  E1          end of Memo\n\
+             002D  F1        end of Memos\n"
+        ));
+    }
+}
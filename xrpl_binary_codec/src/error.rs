@@ -12,6 +12,10 @@ pub enum BinaryCodecError {
     MissingField(String),
     InvalidLength(String),
     InsufficientBytes(String),
+    InvalidKey(String),
+    InvalidSignature(String),
+    InvalidDefinitions(String),
+    InvalidProof(String),
 }
 
 #[cfg(feature = "std")]
@@ -30,7 +34,10 @@ impl fmt::Display for BinaryCodecError {
             Self::MissingField(s) => write!(f, "Missing field: {}", s),
             Self::InvalidLength(s) => write!(f, "Invalid length: {}", s),
             Self::InsufficientBytes(s) => write!(f, "Insufficient bytes to decode: {}", s),
+            Self::InvalidKey(s) => write!(f, "Invalid key: {}", s),
+            Self::InvalidSignature(s) => write!(f, "Invalid signature: {}", s),
+            Self::InvalidDefinitions(s) => write!(f, "Invalid definitions: {}", s),
+            Self::InvalidProof(s) => write!(f, "Invalid proof: {}", s),
         }
     }
 }
-
@@ -19,6 +19,10 @@ pub enum TypeCode {
     UInt64 = 3,
     Array = 15,
     Object = 14,
+    PathSet = 18,
+    Vector256 = 19,
+    Issue = 24,
+    XChainBridge = 25,
 }
 
 impl fmt::Display for TypeCode {
@@ -42,11 +46,26 @@ impl TypeCode {
             3 => Some(Self::UInt64),
             15 => Some(Self::Array),
             14 => Some(Self::Object),
+            18 => Some(Self::PathSet),
+            19 => Some(Self::Vector256),
+            24 => Some(Self::Issue),
+            25 => Some(Self::XChainBridge),
             _ => None,
         }
     }
 }
 
+/// Byte separating paths in a path set <https://xrpl.org/serialization.html#pathset-fields>
+pub const PATH_SEPARATOR_BYTE: u8 = 0xFF;
+/// Byte ending a path set <https://xrpl.org/serialization.html#pathset-fields>
+pub const PATH_SET_END_BYTE: u8 = 0x00;
+/// Path step type flag indicating the step has an account <https://xrpl.org/serialization.html#pathset-fields>
+pub const PATH_STEP_ACCOUNT: u8 = 0x01;
+/// Path step type flag indicating the step has a currency <https://xrpl.org/serialization.html#pathset-fields>
+pub const PATH_STEP_CURRENCY: u8 = 0x10;
+/// Path step type flag indicating the step has an issuer <https://xrpl.org/serialization.html#pathset-fields>
+pub const PATH_STEP_ISSUER: u8 = 0x20;
+
 /// Field code <https://xrpl.org/serialization.html#field-codes>. The code for a given field can be found at
 /// <https://github.com/XRPLF/xrpl.js/blob/main/packages/ripple-binary-codec/src/enums/definitions.json> or
 /// <https://github.com/XRPLF/rippled/blob/72e6005f562a8f0818bc94803d222ac9345e1e40/src/ripple/protocol/impl/SField.cpp#L72-L266>
@@ -69,10 +88,16 @@ pub struct FieldId {
 }
 
 impl FieldId {
-    pub fn from_type_field(type_code: TypeCode, field_code: FieldCode) -> Self {
+    pub const fn from_type_field(type_code: TypeCode, field_code: FieldCode) -> Self {
         Self {
             type_code,
             field_code,
         }
     }
+
+    /// Name of the field in the built-in definitions. Looked up in a static table, without
+    /// hashing or allocation
+    pub fn name(self) -> Option<&'static str> {
+        field_info::field_name(self)
+    }
 }
@@ -1,269 +1,283 @@
 use crate::alloc::string::{String, ToString};
-
+use crate::field::{FieldCode, FieldId, TypeCode};
 use hashbrown::HashMap;
-use crate::field::FieldId;
-
-#[cfg(feature = "std")]
-static FIELD_NAME_TO_FIELD_ID: std::sync::OnceLock<HashMap<String, FieldId>> = std::sync::OnceLock::new();
-
-#[cfg(feature = "std")]
-pub fn field_name_to_field_id() -> &'static HashMap<String, FieldId> {
-    FIELD_NAME_TO_FIELD_ID.get_or_init(create_field_name_to_field_id_map)
-}
-
-#[cfg(not(feature = "std"))]
-static FIELD_NAME_TO_FIELD_ID: spin::Once<HashMap<String, FieldId>> = spin::Once::new();
-
-#[cfg(not(feature = "std"))]
-pub fn field_name_to_field_id() -> &'static HashMap<String, FieldId> {
-    FIELD_NAME_TO_FIELD_ID.call_once(|| create_field_name_to_field_id_map())
-}
-
-pub fn field_id_by_name(field_name: &str) -> Option<&'static FieldId> {
-    field_name_to_field_id().get(field_name)
-}
-
-#[cfg(feature = "std")]
-static FIELD_ID_TO_FIELD_NAME: std::sync::OnceLock<HashMap<FieldId, String>> = std::sync::OnceLock::new();
 
-#[cfg(feature = "std")]
-pub fn field_id_to_field_name() -> &'static HashMap<FieldId, String> {
-    FIELD_ID_TO_FIELD_NAME.get_or_init(create_field_id_to_field_name_map)
+macro_rules! field {
+    ($field_name:literal, $field_code:literal, $field_type:ident) => {
+        (
+            $field_name,
+            FieldId {
+                type_code: TypeCode::$field_type,
+                field_code: FieldCode($field_code),
+            },
+        )
+    };
 }
 
-#[cfg(not(feature = "std"))]
-static FIELD_ID_TO_FIELD_NAME: spin::Once<HashMap<FieldId, String>> = spin::Once::new();
+/// Field info taken from FIELDS in <https://github.com/XRPLF/xrpl.js/blob/main/packages/ripple-binary-codec/src/enums/definitions.json>
+const FIELDS: &[(&str, FieldId)] = &[
+    field!("CloseResolution", 1, UInt8),
+    field!("Method", 2, UInt8),
+    field!("TransactionResult", 3, UInt8),
+    field!("TickSize", 16, UInt8),
+    field!("UNLModifyDisabling", 17, UInt8),
+    field!("HookResult", 18, UInt8),
+    field!("LedgerEntryType", 1, UInt16),
+    field!("TransactionType", 2, UInt16),
+    field!("SignerWeight", 3, UInt16),
+    field!("TransferFee", 4, UInt16),
+    field!("TradingFee", 5, UInt16),
+    field!("DiscountedFee", 6, UInt16),
+    field!("Version", 16, UInt16),
+    field!("HookStateChangeCount", 17, UInt16),
+    field!("HookEmitCount", 18, UInt16),
+    field!("HookExecutionIndex", 19, UInt16),
+    field!("HookApiVersion", 20, UInt16),
+    field!("NetworkID", 1, UInt32),
+    field!("Flags", 2, UInt32),
+    field!("SourceTag", 3, UInt32),
+    field!("Sequence", 4, UInt32),
+    field!("PreviousTxnLgrSeq", 5, UInt32),
+    field!("LedgerSequence", 6, UInt32),
+    field!("CloseTime", 7, UInt32),
+    field!("ParentCloseTime", 8, UInt32),
+    field!("SigningTime", 9, UInt32),
+    field!("Expiration", 10, UInt32),
+    field!("TransferRate", 11, UInt32),
+    field!("WalletSize", 12, UInt32),
+    field!("OwnerCount", 13, UInt32),
+    field!("DestinationTag", 14, UInt32),
+    field!("HighQualityIn", 16, UInt32),
+    field!("HighQualityOut", 17, UInt32),
+    field!("LowQualityIn", 18, UInt32),
+    field!("LowQualityOut", 19, UInt32),
+    field!("QualityIn", 20, UInt32),
+    field!("QualityOut", 21, UInt32),
+    field!("StampEscrow", 22, UInt32),
+    field!("BondAmount", 23, UInt32),
+    field!("LoadFee", 24, UInt32),
+    field!("OfferSequence", 25, UInt32),
+    field!("FirstLedgerSequence", 26, UInt32),
+    field!("LastLedgerSequence", 27, UInt32),
+    field!("TransactionIndex", 28, UInt32),
+    field!("OperationLimit", 29, UInt32),
+    field!("ReferenceFeeUnits", 30, UInt32),
+    field!("ReserveBase", 31, UInt32),
+    field!("ReserveIncrement", 32, UInt32),
+    field!("SetFlag", 33, UInt32),
+    field!("ClearFlag", 34, UInt32),
+    field!("SignerQuorum", 35, UInt32),
+    field!("CancelAfter", 36, UInt32),
+    field!("FinishAfter", 37, UInt32),
+    field!("SignerListID", 38, UInt32),
+    field!("SettleDelay", 39, UInt32),
+    field!("TicketCount", 40, UInt32),
+    field!("TicketSequence", 41, UInt32),
+    field!("NFTokenTaxon", 42, UInt32),
+    field!("MintedNFTokens", 43, UInt32),
+    field!("BurnedNFTokens", 44, UInt32),
+    field!("HookStateCount", 45, UInt32),
+    field!("EmitGeneration", 46, UInt32),
+    field!("VoteWeight", 48, UInt32),
+    field!("FirstNFTokenSequence", 50, UInt32),
+    field!("IndexNext", 1, UInt64),
+    field!("IndexPrevious", 2, UInt64),
+    field!("BookNode", 3, UInt64),
+    field!("OwnerNode", 4, UInt64),
+    field!("BaseFee", 5, UInt64),
+    field!("ExchangeRate", 6, UInt64),
+    field!("LowNode", 7, UInt64),
+    field!("HighNode", 8, UInt64),
+    field!("DestinationNode", 9, UInt64),
+    field!("Cookie", 10, UInt64),
+    field!("ServerVersion", 11, UInt64),
+    field!("NFTokenOfferNode", 12, UInt64),
+    field!("EmitBurden", 13, UInt64),
+    field!("HookOn", 16, UInt64),
+    field!("HookInstructionCount", 17, UInt64),
+    field!("HookReturnCode", 18, UInt64),
+    field!("ReferenceCount", 19, UInt64),
+    field!("EmailHash", 1, Hash128),
+    field!("TakerPaysCurrency", 1, Hash160),
+    field!("TakerPaysIssuer", 2, Hash160),
+    field!("TakerGetsCurrency", 3, Hash160),
+    field!("TakerGetsIssuer", 4, Hash160),
+    field!("LedgerHash", 1, Hash256),
+    field!("ParentHash", 2, Hash256),
+    field!("TransactionHash", 3, Hash256),
+    field!("AccountHash", 4, Hash256),
+    field!("PreviousTxnID", 5, Hash256),
+    field!("LedgerIndex", 6, Hash256),
+    field!("WalletLocator", 7, Hash256),
+    field!("RootIndex", 8, Hash256),
+    field!("AccountTxnID", 9, Hash256),
+    field!("NFTokenID", 10, Hash256),
+    field!("EmitParentTxnID", 11, Hash256),
+    field!("EmitNonce", 12, Hash256),
+    field!("EmitHookHash", 13, Hash256),
+    field!("AMMID", 14, Hash256),
+    field!("BookDirectory", 16, Hash256),
+    field!("InvoiceID", 17, Hash256),
+    field!("Nickname", 18, Hash256),
+    field!("Amendment", 19, Hash256),
+    field!("Digest", 21, Hash256),
+    field!("Channel", 22, Hash256),
+    field!("ConsensusHash", 23, Hash256),
+    field!("CheckID", 24, Hash256),
+    field!("ValidatedHash", 25, Hash256),
+    field!("PreviousPageMin", 26, Hash256),
+    field!("NextPageMin", 27, Hash256),
+    field!("NFTokenBuyOffer", 28, Hash256),
+    field!("NFTokenSellOffer", 29, Hash256),
+    field!("HookStateKey", 30, Hash256),
+    field!("HookHash", 31, Hash256),
+    field!("HookNamespace", 32, Hash256),
+    field!("HookSetTxnID", 33, Hash256),
+    field!("Amount", 1, Amount),
+    field!("Balance", 2, Amount),
+    field!("LimitAmount", 3, Amount),
+    field!("TakerPays", 4, Amount),
+    field!("TakerGets", 5, Amount),
+    field!("LowLimit", 6, Amount),
+    field!("HighLimit", 7, Amount),
+    field!("Fee", 8, Amount),
+    field!("SendMax", 9, Amount),
+    field!("DeliverMin", 10, Amount),
+    field!("MinimumOffer", 16, Amount),
+    field!("RippleEscrow", 17, Amount),
+    field!("DeliveredAmount", 18, Amount),
+    field!("NFTokenBrokerFee", 19, Amount),
+    field!("BaseFeeDrops", 22, Amount),
+    field!("ReserveBaseDrops", 23, Amount),
+    field!("ReserveIncrementDrops", 24, Amount),
+    field!("Price", 28, Amount),
+    field!("LPTokenBalance", 31, Amount),
+    field!("PublicKey", 1, Blob),
+    field!("MessageKey", 2, Blob),
+    field!("SigningPubKey", 3, Blob),
+    field!("TxnSignature", 4, Blob),
+    field!("URI", 5, Blob),
+    field!("Signature", 6, Blob),
+    field!("Domain", 7, Blob),
+    field!("FundCode", 8, Blob),
+    field!("RemoveCode", 9, Blob),
+    field!("ExpireCode", 10, Blob),
+    field!("CreateCode", 11, Blob),
+    field!("MemoType", 12, Blob),
+    field!("MemoData", 13, Blob),
+    field!("MemoFormat", 14, Blob),
+    field!("Fulfillment", 16, Blob),
+    field!("Condition", 17, Blob),
+    field!("MasterSignature", 18, Blob),
+    field!("UNLModifyValidator", 19, Blob),
+    field!("ValidatorToDisable", 20, Blob),
+    field!("ValidatorToReEnable", 21, Blob),
+    field!("HookStateData", 22, Blob),
+    field!("HookReturnString", 23, Blob),
+    field!("HookParameterName", 24, Blob),
+    field!("HookParameterValue", 25, Blob),
+    field!("Account", 1, AccountId),
+    field!("Owner", 2, AccountId),
+    field!("Destination", 3, AccountId),
+    field!("Issuer", 4, AccountId),
+    field!("Authorize", 5, AccountId),
+    field!("Unauthorize", 6, AccountId),
+    field!("RegularKey", 8, AccountId),
+    field!("NFTokenMinter", 9, AccountId),
+    field!("EmitCallback", 10, AccountId),
+    field!("HookAccount", 16, AccountId),
+    field!("TransactionMetaData", 2, Object),
+    field!("CreatedNode", 3, Object),
+    field!("DeletedNode", 4, Object),
+    field!("ModifiedNode", 5, Object),
+    field!("PreviousFields", 6, Object),
+    field!("FinalFields", 7, Object),
+    field!("NewFields", 8, Object),
+    field!("TemplateEntry", 9, Object),
+    field!("Memo", 10, Object),
+    field!("SignerEntry", 11, Object),
+    field!("NFToken", 12, Object),
+    field!("EmitDetails", 13, Object),
+    field!("Hook", 14, Object),
+    field!("Signer", 16, Object),
+    field!("Majority", 18, Object),
+    field!("DisabledValidator", 19, Object),
+    field!("EmittedTxn", 20, Object),
+    field!("HookExecution", 21, Object),
+    field!("HookDefinition", 22, Object),
+    field!("HookParameter", 23, Object),
+    field!("HookGrant", 24, Object),
+    field!("VoteEntry", 25, Object),
+    field!("AuctionSlot", 26, Object),
+    field!("AuthAccount", 27, Object),
+    field!("ObjectEndMarker", 1, Object),
+    field!("Signers", 3, Array),
+    field!("SignerEntries", 4, Array),
+    field!("Template", 5, Array),
+    field!("Necessary", 6, Array),
+    field!("Sufficient", 7, Array),
+    field!("AffectedNodes", 8, Array),
+    field!("Memos", 9, Array),
+    field!("NFTokens", 10, Array),
+    field!("Hooks", 11, Array),
+    field!("VoteSlots", 12, Array),
+    field!("Majorities", 16, Array),
+    field!("DisabledValidators", 17, Array),
+    field!("HookExecutions", 18, Array),
+    field!("HookParameters", 19, Array),
+    field!("HookGrants", 20, Array),
+    field!("AuthAccounts", 25, Array),
+    field!("ArrayEndMarker", 1, Array),
+    field!("Paths", 1, PathSet),
+    field!("Indexes", 1, Vector256),
+    field!("Hashes", 2, Vector256),
+    field!("Amendments", 3, Vector256),
+    field!("NFTokenOffers", 4, Vector256),
+    field!("LockingChainIssue", 1, Issue),
+    field!("IssuingChainIssue", 2, Issue),
+    field!("Asset", 3, Issue),
+    field!("Asset2", 4, Issue),
+    field!("XChainBridge", 1, XChainBridge),
+];
 
-#[cfg(not(feature = "std"))]
-pub fn field_id_to_field_name() -> &'static HashMap<FieldId, String> {
-    FIELD_ID_TO_FIELD_NAME.call_once(|| create_field_id_to_field_name_map())
-}
+/// Number of rows in `FIELD_INDEX`, one more than the largest type code discriminant
+const TYPE_CODE_COUNT: usize = 26;
+/// Marks field ids in `FIELD_INDEX` that are not defined
+const NO_FIELD: u16 = u16::MAX;
 
-pub fn field_name_by_id(field_id: FieldId) -> Option<&'static str> {
-    field_id_to_field_name().get(&field_id).map(|s| s.as_str())
-}
+/// Position in `FIELDS` of each field, indexed by type code and field code. Allows looking up fields
+/// by id without hashing
+static FIELD_INDEX: [[u16; 256]; TYPE_CODE_COUNT] = create_field_index();
 
-fn create_field_id_to_field_name_map() -> HashMap<FieldId, String> {
-    field_name_to_field_id().clone().into_iter().map(|(k, v)|(v, k)).collect()
+const fn create_field_index() -> [[u16; 256]; TYPE_CODE_COUNT] {
+    let mut index = [[NO_FIELD; 256]; TYPE_CODE_COUNT];
+    let mut i = 0;
+    while i < FIELDS.len() {
+        let field_id = FIELDS[i].1;
+        let entry = &mut index[field_id.type_code as usize][field_id.field_code.0 as usize];
+        if *entry != NO_FIELD {
+            panic!("Field with same id inserted twice");
+        }
+        *entry = i as u16;
+        i += 1;
+    }
+    index
 }
 
-
-macro_rules! insert_field_by_name {
-    ($map:ident, $field_name:literal, $field_code:literal, $field_type:ident) => {
-        if $map
-            .insert(
-                $field_name.to_string(),
-                FieldId {
-                    type_code: $crate::field::TypeCode::$field_type,
-                    field_code: $crate::field::FieldCode($field_code),
-                },
-            )
-            .is_some()
-        {
-            panic!("Field with name {} inserted twice", $field_name);
+pub(crate) fn create_field_name_to_field_id_map() -> HashMap<String, FieldId> {
+    let mut map = HashMap::with_capacity(FIELDS.len());
+    for (field_name, field_id) in FIELDS {
+        if map.insert(field_name.to_string(), *field_id).is_some() {
+            panic!("Field with name {} inserted twice", field_name);
         }
-    };
+    }
+    map
 }
 
-/// Field info taken from FIELDS in <https://github.com/XRPLF/xrpl.js/blob/main/packages/ripple-binary-codec/src/enums/definitions.json>
-fn create_field_name_to_field_id_map() -> HashMap<String, FieldId> {
-    let mut map = HashMap::new();
-    insert_field_by_name!(map, "CloseResolution", 1, UInt8);
-    insert_field_by_name!(map, "Method", 2, UInt8);
-    insert_field_by_name!(map, "TransactionResult", 3, UInt8);
-    insert_field_by_name!(map, "TickSize", 16, UInt8);
-    insert_field_by_name!(map, "UNLModifyDisabling", 17, UInt8);
-    insert_field_by_name!(map, "HookResult", 18, UInt8);
-    insert_field_by_name!(map, "LedgerEntryType", 1, UInt16);
-    insert_field_by_name!(map, "TransactionType", 2, UInt16);
-    insert_field_by_name!(map, "SignerWeight", 3, UInt16);
-    insert_field_by_name!(map, "TransferFee", 4, UInt16);
-    insert_field_by_name!(map, "Version", 16, UInt16);
-    insert_field_by_name!(map, "HookStateChangeCount", 17, UInt16);
-    insert_field_by_name!(map, "HookEmitCount", 18, UInt16);
-    insert_field_by_name!(map, "HookExecutionIndex", 19, UInt16);
-    insert_field_by_name!(map, "HookApiVersion", 20, UInt16);
-    insert_field_by_name!(map, "NetworkID", 1, UInt32);
-    insert_field_by_name!(map, "Flags", 2, UInt32);
-    insert_field_by_name!(map, "SourceTag", 3, UInt32);
-    insert_field_by_name!(map, "Sequence", 4, UInt32);
-    insert_field_by_name!(map, "PreviousTxnLgrSeq", 5, UInt32);
-    insert_field_by_name!(map, "LedgerSequence", 6, UInt32);
-    insert_field_by_name!(map, "CloseTime", 7, UInt32);
-    insert_field_by_name!(map, "ParentCloseTime", 8, UInt32);
-    insert_field_by_name!(map, "SigningTime", 9, UInt32);
-    insert_field_by_name!(map, "Expiration", 10, UInt32);
-    insert_field_by_name!(map, "TransferRate", 11, UInt32);
-    insert_field_by_name!(map, "WalletSize", 12, UInt32);
-    insert_field_by_name!(map, "OwnerCount", 13, UInt32);
-    insert_field_by_name!(map, "DestinationTag", 14, UInt32);
-    insert_field_by_name!(map, "HighQualityIn", 16, UInt32);
-    insert_field_by_name!(map, "HighQualityOut", 17, UInt32);
-    insert_field_by_name!(map, "LowQualityIn", 18, UInt32);
-    insert_field_by_name!(map, "LowQualityOut", 19, UInt32);
-    insert_field_by_name!(map, "QualityIn", 20, UInt32);
-    insert_field_by_name!(map, "QualityOut", 21, UInt32);
-    insert_field_by_name!(map, "StampEscrow", 22, UInt32);
-    insert_field_by_name!(map, "BondAmount", 23, UInt32);
-    insert_field_by_name!(map, "LoadFee", 24, UInt32);
-    insert_field_by_name!(map, "OfferSequence", 25, UInt32);
-    insert_field_by_name!(map, "FirstLedgerSequence", 26, UInt32);
-    insert_field_by_name!(map, "LastLedgerSequence", 27, UInt32);
-    insert_field_by_name!(map, "TransactionIndex", 28, UInt32);
-    insert_field_by_name!(map, "OperationLimit", 29, UInt32);
-    insert_field_by_name!(map, "ReferenceFeeUnits", 30, UInt32);
-    insert_field_by_name!(map, "ReserveBase", 31, UInt32);
-    insert_field_by_name!(map, "ReserveIncrement", 32, UInt32);
-    insert_field_by_name!(map, "SetFlag", 33, UInt32);
-    insert_field_by_name!(map, "ClearFlag", 34, UInt32);
-    insert_field_by_name!(map, "SignerQuorum", 35, UInt32);
-    insert_field_by_name!(map, "CancelAfter", 36, UInt32);
-    insert_field_by_name!(map, "FinishAfter", 37, UInt32);
-    insert_field_by_name!(map, "SignerListID", 38, UInt32);
-    insert_field_by_name!(map, "SettleDelay", 39, UInt32);
-    insert_field_by_name!(map, "TicketCount", 40, UInt32);
-    insert_field_by_name!(map, "TicketSequence", 41, UInt32);
-    insert_field_by_name!(map, "NFTokenTaxon", 42, UInt32);
-    insert_field_by_name!(map, "MintedNFTokens", 43, UInt32);
-    insert_field_by_name!(map, "BurnedNFTokens", 44, UInt32);
-    insert_field_by_name!(map, "HookStateCount", 45, UInt32);
-    insert_field_by_name!(map, "EmitGeneration", 46, UInt32);
-    insert_field_by_name!(map, "IndexNext", 1, UInt64);
-    insert_field_by_name!(map, "IndexPrevious", 2, UInt64);
-    insert_field_by_name!(map, "BookNode", 3, UInt64);
-    insert_field_by_name!(map, "OwnerNode", 4, UInt64);
-    insert_field_by_name!(map, "BaseFee", 5, UInt64);
-    insert_field_by_name!(map, "ExchangeRate", 6, UInt64);
-    insert_field_by_name!(map, "LowNode", 7, UInt64);
-    insert_field_by_name!(map, "HighNode", 8, UInt64);
-    insert_field_by_name!(map, "DestinationNode", 9, UInt64);
-    insert_field_by_name!(map, "Cookie", 10, UInt64);
-    insert_field_by_name!(map, "ServerVersion", 11, UInt64);
-    insert_field_by_name!(map, "NFTokenOfferNode", 12, UInt64);
-    insert_field_by_name!(map, "EmitBurden", 13, UInt64);
-    insert_field_by_name!(map, "HookOn", 16, UInt64);
-    insert_field_by_name!(map, "HookInstructionCount", 17, UInt64);
-    insert_field_by_name!(map, "HookReturnCode", 18, UInt64);
-    insert_field_by_name!(map, "ReferenceCount", 19, UInt64);
-    insert_field_by_name!(map, "EmailHash", 1, Hash128);
-    insert_field_by_name!(map, "TakerPaysCurrency", 1, Hash160);
-    insert_field_by_name!(map, "TakerPaysIssuer", 2, Hash160);
-    insert_field_by_name!(map, "TakerGetsCurrency", 3, Hash160);
-    insert_field_by_name!(map, "TakerGetsIssuer", 4, Hash160);
-    insert_field_by_name!(map, "LedgerHash", 1, Hash256);
-    insert_field_by_name!(map, "ParentHash", 2, Hash256);
-    insert_field_by_name!(map, "TransactionHash", 3, Hash256);
-    insert_field_by_name!(map, "AccountHash", 4, Hash256);
-    insert_field_by_name!(map, "PreviousTxnID", 5, Hash256);
-    insert_field_by_name!(map, "LedgerIndex", 6, Hash256);
-    insert_field_by_name!(map, "WalletLocator", 7, Hash256);
-    insert_field_by_name!(map, "RootIndex", 8, Hash256);
-    insert_field_by_name!(map, "AccountTxnID", 9, Hash256);
-    insert_field_by_name!(map, "NFTokenID", 10, Hash256);
-    insert_field_by_name!(map, "EmitParentTxnID", 11, Hash256);
-    insert_field_by_name!(map, "EmitNonce", 12, Hash256);
-    insert_field_by_name!(map, "EmitHookHash", 13, Hash256);
-    insert_field_by_name!(map, "BookDirectory", 16, Hash256);
-    insert_field_by_name!(map, "InvoiceID", 17, Hash256);
-    insert_field_by_name!(map, "Nickname", 18, Hash256);
-    insert_field_by_name!(map, "Amendment", 19, Hash256);
-    insert_field_by_name!(map, "Digest", 21, Hash256);
-    insert_field_by_name!(map, "Channel", 22, Hash256);
-    insert_field_by_name!(map, "ConsensusHash", 23, Hash256);
-    insert_field_by_name!(map, "CheckID", 24, Hash256);
-    insert_field_by_name!(map, "ValidatedHash", 25, Hash256);
-    insert_field_by_name!(map, "PreviousPageMin", 26, Hash256);
-    insert_field_by_name!(map, "NextPageMin", 27, Hash256);
-    insert_field_by_name!(map, "NFTokenBuyOffer", 28, Hash256);
-    insert_field_by_name!(map, "NFTokenSellOffer", 29, Hash256);
-    insert_field_by_name!(map, "HookStateKey", 30, Hash256);
-    insert_field_by_name!(map, "HookHash", 31, Hash256);
-    insert_field_by_name!(map, "HookNamespace", 32, Hash256);
-    insert_field_by_name!(map, "HookSetTxnID", 33, Hash256);
-    insert_field_by_name!(map, "Amount", 1, Amount);
-    insert_field_by_name!(map, "Balance", 2, Amount);
-    insert_field_by_name!(map, "LimitAmount", 3, Amount);
-    insert_field_by_name!(map, "TakerPays", 4, Amount);
-    insert_field_by_name!(map, "TakerGets", 5, Amount);
-    insert_field_by_name!(map, "LowLimit", 6, Amount);
-    insert_field_by_name!(map, "HighLimit", 7, Amount);
-    insert_field_by_name!(map, "Fee", 8, Amount);
-    insert_field_by_name!(map, "SendMax", 9, Amount);
-    insert_field_by_name!(map, "DeliverMin", 10, Amount);
-    insert_field_by_name!(map, "MinimumOffer", 16, Amount);
-    insert_field_by_name!(map, "RippleEscrow", 17, Amount);
-    insert_field_by_name!(map, "DeliveredAmount", 18, Amount);
-    insert_field_by_name!(map, "NFTokenBrokerFee", 19, Amount);
-    insert_field_by_name!(map, "PublicKey", 1, Blob);
-    insert_field_by_name!(map, "MessageKey", 2, Blob);
-    insert_field_by_name!(map, "SigningPubKey", 3, Blob);
-    insert_field_by_name!(map, "TxnSignature", 4, Blob);
-    insert_field_by_name!(map, "URI", 5, Blob);
-    insert_field_by_name!(map, "Signature", 6, Blob);
-    insert_field_by_name!(map, "Domain", 7, Blob);
-    insert_field_by_name!(map, "FundCode", 8, Blob);
-    insert_field_by_name!(map, "RemoveCode", 9, Blob);
-    insert_field_by_name!(map, "ExpireCode", 10, Blob);
-    insert_field_by_name!(map, "CreateCode", 11, Blob);
-    insert_field_by_name!(map, "MemoType", 12, Blob);
-    insert_field_by_name!(map, "MemoData", 13, Blob);
-    insert_field_by_name!(map, "MemoFormat", 14, Blob);
-    insert_field_by_name!(map, "Fulfillment", 16, Blob);
-    insert_field_by_name!(map, "Condition", 17, Blob);
-    insert_field_by_name!(map, "MasterSignature", 18, Blob);
-    insert_field_by_name!(map, "UNLModifyValidator", 19, Blob);
-    insert_field_by_name!(map, "ValidatorToDisable", 20, Blob);
-    insert_field_by_name!(map, "ValidatorToReEnable", 21, Blob);
-    insert_field_by_name!(map, "HookStateData", 22, Blob);
-    insert_field_by_name!(map, "HookReturnString", 23, Blob);
-    insert_field_by_name!(map, "HookParameterName", 24, Blob);
-    insert_field_by_name!(map, "HookParameterValue", 25, Blob);
-    insert_field_by_name!(map, "Account", 1, AccountId);
-    insert_field_by_name!(map, "Owner", 2, AccountId);
-    insert_field_by_name!(map, "Destination", 3, AccountId);
-    insert_field_by_name!(map, "Issuer", 4, AccountId);
-    insert_field_by_name!(map, "Authorize", 5, AccountId);
-    insert_field_by_name!(map, "Unauthorize", 6, AccountId);
-    insert_field_by_name!(map, "RegularKey", 8, AccountId);
-    insert_field_by_name!(map, "NFTokenMinter", 9, AccountId);
-    insert_field_by_name!(map, "EmitCallback", 10, AccountId);
-    insert_field_by_name!(map, "HookAccount", 16, AccountId);
-    insert_field_by_name!(map, "TransactionMetaData", 2, Object);
-    insert_field_by_name!(map, "CreatedNode", 3, Object);
-    insert_field_by_name!(map, "DeletedNode", 4, Object);
-    insert_field_by_name!(map, "ModifiedNode", 5, Object);
-    insert_field_by_name!(map, "PreviousFields", 6, Object);
-    insert_field_by_name!(map, "FinalFields", 7, Object);
-    insert_field_by_name!(map, "NewFields", 8, Object);
-    insert_field_by_name!(map, "TemplateEntry", 9, Object);
-    insert_field_by_name!(map, "Memo", 10, Object);
-    insert_field_by_name!(map, "SignerEntry", 11, Object);
-    insert_field_by_name!(map, "NFToken", 12, Object);
-    insert_field_by_name!(map, "EmitDetails", 13, Object);
-    insert_field_by_name!(map, "Hook", 14, Object);
-    insert_field_by_name!(map, "Signer", 16, Object);
-    insert_field_by_name!(map, "Majority", 18, Object);
-    insert_field_by_name!(map, "DisabledValidator", 19, Object);
-    insert_field_by_name!(map, "EmittedTxn", 20, Object);
-    insert_field_by_name!(map, "HookExecution", 21, Object);
-    insert_field_by_name!(map, "HookDefinition", 22, Object);
-    insert_field_by_name!(map, "HookParameter", 23, Object);
-    insert_field_by_name!(map, "HookGrant", 24, Object);
-    insert_field_by_name!(map, "ObjectEndMarker", 1, Object);
-    insert_field_by_name!(map, "Signers", 3, Array);
-    insert_field_by_name!(map, "SignerEntries", 4, Array);
-    insert_field_by_name!(map, "Template", 5, Array);
-    insert_field_by_name!(map, "Necessary", 6, Array);
-    insert_field_by_name!(map, "Sufficient", 7, Array);
-    insert_field_by_name!(map, "AffectedNodes", 8, Array);
-    insert_field_by_name!(map, "Memos", 9, Array);
-    insert_field_by_name!(map, "NFTokens", 10, Array);
-    insert_field_by_name!(map, "Hooks", 11, Array);
-    insert_field_by_name!(map, "Majorities", 16, Array);
-    insert_field_by_name!(map, "DisabledValidators", 17, Array);
-    insert_field_by_name!(map, "HookExecutions", 18, Array);
-    insert_field_by_name!(map, "HookParameters", 19, Array);
-    insert_field_by_name!(map, "HookGrants", 20, Array);
-    insert_field_by_name!(map, "ArrayEndMarker", 1, Array);
-    map
+/// Name of the field with the given id in the built-in field definitions
+pub(crate) fn field_name(field_id: FieldId) -> Option<&'static str> {
+    match FIELD_INDEX[field_id.type_code as usize][field_id.field_code.0 as usize] {
+        NO_FIELD => None,
+        index => Some(FIELDS[index as usize].0),
+    }
 }
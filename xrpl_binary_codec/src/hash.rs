@@ -1,13 +1,34 @@
+use crate::deserializer::borrowed;
+use crate::{ledger_header, serialize, sign, BinaryCodecError};
 use sha2::Digest;
 use sha2::Sha512;
-use xrpl_types::Hash256;
+use xrpl_types::{Hash256, LedgerHeader, TransactionTrait};
 
 /// Unsigned single signer transactions prefix <https://xrpl.org/basic-data-types.html#hash-prefixes>
 pub const HASH_PREFIX_UNSIGNED_TRANSACTION_SINGLE: [u8; 4] = [0x53, 0x54, 0x58, 0x00];
 
+/// Unsigned multi-signer transactions prefix <https://xrpl.org/basic-data-types.html#hash-prefixes>
+pub const HASH_PREFIX_UNSIGNED_TRANSACTION_MULTI: [u8; 4] = [0x53, 0x4D, 0x54, 0x00];
+
 /// Signed transactions prefix <https://xrpl.org/basic-data-types.html#hash-prefixes>
 pub const HASH_PREFIX_SIGNED_TRANSACTION: [u8; 4] = [0x54, 0x58, 0x4E, 0x00];
 
+/// Ledger header prefix <https://xrpl.org/basic-data-types.html#hash-prefixes>
+pub const HASH_PREFIX_LEDGER_MASTER: [u8; 4] = [0x4C, 0x57, 0x52, 0x00];
+
+/// Validator manifest prefix <https://xrpl.org/basic-data-types.html#hash-prefixes>
+pub const HASH_PREFIX_MANIFEST: [u8; 4] = [0x4D, 0x41, 0x4E, 0x00];
+
+/// SHAMap inner node prefix <https://xrpl.org/basic-data-types.html#hash-prefixes>
+pub const HASH_PREFIX_INNER_NODE: [u8; 4] = [0x4D, 0x49, 0x4E, 0x00];
+
+/// SHAMap state tree leaf prefix <https://xrpl.org/basic-data-types.html#hash-prefixes>
+pub const HASH_PREFIX_LEAF_NODE: [u8; 4] = [0x4D, 0x4C, 0x4E, 0x00];
+
+/// SHAMap transaction tree leaf prefix, for transactions with metadata
+/// <https://xrpl.org/basic-data-types.html#hash-prefixes>
+pub const HASH_PREFIX_TRANSACTION_NODE: [u8; 4] = [0x53, 0x4E, 0x44, 0x00];
+
 /// Calculate hash <https://xrpl.org/basic-data-types.html#hashes> of given data
 pub fn hash(prefix: [u8; 4], data: &[u8]) -> Hash256 {
     // INSIGHT: Sha512Trunc245 does not give same result as Sha512[0..32]
@@ -16,3 +37,140 @@ pub fn hash(prefix: [u8; 4], data: &[u8]) -> Hash256 {
     let hash: [u8; 64] = hasher.finalize().into();
     Hash256(hash[0..32].try_into().expect("length 64"))
 }
+
+/// Calculate SHA-512Half <https://xrpl.org/basic-data-types.html#hashes> of given data without prefix
+pub fn sha512_half(data: &[u8]) -> Hash256 {
+    let hash: [u8; 64] = Sha512::digest(data).into();
+    Hash256(hash[0..32].try_into().expect("length 64"))
+}
+
+/// Transaction id <https://xrpl.org/transaction-basics.html#identifying-transactions>, which
+/// is the hash of the signed transaction, as given in the `hash` field by rippled
+pub fn transaction_id<T: TransactionTrait>(transaction: &T) -> Result<Hash256, BinaryCodecError> {
+    let serialized = serialize::serialize(transaction)?;
+    Ok(transaction_id_from_blob(&serialized))
+}
+
+/// Transaction id <https://xrpl.org/transaction-basics.html#identifying-transactions> of the
+/// given serialized signed transaction
+pub fn transaction_id_from_blob(bytes: &[u8]) -> Hash256 {
+    hash(HASH_PREFIX_SIGNED_TRANSACTION, bytes)
+}
+
+/// Hash of the transaction that is signed by single-signing <https://xrpl.org/cryptographic-keys.html#signing-algorithms>.
+/// This is the transaction serialized without `TxnSignature`.
+pub fn signing_hash<T: TransactionTrait + Clone>(
+    transaction: &T,
+) -> Result<Hash256, BinaryCodecError> {
+    let mut unsigned = transaction.clone();
+    unsigned.common_mut().txn_signature = None;
+    let serialized = serialize::serialize(&unsigned)?;
+    Ok(hash(HASH_PREFIX_UNSIGNED_TRANSACTION_SINGLE, &serialized))
+}
+
+/// Hash of the given serialized transaction that is signed by single-signing, see [`signing_hash`].
+/// Computed from the raw fields of the blob, with `TxnSignature` left out and the other fields
+/// re-emitted as they are, such that the transaction need not be modelled and may contain fields
+/// that are not known.
+pub fn signing_hash_from_blob(bytes: &[u8]) -> Result<Hash256, BinaryCodecError> {
+    let fields = borrowed::raw_fields(bytes)?;
+    Ok(hash(
+        HASH_PREFIX_UNSIGNED_TRANSACTION_SINGLE,
+        &sign::fields_without(&fields, sign::TXN_SIGNATURE),
+    ))
+}
+
+/// Ledger hash <https://xrpl.org/ledger-header.html#ledger-index-or-ledger-hash> of the given
+/// ledger header, which can be compared to the `ledger_hash` returned by rippled
+pub fn ledger_hash(header: &LedgerHeader) -> Hash256 {
+    hash(HASH_PREFIX_LEDGER_MASTER, &ledger_header::encode(header))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deserialize;
+    use crate::keypair::PublicKey;
+    use xrpl_types::{Amount, PaymentTransaction, Transaction};
+
+    /// Example from <https://xrpl.org/submit.html>
+    const TX_BLOB: &str = "1200002280000000240000016861D4838D7EA4C6800000000000000000000000000055534400000000004B4E9C06F24296074F7BC48F92A97916C6DC5EA9684000000000002710732103AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB7446304402200E5C2DD81FDF0BE9AB2A8D797885ED49E804DBF28E806604D878756410CA98B102203349581946B0DDA06B36B35DBC20EDA27552C1F167BCF5C6ECFF49C6A46F858081144B4E9C06F24296074F7BC48F92A97916C6DC5EA983143E9D4A2B8AA0780F682D136F7A56D6724EF53754";
+    const TX_HASH: &str = "4D5D90890F8D49519E4151938601EF3D0B30B16CD6A519D9C99102C9FA77F7E0";
+
+    #[test]
+    fn test_transaction_id_from_blob() {
+        let bytes = hex::decode(TX_BLOB).unwrap();
+        assert_eq!(transaction_id_from_blob(&bytes).to_hex(), TX_HASH);
+    }
+
+    #[test]
+    fn test_transaction_id() {
+        let bytes = hex::decode(TX_BLOB).unwrap();
+        let transaction: PaymentTransaction = deserialize::deserialize(&bytes).unwrap();
+        assert_eq!(transaction_id(&transaction).unwrap().to_hex(), TX_HASH);
+
+        let transaction: Transaction = deserialize::deserialize(&bytes).unwrap();
+        assert_eq!(transaction_id(&transaction).unwrap().to_hex(), TX_HASH);
+    }
+
+    #[test]
+    fn test_signing_hash() {
+        let bytes = hex::decode(TX_BLOB).unwrap();
+        let transaction: PaymentTransaction = deserialize::deserialize(&bytes).unwrap();
+        let hash = signing_hash(&transaction).unwrap();
+        assert_eq!(signing_hash_from_blob(&bytes).unwrap(), hash);
+
+        // The signature in the blob is a signature of the signing hash
+        let PublicKey::Secp256k1(public_key) =
+            PublicKey::from_bytes(&transaction.common.signing_pub_key.as_ref().unwrap().0).unwrap()
+        else {
+            panic!("expected secp256k1 key");
+        };
+        let signature = libsecp256k1::Signature::parse_der(
+            &transaction.common.txn_signature.as_ref().unwrap().0,
+        )
+        .unwrap();
+        assert!(libsecp256k1::verify(
+            &libsecp256k1::Message::parse(&hash.0),
+            &signature,
+            &public_key
+        ));
+        sign::verify_transaction(&transaction).unwrap();
+    }
+
+    /// Test that fields that are not known are part of the signing hash, as they are signed
+    #[test]
+    fn test_signing_hash_from_blob_unknown_field() {
+        let bytes = hex::decode(TX_BLOB).unwrap();
+        let mut transaction: PaymentTransaction = deserialize::deserialize(&bytes).unwrap();
+        // AccountId field with field code 200, ordered after `Destination`
+        let unknown_field = [[0x80, 200, 20].as_slice(), &[0xAB; 20]].concat();
+        let blob = [bytes.as_slice(), &unknown_field].concat();
+
+        transaction.common.txn_signature = None;
+        let unsigned = [serialize::serialize(&transaction).unwrap(), unknown_field].concat();
+        assert_eq!(
+            signing_hash_from_blob(&blob).unwrap(),
+            hash(HASH_PREFIX_UNSIGNED_TRANSACTION_SINGLE, &unsigned)
+        );
+        assert_ne!(
+            signing_hash_from_blob(&blob).unwrap(),
+            signing_hash_from_blob(&bytes).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_signing_hash_ignores_signature() {
+        let bytes = hex::decode(TX_BLOB).unwrap();
+        let mut transaction: PaymentTransaction = deserialize::deserialize(&bytes).unwrap();
+        let hash = signing_hash(&transaction).unwrap();
+        let id = transaction_id(&transaction).unwrap();
+
+        transaction.common.txn_signature = None;
+        assert_eq!(signing_hash(&transaction).unwrap(), hash);
+
+        transaction.amount = Amount::drops(1).unwrap();
+        assert_ne!(signing_hash(&transaction).unwrap(), hash);
+        assert_ne!(transaction_id(&transaction).unwrap(), id);
+    }
+}
@@ -0,0 +1,771 @@
+mod deserializer;
+mod serializer;
+
+pub use deserializer::{JsonArrayDeserializer, JsonDeserializer};
+pub use serializer::{JsonArraySerializer, JsonSerializer};
+
+use crate::alloc::format;
+use crate::alloc::string::{String, ToString};
+use crate::alloc::vec::Vec;
+use crate::definitions::Definitions;
+use crate::error::BinaryCodecError;
+use core::str::FromStr;
+use serde_json::{Map, Value};
+use xrpl_types::deserialize::Deserialize;
+use xrpl_types::serialize::Serialize;
+use xrpl_types::{
+    AccountId, Amount, Currency, CurrencyCode, Hash256, IssuedValue, Object, Path, PathSet,
+    PathStep, UInt16, UInt64, UInt8, XChainBridge,
+};
+
+/// Encodes object given in the JSON representation used by rippled <https://xrpl.org/serialization.html#json-format>
+/// to the canonical binary format. Keys starting with a lowercase letter, like `hash` or `ledger_index`, are not
+/// fields of the object and are ignored.
+pub fn encode(json: &Value) -> Result<Vec<u8>, BinaryCodecError> {
+    encode_with_definitions(json, Definitions::builtin())
+}
+
+/// Encodes object given in JSON to the canonical binary format, looking up fields in the given definitions.
+/// See [`encode`].
+pub fn encode_with_definitions(
+    json: &Value,
+    definitions: &Definitions,
+) -> Result<Vec<u8>, BinaryCodecError> {
+    let object: Object = deserialize_with_definitions(json, definitions)?;
+    crate::serialize::serialize_with_definitions(&object, definitions)
+}
+
+/// Decodes object in the canonical binary format to the JSON representation used by rippled
+/// <https://xrpl.org/serialization.html#json-format>
+pub fn decode(bytes: &[u8]) -> Result<Value, BinaryCodecError> {
+    decode_with_definitions(bytes, Definitions::builtin())
+}
+
+/// Decodes object in the canonical binary format to JSON, looking up fields in the given definitions.
+/// See [`decode`].
+pub fn decode_with_definitions(
+    bytes: &[u8],
+    definitions: &Definitions,
+) -> Result<Value, BinaryCodecError> {
+    let object: Object = crate::deserialize::deserialize_with_definitions(bytes, definitions)?;
+    serialize_with_definitions(&object, definitions)
+}
+
+/// Serializes the given object to the JSON representation used by rippled <https://xrpl.org/serialization.html#json-format>
+pub fn serialize(object: &impl Serialize) -> Result<Value, BinaryCodecError> {
+    serialize_with_definitions(object, Definitions::builtin())
+}
+
+/// Serializes the given object to JSON, looking up fields in the given definitions. See [`serialize`].
+pub fn serialize_with_definitions(
+    object: &impl Serialize,
+    definitions: &Definitions,
+) -> Result<Value, BinaryCodecError> {
+    let mut serializer = JsonSerializer::with_definitions(definitions);
+    object.serialize(&mut serializer)?;
+    Ok(serializer.into_json())
+}
+
+/// Deserializes `T` from the JSON representation used by rippled <https://xrpl.org/serialization.html#json-format>.
+/// Keys starting with a lowercase letter are not fields of the object and are ignored.
+pub fn deserialize<T: Deserialize>(json: &Value) -> Result<T, BinaryCodecError> {
+    deserialize_with_definitions(json, Definitions::builtin())
+}
+
+/// Deserializes `T` from JSON, looking up fields in the given definitions. See [`deserialize`].
+pub fn deserialize_with_definitions<T: Deserialize>(
+    json: &Value,
+    definitions: &Definitions,
+) -> Result<T, BinaryCodecError> {
+    T::deserialize(JsonDeserializer::with_definitions(
+        as_object(json, "JSON")?,
+        definitions,
+    ))
+}
+
+/// Keys starting with a lowercase letter, like `hash` or `ledger_index`, are not fields
+fn is_field(key: &str) -> bool {
+    !key.starts_with(|c: char| c.is_ascii_lowercase())
+}
+
+fn unknown_name(kind: &str, name: &str) -> BinaryCodecError {
+    BinaryCodecError::OutOfRange(format!("Unknown {}: {}", kind, name))
+}
+
+fn name_or_number(name: Option<&str>, value: impl Into<Value>) -> Value {
+    match name {
+        Some(name) => Value::String(name.to_string()),
+        None => value.into(),
+    }
+}
+
+fn invalid_json(expected: &str, json: &Value) -> BinaryCodecError {
+    BinaryCodecError::InvalidField(format!("Expected {}, found {}", expected, json))
+}
+
+fn as_object<'a>(
+    json: &'a Value,
+    context: &str,
+) -> Result<&'a Map<String, Value>, BinaryCodecError> {
+    json.as_object()
+        .ok_or_else(|| invalid_json(&format!("object for {}", context), json))
+}
+
+fn as_array(json: &Value) -> Result<&Vec<Value>, BinaryCodecError> {
+    json.as_array().ok_or_else(|| invalid_json("array", json))
+}
+
+fn as_str(json: &Value) -> Result<&str, BinaryCodecError> {
+    json.as_str().ok_or_else(|| invalid_json("string", json))
+}
+
+fn get<'a>(json: &'a Map<String, Value>, key: &str) -> Result<&'a Value, BinaryCodecError> {
+    json.get(key)
+        .ok_or_else(|| BinaryCodecError::MissingField(key.to_string()))
+}
+
+fn parse_uint<T: TryFrom<u64>>(json: &Value) -> Result<T, BinaryCodecError> {
+    json.as_u64()
+        .and_then(|value| T::try_from(value).ok())
+        .ok_or_else(|| invalid_json("unsigned integer within range", json))
+}
+
+/// Parses `UInt8` field, which for `TransactionResult` may be given by name
+fn parse_uint8(
+    definitions: &Definitions,
+    field_name: &str,
+    json: &Value,
+) -> Result<UInt8, BinaryCodecError> {
+    match (field_name, json) {
+        ("TransactionResult", Value::String(name)) => {
+            let code = definitions
+                .transaction_result_code(name)
+                .ok_or_else(|| unknown_name("transaction result", name))?;
+            UInt8::try_from(code).map_err(|_| {
+                BinaryCodecError::OutOfRange(format!(
+                    "Transaction result cannot be serialized: {}",
+                    name
+                ))
+            })
+        }
+        _ => parse_uint(json),
+    }
+}
+
+/// Parses `UInt16` field, which for `TransactionType` and `LedgerEntryType` may be given by name
+fn parse_uint16(
+    definitions: &Definitions,
+    field_name: &str,
+    json: &Value,
+) -> Result<UInt16, BinaryCodecError> {
+    match (field_name, json) {
+        ("TransactionType", Value::String(name)) => definitions
+            .transaction_type_code(name)
+            .ok_or_else(|| unknown_name("transaction type", name)),
+        ("LedgerEntryType", Value::String(name)) => definitions
+            .ledger_entry_type_code(name)
+            .ok_or_else(|| unknown_name("ledger entry type", name)),
+        _ => parse_uint(json),
+    }
+}
+
+/// `UInt64` fields are represented as hex strings
+fn parse_uint64(json: &Value) -> Result<UInt64, BinaryCodecError> {
+    UInt64::from_str_radix(as_str(json)?, 16)
+        .map_err(|err| BinaryCodecError::OutOfRange(err.to_string()))
+}
+
+fn parse_hex(json: &Value) -> Result<Vec<u8>, BinaryCodecError> {
+    hex::decode(as_str(json)?)
+        .map_err(|err| BinaryCodecError::OutOfRange(format!("Invalid hex: {}", err)))
+}
+
+fn parse_hex_array<const LEN: usize>(json: &Value) -> Result<[u8; LEN], BinaryCodecError> {
+    parse_hex(json)?.try_into().map_err(|bytes: Vec<u8>| {
+        BinaryCodecError::InvalidLength(format!("Expected {} bytes, found {}", LEN, bytes.len()))
+    })
+}
+
+fn parse_account_id(json: &Value) -> Result<AccountId, BinaryCodecError> {
+    AccountId::from_address(as_str(json)?)
+        .map_err(|err| BinaryCodecError::OutOfRange(err.to_string()))
+}
+
+fn parse_currency_code(json: &Value) -> Result<CurrencyCode, BinaryCodecError> {
+    CurrencyCode::from_str(as_str(json)?)
+        .map_err(|err| BinaryCodecError::OutOfRange(err.to_string()))
+}
+
+/// <https://xrpl.org/currency-formats.html#specifying-currency-amounts>
+fn parse_amount(json: &Value) -> Result<Amount, BinaryCodecError> {
+    match json {
+        Value::String(drops) => {
+            let drops = drops
+                .parse()
+                .map_err(|_| invalid_json("drops amount", json))?;
+            Amount::drops(drops).map_err(|err| BinaryCodecError::OutOfRange(err.to_string()))
+        }
+        Value::Object(amount) => Amount::issued(
+            parse_issued_value(as_str(get(amount, "value")?)?)?,
+            parse_currency_code(get(amount, "currency")?)?,
+            parse_account_id(get(amount, "issuer")?)?,
+        )
+        .map_err(|err| BinaryCodecError::OutOfRange(err.to_string())),
+        _ => Err(invalid_json("amount", json)),
+    }
+}
+
+/// <https://xrpl.org/currency-formats.html#specifying-without-amounts>
+fn parse_issue(json: &Value) -> Result<Currency, BinaryCodecError> {
+    let issue = as_object(json, "issue")?;
+    let currency_code = parse_currency_code(get(issue, "currency")?)?;
+    if currency_code.is_xrp() {
+        return Ok(Currency::Xrp);
+    }
+    Currency::issued(currency_code, parse_account_id(get(issue, "issuer")?)?)
+        .map_err(|err| BinaryCodecError::OutOfRange(err.to_string()))
+}
+
+fn parse_vector256(json: &Value) -> Result<Vec<Hash256>, BinaryCodecError> {
+    as_array(json)?
+        .iter()
+        .map(|hash| Ok(Hash256(parse_hex_array(hash)?)))
+        .collect()
+}
+
+fn parse_xchain_bridge(json: &Value) -> Result<XChainBridge, BinaryCodecError> {
+    let json = as_object(json, "XChainBridge")?;
+    Ok(XChainBridge {
+        locking_chain_door: parse_account_id(get(json, "LockingChainDoor")?)?,
+        locking_chain_issue: parse_issue(get(json, "LockingChainIssue")?)?,
+        issuing_chain_door: parse_account_id(get(json, "IssuingChainDoor")?)?,
+        issuing_chain_issue: parse_issue(get(json, "IssuingChainIssue")?)?,
+    })
+}
+
+/// <https://xrpl.org/paths.html#path-specifications>
+fn parse_path_set(json: &Value) -> Result<PathSet, BinaryCodecError> {
+    let paths = as_array(json)?
+        .iter()
+        .map(|path| {
+            let steps = as_array(path)?
+                .iter()
+                .map(|step| {
+                    let step = as_object(step, "path step")?;
+                    Ok(PathStep {
+                        account: step.get("account").map(parse_account_id).transpose()?,
+                        currency: step.get("currency").map(parse_currency_code).transpose()?,
+                        issuer: step.get("issuer").map(parse_account_id).transpose()?,
+                    })
+                })
+                .collect::<Result<_, BinaryCodecError>>()?;
+            Ok(Path(steps))
+        })
+        .collect::<Result<_, BinaryCodecError>>()?;
+    Ok(PathSet(paths))
+}
+
+/// Parses decimal string, possibly in scientific notation, as used for token amount values
+/// <https://xrpl.org/currency-formats.html#string-numbers>
+fn parse_issued_value(value: &str) -> Result<IssuedValue, BinaryCodecError> {
+    let invalid = || BinaryCodecError::OutOfRange(format!("Invalid token amount value: {}", value));
+
+    let (negative, unsigned) = match value.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (decimal, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((decimal, exponent)) => (decimal, exponent.parse::<i32>().map_err(|_| invalid())?),
+        None => (unsigned, 0),
+    };
+    let (integer_part, fraction_part) = decimal.split_once('.').unwrap_or((decimal, ""));
+    if integer_part.is_empty() && fraction_part.is_empty() {
+        return Err(invalid());
+    }
+
+    // digits beyond what fits in the mantissa are truncated, like when normalizing the value
+    const MANTISSA_LIMIT: i64 = 100_000_000_000_000_000;
+    let mut mantissa: i64 = 0;
+    let mut exponent = exponent;
+    for (digit, is_fraction) in integer_part
+        .chars()
+        .map(|c| (c, false))
+        .chain(fraction_part.chars().map(|c| (c, true)))
+    {
+        let digit = digit.to_digit(10).ok_or_else(invalid)?;
+        if mantissa < MANTISSA_LIMIT {
+            mantissa = mantissa * 10 + i64::from(digit);
+            if is_fraction {
+                exponent -= 1;
+            }
+        } else if !is_fraction {
+            exponent += 1;
+        }
+    }
+
+    while exponent < i32::from(i8::MIN) && mantissa != 0 {
+        mantissa /= 10;
+        exponent += 1;
+    }
+    if mantissa == 0 {
+        return Ok(IssuedValue::zero());
+    }
+    let exponent = i8::try_from(exponent).map_err(|_| invalid())?;
+    IssuedValue::from_mantissa_exponent(if negative { -mantissa } else { mantissa }, exponent)
+        .map_err(|err| BinaryCodecError::OutOfRange(err.to_string()))
+}
+
+/// Formats token amount value the same way as rippled does
+fn issued_value_to_string(value: IssuedValue) -> String {
+    if value.mantissa() == 0 {
+        return "0".to_string();
+    }
+    let sign = if value.mantissa() < 0 { "-" } else { "" };
+    let digits = value.mantissa().unsigned_abs().to_string();
+    let exponent = i32::from(value.exponent());
+
+    if exponent != 0 && !(-25..=-5).contains(&exponent) {
+        return format!("{}{}e{}", sign, digits, exponent);
+    }
+
+    let point = digits.len() as i32 + exponent;
+    let (integer_part, fraction_part) = if point > 0 {
+        let (integer_part, fraction_part) = digits.split_at(point as usize);
+        (integer_part.to_string(), fraction_part.to_string())
+    } else {
+        ("0".to_string(), "0".repeat((-point) as usize) + &digits)
+    };
+    let fraction_part = fraction_part.trim_end_matches('0');
+    if fraction_part.is_empty() {
+        format!("{}{}", sign, integer_part)
+    } else {
+        format!("{}{}.{}", sign, integer_part, fraction_part)
+    }
+}
+
+fn uint8_to_json(definitions: &Definitions, field_name: &str, value: UInt8) -> Value {
+    match field_name {
+        "TransactionResult" => {
+            name_or_number(definitions.transaction_result_name(value.into()), value)
+        }
+        _ => Value::from(value),
+    }
+}
+
+fn uint16_to_json(definitions: &Definitions, field_name: &str, value: UInt16) -> Value {
+    match field_name {
+        "TransactionType" => name_or_number(definitions.transaction_type_name(value), value),
+        "LedgerEntryType" => name_or_number(definitions.ledger_entry_type_name(value), value),
+        _ => Value::from(value),
+    }
+}
+
+fn uint64_to_json(value: UInt64) -> Value {
+    Value::String(format!("{:016X}", value))
+}
+
+fn amount_to_json(amount: Amount) -> Value {
+    match amount {
+        Amount::Drops(drops) => Value::String(drops.drops().to_string()),
+        Amount::Issued(issued) => {
+            let mut json = Map::new();
+            json.insert(
+                "value".to_string(),
+                Value::String(issued_value_to_string(issued.value())),
+            );
+            json.insert(
+                "currency".to_string(),
+                Value::String(issued.currency().to_string()),
+            );
+            json.insert(
+                "issuer".to_string(),
+                Value::String(issued.issuer().to_address()),
+            );
+            Value::Object(json)
+        }
+    }
+}
+
+fn issue_to_json(issue: Currency) -> Value {
+    let mut json = Map::new();
+    match issue {
+        Currency::Xrp => {
+            json.insert("currency".to_string(), Value::String("XRP".to_string()));
+        }
+        Currency::Issued(issued) => {
+            json.insert(
+                "currency".to_string(),
+                Value::String(issued.currency_code().to_string()),
+            );
+            json.insert(
+                "issuer".to_string(),
+                Value::String(issued.issuer().to_address()),
+            );
+        }
+    }
+    Value::Object(json)
+}
+
+fn vector256_to_json(hashes: &[Hash256]) -> Value {
+    Value::Array(
+        hashes
+            .iter()
+            .map(|hash| Value::String(hash.to_hex()))
+            .collect(),
+    )
+}
+
+fn xchain_bridge_to_json(xchain_bridge: &XChainBridge) -> Value {
+    let mut json = Map::new();
+    json.insert(
+        "LockingChainDoor".to_string(),
+        Value::String(xchain_bridge.locking_chain_door.to_address()),
+    );
+    json.insert(
+        "LockingChainIssue".to_string(),
+        issue_to_json(xchain_bridge.locking_chain_issue),
+    );
+    json.insert(
+        "IssuingChainDoor".to_string(),
+        Value::String(xchain_bridge.issuing_chain_door.to_address()),
+    );
+    json.insert(
+        "IssuingChainIssue".to_string(),
+        issue_to_json(xchain_bridge.issuing_chain_issue),
+    );
+    Value::Object(json)
+}
+
+fn path_set_to_json(path_set: &PathSet) -> Value {
+    Value::Array(
+        path_set
+            .0
+            .iter()
+            .map(|path| {
+                Value::Array(
+                    path.0
+                        .iter()
+                        .map(|step| {
+                            let mut json = Map::new();
+                            if let Some(account) = step.account {
+                                json.insert(
+                                    "account".to_string(),
+                                    Value::String(account.to_address()),
+                                );
+                            }
+                            if let Some(currency) = step.currency {
+                                json.insert(
+                                    "currency".to_string(),
+                                    Value::String(currency.to_string()),
+                                );
+                            }
+                            if let Some(issuer) = step.issuer {
+                                json.insert(
+                                    "issuer".to_string(),
+                                    Value::String(issuer.to_address()),
+                                );
+                            }
+                            Value::Object(json)
+                        })
+                        .collect(),
+                )
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+    use serde_json::json;
+    use xrpl_types::{DropsAmount, FieldValue, PaymentFlags, Transaction, TransactionTrait};
+
+    /// Example from <https://xrpl.org/submit.html>
+    const TX_BLOB: &str = "1200002280000000240000016861D4838D7EA4C6800000000000000000000000000055534400000000004B4E9C06F24296074F7BC48F92A97916C6DC5EA9684000000000002710732103AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB7446304402200E5C2DD81FDF0BE9AB2A8D797885ED49E804DBF28E806604D878756410CA98B102203349581946B0DDA06B36B35DBC20EDA27552C1F167BCF5C6ECFF49C6A46F858081144B4E9C06F24296074F7BC48F92A97916C6DC5EA983143E9D4A2B8AA0780F682D136F7A56D6724EF53754";
+
+    fn tx_json() -> Value {
+        json!({
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "Amount": {
+                "currency": "USD",
+                "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "value": "1"
+            },
+            "Destination": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+            "Fee": "10000",
+            "Flags": 2147483648u32,
+            "Sequence": 360,
+            "SigningPubKey": "03AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB",
+            "TransactionType": "Payment",
+            "TxnSignature": "304402200E5C2DD81FDF0BE9AB2A8D797885ED49E804DBF28E806604D878756410CA98B102203349581946B0DDA06B36B35DBC20EDA27552C1F167BCF5C6ECFF49C6A46F8580"
+        })
+    }
+
+    #[test]
+    fn test_encode_transaction() {
+        let mut json = tx_json();
+        // fields that are not part of the binary format are ignored
+        json["hash"] = json!("4D5D90890F8D49519E4151938601EF3D0B30B16CD6A519D9C99102C9FA77F7E0");
+        let bytes = encode(&json).unwrap();
+        assert_eq!(hex::encode_upper(bytes), TX_BLOB);
+    }
+
+    #[test]
+    fn test_decode_transaction() {
+        let json = decode(&hex::decode(TX_BLOB).unwrap()).unwrap();
+        assert_eq!(json, tx_json());
+    }
+
+    #[test]
+    fn test_encode_decode_nested_objects_and_arrays() {
+        let json = json!({
+            "TransactionIndex": 3,
+            "TransactionResult": "tesSUCCESS",
+            "AffectedNodes": [
+                {
+                    "ModifiedNode": {
+                        "LedgerEntryType": "AccountRoot",
+                        "FinalFields": {
+                            "Flags": 0
+                        }
+                    }
+                }
+            ]
+        });
+        let bytes = encode(&json).unwrap();
+        assert_eq!(
+            hex::encode_upper(&bytes),
+            "201C00000003F8E5110061E72200000000E1E1F1031000"
+        );
+        assert_eq!(decode(&bytes).unwrap(), json);
+    }
+
+    #[test]
+    fn test_encode_decode_field_types() {
+        let json = json!({
+            "Amendments": [
+                "42426C4D4F1009EE67080A9B7965B44656D7714D104A72F9B4369F97ABF044EE",
+                "4C97EBA926031A7CF7D7B36FDE3ED66DDA5421192D63DE53FFB46E43B9DC8373"
+            ],
+            "Asset": {"currency": "XRP"},
+            "Asset2": {"currency": "USD", "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"},
+            "Paths": [
+                [{"account": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX"}],
+                [
+                    {"currency": "USD", "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"},
+                    {"currency": "XRP"}
+                ]
+            ],
+            "XChainBridge": {
+                "LockingChainDoor": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "LockingChainIssue": {"currency": "XRP"},
+                "IssuingChainDoor": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+                "IssuingChainIssue": {"currency": "XRP"}
+            },
+            "OwnerNode": "000000000000001A",
+            "EmailHash": "98B4375E1D753E5B91627516F6D70977",
+            "TakerPaysCurrency": "0000000000000000000000005553440000000000",
+            "LedgerEntryType": "Offer",
+            "TickSize": 5,
+            "SendMax": {
+                "currency": "0158415500000000C1F76FF6ECB0BAC600000000",
+                "issuer": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+                "value": "-0.0012"
+            },
+            "Memos": [
+                {"Memo": {"MemoData": "", "MemoType": "6D656D6F"}}
+            ]
+        });
+        let bytes = encode(&json).unwrap();
+        assert_eq!(decode(&bytes).unwrap(), json);
+    }
+
+    #[test]
+    fn test_encode_unknown_field() {
+        let result = encode(&json!({"NotAField": 1}));
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("NotAField is not known"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_encode_invalid_value() {
+        let result = encode(&json!({"Sequence": "1"}));
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("Expected unsigned integer"), "message: {}", message);
+        });
+        let result = encode(&json!({"TransactionType": "NotATransaction"}));
+        assert_matches!(result, Err(BinaryCodecError::OutOfRange(message)) => {
+            assert!(message.contains("Unknown transaction type"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_decode_unknown_type_names_as_numbers() {
+        let json = decode(&[0x12, 0x00, 0x58]).unwrap();
+        assert_eq!(json, json!({"TransactionType": 88}));
+    }
+
+    #[test]
+    fn test_parse_issued_value() {
+        assert_eq!(
+            parse_issued_value("1").unwrap(),
+            IssuedValue::from_mantissa_exponent(1, 0).unwrap()
+        );
+        assert_eq!(
+            parse_issued_value("-12.50").unwrap(),
+            IssuedValue::from_mantissa_exponent(-125, -1).unwrap()
+        );
+        assert_eq!(
+            parse_issued_value("0.000001").unwrap(),
+            IssuedValue::from_mantissa_exponent(1, -6).unwrap()
+        );
+        assert_eq!(
+            parse_issued_value("1.5e-20").unwrap(),
+            IssuedValue::from_mantissa_exponent(15, -21).unwrap()
+        );
+        assert_eq!(
+            parse_issued_value("12345678901234567890").unwrap(),
+            IssuedValue::from_mantissa_exponent(1234567890123456, 4).unwrap()
+        );
+        assert_eq!(parse_issued_value("0.0").unwrap(), IssuedValue::zero());
+        assert_eq!(parse_issued_value("1e-200").unwrap(), IssuedValue::zero());
+        assert_matches!(
+            parse_issued_value("1e100"),
+            Err(BinaryCodecError::OutOfRange(_))
+        );
+        assert_matches!(
+            parse_issued_value("1.2.3"),
+            Err(BinaryCodecError::OutOfRange(_))
+        );
+        assert_matches!(parse_issued_value(""), Err(BinaryCodecError::OutOfRange(_)));
+    }
+
+    #[test]
+    fn test_issued_value_to_string() {
+        let value = |mantissa, exponent| {
+            issued_value_to_string(IssuedValue::from_mantissa_exponent(mantissa, exponent).unwrap())
+        };
+        assert_eq!(value(0, 0), "0");
+        assert_eq!(value(1, 0), "1");
+        assert_eq!(value(-125, -1), "-12.5");
+        assert_eq!(value(1, -6), "0.000001");
+        assert_eq!(value(1, 6), "1000000");
+        assert_eq!(value(1, 12), "1000000000000000e-3");
+        assert_eq!(value(15, -21), "1500000000000000e-35");
+    }
+
+    #[test]
+    fn test_deserialize_serialize_transaction() {
+        let mut json = tx_json();
+        json["hash"] = json!("4D5D90890F8D49519E4151938601EF3D0B30B16CD6A519D9C99102C9FA77F7E0");
+        let transaction: Transaction = deserialize(&json).unwrap();
+        assert_matches!(&transaction, Transaction::Payment(payment) => {
+            assert_eq!(payment.flags, Some(PaymentFlags::FullyCanonicalSig.into()));
+            assert_eq!(payment.common.sequence, Some(360));
+            assert_eq!(payment.common.fee, Some(DropsAmount::from_drops(10000).unwrap()));
+        });
+
+        assert_eq!(serialize(&transaction).unwrap(), tx_json());
+        assert_eq!(
+            hex::encode_upper(crate::serialize::serialize(&transaction).unwrap()),
+            TX_BLOB
+        );
+    }
+
+    #[test]
+    fn test_deserialize_serialize_transaction_with_array() {
+        let mut json = tx_json();
+        json["Memos"] = json!([
+            {"Memo": {"MemoData": "72656E74", "MemoType": "687474703A2F2F6578616D706C652E636F6D2F6D656D6F2F67656E65726963"}},
+            {"Memo": {"MemoData": "", "MemoFormat": "746578742F706C61696E", "MemoType": "6D656D6F"}}
+        ]);
+        let transaction: Transaction = deserialize(&json).unwrap();
+        assert_eq!(transaction.common().memos.len(), 2);
+        assert_eq!(serialize(&transaction).unwrap(), json);
+    }
+
+    /// Tests that fields not modelled by the transaction are kept
+    #[test]
+    fn test_deserialize_serialize_transaction_unknown_field() {
+        let mut json = tx_json();
+        json["OfferSequence"] = json!(1);
+        let transaction: Transaction = deserialize(&json).unwrap();
+        assert_eq!(
+            transaction.common().unknown_fields.get("OfferSequence"),
+            Some(&FieldValue::UInt32(1))
+        );
+        assert_eq!(serialize(&transaction).unwrap(), json);
+    }
+
+    #[test]
+    fn test_deserialize_transaction_missing_field() {
+        let mut json = tx_json();
+        json.as_object_mut().unwrap().remove("TransactionType");
+        let result = deserialize::<Transaction>(&json);
+        assert_matches!(result, Err(BinaryCodecError::MissingField(field)) => {
+            assert_eq!(field, "TransactionType");
+        });
+    }
+
+    #[test]
+    fn test_deserialize_wrong_type() {
+        let mut json = tx_json();
+        json["Amount"] = json!(1);
+        let result = deserialize::<Transaction>(&json);
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("Expected amount"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_serialize_object_field() {
+        struct Inner;
+
+        impl Serialize for Inner {
+            fn serialize<S: xrpl_types::serialize::Serializer>(
+                &self,
+                serializer: &mut S,
+            ) -> Result<(), S::Error> {
+                serializer.serialize_uint32("Flags", 0)
+            }
+        }
+
+        struct Outer;
+
+        impl Serialize for Outer {
+            fn serialize<S: xrpl_types::serialize::Serializer>(
+                &self,
+                serializer: &mut S,
+            ) -> Result<(), S::Error> {
+                serializer.serialize_uint16("LedgerEntryType", 0x0061)?;
+                serializer.serialize_object("FinalFields", &Inner)
+            }
+        }
+
+        assert_eq!(
+            serialize(&Outer).unwrap(),
+            json!({"LedgerEntryType": "AccountRoot", "FinalFields": {"Flags": 0}})
+        );
+    }
+
+    #[test]
+    fn test_serialize_unknown_field() {
+        struct Object;
+
+        impl Serialize for Object {
+            fn serialize<S: xrpl_types::serialize::Serializer>(
+                &self,
+                serializer: &mut S,
+            ) -> Result<(), S::Error> {
+                serializer.serialize_uint32("Amount", 0)
+            }
+        }
+
+        let result = serialize(&Object);
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("Amount must have type Amount"), "message: {}", message);
+        });
+    }
+}
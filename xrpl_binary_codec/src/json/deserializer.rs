@@ -0,0 +1,298 @@
+use super::{
+    as_array, as_object, is_field, parse_account_id, parse_amount, parse_hex, parse_hex_array,
+    parse_issue, parse_path_set, parse_uint, parse_uint16, parse_uint64, parse_uint8,
+    parse_vector256, parse_xchain_bridge,
+};
+use crate::alloc::format;
+use crate::alloc::string::{String, ToString};
+use crate::alloc::vec::Vec;
+use crate::definitions::Definitions;
+use crate::field::{FieldId, TypeCode};
+use crate::BinaryCodecError;
+use core::slice;
+use serde_json::{Map, Value};
+use xrpl_types::deserialize::{self, Deserialize, Visitor};
+use xrpl_types::{
+    AccountId, Amount, Blob, Currency, FieldValue, Hash128, Hash160, Hash256, PathSet, UInt16,
+    UInt32, UInt64, UInt8, XChainBridge,
+};
+
+/// Deserializes objects from the JSON representation used by rippled <https://xrpl.org/serialization.html#json-format>,
+/// e.g. the `tx_json` of a transaction. Fields are visited in canonical field order
+/// <https://xrpl.org/serialization.html#canonical-field-order>, and keys starting with a lowercase letter,
+/// like `hash` or `ledger_index`, are ignored.
+#[derive(Debug)]
+pub struct JsonDeserializer<'a> {
+    definitions: &'a Definitions,
+    json: &'a Map<String, Value>,
+    /// Fields already deserialized by [`deserialize::Deserializer::deserialize_single_field`]
+    deserialized_fields: Vec<&'a str>,
+}
+
+impl<'a> JsonDeserializer<'a> {
+    pub fn new(json: &'a Map<String, Value>) -> Self {
+        Self::with_definitions(json, Definitions::builtin())
+    }
+
+    /// Creates deserializer that looks up fields in the given definitions
+    pub fn with_definitions(json: &'a Map<String, Value>, definitions: &'a Definitions) -> Self {
+        Self {
+            definitions,
+            json,
+            deserialized_fields: Vec::new(),
+        }
+    }
+
+    fn field_id(&self, field_name: &str) -> Result<FieldId, BinaryCodecError> {
+        self.definitions.field_id(field_name).ok_or_else(|| {
+            BinaryCodecError::InvalidField(format!("Field with name {} is not known", field_name))
+        })
+    }
+}
+
+impl<'a> deserialize::Deserializer for JsonDeserializer<'a> {
+    type Error = BinaryCodecError;
+
+    fn deserialize<V: Visitor>(self, visitor: &mut V) -> Result<(), Self::Error> {
+        let mut fields = Vec::with_capacity(self.json.len());
+        for (field_name, json) in self.json {
+            if is_field(field_name) && !self.deserialized_fields.contains(&field_name.as_str()) {
+                fields.push((self.field_id(field_name)?, field_name.as_str(), json));
+            }
+        }
+        fields.sort_by_key(|(field_id, _, _)| *field_id);
+
+        for (field_id, field_name, json) in fields {
+            match field_id.type_code {
+                TypeCode::Array => {
+                    let array_deserializer = JsonArrayDeserializer {
+                        definitions: self.definitions,
+                        elements: as_array(json)?.iter(),
+                    };
+                    visitor.visit_array(field_name, array_deserializer)?;
+                }
+                TypeCode::Object => {
+                    let deserializer = JsonDeserializer::with_definitions(
+                        as_object(json, field_name)?,
+                        self.definitions,
+                    );
+                    visitor.visit_object(field_name, deserializer)?;
+                }
+                type_code => {
+                    let field_accessor = JsonFieldAccessor {
+                        definitions: self.definitions,
+                        field_name,
+                        type_code,
+                        json,
+                    };
+                    visitor.visit_field(field_name, field_accessor)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn deserialize_single_field(
+        &mut self,
+        field_name: &str,
+    ) -> Result<impl deserialize::FieldAccessor<Error = BinaryCodecError>, Self::Error> {
+        let field_id = self.field_id(field_name)?;
+        let (field_name, json) = self
+            .json
+            .get_key_value(field_name)
+            .ok_or_else(|| BinaryCodecError::MissingField(field_name.to_string()))?;
+        self.deserialized_fields.push(field_name);
+
+        Ok(JsonFieldAccessor {
+            definitions: self.definitions,
+            field_name,
+            type_code: field_id.type_code,
+            json,
+        })
+    }
+}
+
+/// Deserializes array elements, each represented as an object with a single field
+#[derive(Debug)]
+pub struct JsonArrayDeserializer<'a> {
+    definitions: &'a Definitions,
+    elements: slice::Iter<'a, Value>,
+}
+
+impl<'a> deserialize::ArrayDeserializer for JsonArrayDeserializer<'a> {
+    type Error = BinaryCodecError;
+
+    fn deserialize_object<T: Deserialize>(
+        &mut self,
+        expected_field_name: &str,
+    ) -> Result<Option<T>, Self::Error> {
+        let Some(element) = self.elements.next() else {
+            return Ok(None);
+        };
+
+        let (field_name, object) = single_entry(element)?;
+
+        if field_name == expected_field_name {
+            let deserializer = JsonDeserializer::with_definitions(
+                as_object(object, field_name)?,
+                self.definitions,
+            );
+            Ok(Some(T::deserialize(deserializer)?))
+        } else {
+            Err(BinaryCodecError::InvalidField(format!(
+                "Expected field {}, found {}",
+                expected_field_name, field_name
+            )))
+        }
+    }
+
+    fn deserialize_any_object<T: Deserialize>(
+        &mut self,
+    ) -> Result<Option<(String, T)>, Self::Error> {
+        let Some(element) = self.elements.next() else {
+            return Ok(None);
+        };
+
+        let (field_name, object) = single_entry(element)?;
+        let deserializer =
+            JsonDeserializer::with_definitions(as_object(object, field_name)?, self.definitions);
+        Ok(Some((field_name.clone(), T::deserialize(deserializer)?)))
+    }
+}
+
+/// Array elements are objects with a single field, e.g. `{"Memo": {...}}`
+fn single_entry(element: &Value) -> Result<(&String, &Value), BinaryCodecError> {
+    let mut entries = as_object(element, "array element")?.iter();
+    match (entries.next(), entries.next()) {
+        (Some(entry), None) => Ok(entry),
+        _ => Err(BinaryCodecError::InvalidField(format!(
+            "Array element {} must be object with single field",
+            element
+        ))),
+    }
+}
+
+#[derive(Debug)]
+struct JsonFieldAccessor<'a> {
+    definitions: &'a Definitions,
+    field_name: &'a str,
+    type_code: TypeCode,
+    json: &'a Value,
+}
+
+impl<'a> JsonFieldAccessor<'a> {
+    fn check_type(&self, expected_type_code: TypeCode) -> Result<(), BinaryCodecError> {
+        if self.type_code != expected_type_code {
+            Err(BinaryCodecError::InvalidField(format!(
+                "Expected type {}, found {}",
+                expected_type_code, self.type_code
+            )))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'a> deserialize::FieldAccessor for JsonFieldAccessor<'a> {
+    type Error = BinaryCodecError;
+
+    fn deserialize_account_id(self) -> Result<AccountId, Self::Error> {
+        self.check_type(TypeCode::AccountId)?;
+        parse_account_id(self.json)
+    }
+
+    fn deserialize_amount(self) -> Result<Amount, Self::Error> {
+        self.check_type(TypeCode::Amount)?;
+        parse_amount(self.json)
+    }
+
+    fn deserialize_blob(self) -> Result<Blob, Self::Error> {
+        self.check_type(TypeCode::Blob)?;
+        Ok(Blob(parse_hex(self.json)?))
+    }
+
+    fn deserialize_hash128(self) -> Result<Hash128, Self::Error> {
+        self.check_type(TypeCode::Hash128)?;
+        Ok(Hash128(parse_hex_array(self.json)?))
+    }
+
+    fn deserialize_hash160(self) -> Result<Hash160, Self::Error> {
+        self.check_type(TypeCode::Hash160)?;
+        Ok(Hash160(parse_hex_array(self.json)?))
+    }
+
+    fn deserialize_hash256(self) -> Result<Hash256, Self::Error> {
+        self.check_type(TypeCode::Hash256)?;
+        Ok(Hash256(parse_hex_array(self.json)?))
+    }
+
+    fn deserialize_uint8(self) -> Result<UInt8, Self::Error> {
+        self.check_type(TypeCode::UInt8)?;
+        parse_uint8(self.definitions, self.field_name, self.json)
+    }
+
+    fn deserialize_uint16(self) -> Result<UInt16, Self::Error> {
+        self.check_type(TypeCode::UInt16)?;
+        parse_uint16(self.definitions, self.field_name, self.json)
+    }
+
+    fn deserialize_uint32(self) -> Result<UInt32, Self::Error> {
+        self.check_type(TypeCode::UInt32)?;
+        parse_uint(self.json)
+    }
+
+    fn deserialize_uint64(self) -> Result<UInt64, Self::Error> {
+        self.check_type(TypeCode::UInt64)?;
+        parse_uint64(self.json)
+    }
+
+    fn deserialize_path_set(self) -> Result<PathSet, Self::Error> {
+        self.check_type(TypeCode::PathSet)?;
+        parse_path_set(self.json)
+    }
+
+    fn deserialize_vector256(self) -> Result<Vec<Hash256>, Self::Error> {
+        self.check_type(TypeCode::Vector256)?;
+        parse_vector256(self.json)
+    }
+
+    fn deserialize_issue(self) -> Result<Currency, Self::Error> {
+        self.check_type(TypeCode::Issue)?;
+        parse_issue(self.json)
+    }
+
+    fn deserialize_xchain_bridge(self) -> Result<XChainBridge, Self::Error> {
+        self.check_type(TypeCode::XChainBridge)?;
+        parse_xchain_bridge(self.json)
+    }
+
+    fn deserialize_any(self) -> Result<FieldValue, Self::Error> {
+        let json = self.json;
+        Ok(match self.type_code {
+            TypeCode::AccountId => FieldValue::AccountId(parse_account_id(json)?),
+            TypeCode::Amount => FieldValue::Amount(parse_amount(json)?),
+            TypeCode::Blob => FieldValue::Blob(Blob(parse_hex(json)?)),
+            TypeCode::Hash128 => FieldValue::Hash128(Hash128(parse_hex_array(json)?)),
+            TypeCode::Hash160 => FieldValue::Hash160(Hash160(parse_hex_array(json)?)),
+            TypeCode::Hash256 => FieldValue::Hash256(Hash256(parse_hex_array(json)?)),
+            TypeCode::UInt8 => {
+                FieldValue::UInt8(parse_uint8(self.definitions, self.field_name, json)?)
+            }
+            TypeCode::UInt16 => {
+                FieldValue::UInt16(parse_uint16(self.definitions, self.field_name, json)?)
+            }
+            TypeCode::UInt32 => FieldValue::UInt32(parse_uint(json)?),
+            TypeCode::UInt64 => FieldValue::UInt64(parse_uint64(json)?),
+            TypeCode::PathSet => FieldValue::PathSet(parse_path_set(json)?),
+            TypeCode::Vector256 => FieldValue::Vector256(parse_vector256(json)?),
+            TypeCode::Issue => FieldValue::Issue(parse_issue(json)?),
+            TypeCode::XChainBridge => FieldValue::XChainBridge(parse_xchain_bridge(json)?),
+            TypeCode::Object | TypeCode::Array => {
+                return Err(BinaryCodecError::InvalidField(format!(
+                    "Field of type {} cannot be deserialized as single value",
+                    self.type_code
+                )))
+            }
+        })
+    }
+}
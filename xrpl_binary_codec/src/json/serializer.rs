@@ -0,0 +1,272 @@
+use super::{
+    amount_to_json, issue_to_json, path_set_to_json, uint16_to_json, uint64_to_json, uint8_to_json,
+    vector256_to_json, xchain_bridge_to_json,
+};
+use crate::alloc::format;
+use crate::alloc::string::{String, ToString};
+use crate::alloc::vec::Vec;
+use crate::definitions::Definitions;
+use crate::field::TypeCode;
+use crate::BinaryCodecError;
+use serde_json::{Map, Value};
+use xrpl_types::serialize::{self, Serialize};
+use xrpl_types::{
+    AccountId, Amount, Blob, Currency, Hash128, Hash160, Hash256, PathSet, UInt16, UInt32, UInt64,
+    UInt8, XChainBridge,
+};
+
+/// Serializes objects to the JSON representation used by rippled <https://xrpl.org/serialization.html#json-format>,
+/// e.g. the `tx_json` of a transaction
+#[derive(Debug)]
+pub struct JsonSerializer<'d> {
+    definitions: &'d Definitions,
+    json: Map<String, Value>,
+}
+
+impl Default for JsonSerializer<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'d> JsonSerializer<'d> {
+    pub fn new() -> Self {
+        Self::with_definitions(Definitions::builtin())
+    }
+
+    /// Creates serializer that looks up fields in the given definitions
+    pub fn with_definitions(definitions: &'d Definitions) -> Self {
+        Self {
+            definitions,
+            json: Map::new(),
+        }
+    }
+
+    /// Returns the serialized JSON object
+    pub fn into_json(self) -> Value {
+        Value::Object(self.json)
+    }
+
+    fn check_field(&self, field_name: &str, field_type: TypeCode) -> Result<(), BinaryCodecError> {
+        let field_id = self.definitions.field_id(field_name).ok_or_else(|| {
+            BinaryCodecError::InvalidField(format!("Field with name {} is not known", field_name))
+        })?;
+        if field_type != field_id.type_code {
+            return Err(BinaryCodecError::InvalidField(format!(
+                "Field with name {} must have type {}",
+                field_name, field_id.type_code
+            )));
+        }
+        Ok(())
+    }
+
+    fn insert_field(
+        &mut self,
+        field_name: &str,
+        field_type: TypeCode,
+        value: Value,
+    ) -> Result<(), BinaryCodecError> {
+        self.check_field(field_name, field_type)?;
+        self.json.insert(field_name.to_string(), value);
+        Ok(())
+    }
+
+    fn object_to_json<T: Serialize>(&self, object: &T) -> Result<Value, BinaryCodecError> {
+        let mut serializer = Self::with_definitions(self.definitions);
+        object.serialize(&mut serializer)?;
+        Ok(serializer.into_json())
+    }
+}
+
+impl<'d> serialize::Serializer for JsonSerializer<'d> {
+    type Error = BinaryCodecError;
+    type ArraySerializer<'a>
+        = JsonArraySerializer<'a, 'd>
+    where
+        Self: 'a;
+
+    fn serialize_account_id(
+        &mut self,
+        field_name: &str,
+        account_id: AccountId,
+    ) -> Result<(), BinaryCodecError> {
+        self.insert_field(
+            field_name,
+            TypeCode::AccountId,
+            Value::String(account_id.to_address()),
+        )
+    }
+
+    fn serialize_amount(
+        &mut self,
+        field_name: &str,
+        amount: Amount,
+    ) -> Result<(), BinaryCodecError> {
+        self.insert_field(field_name, TypeCode::Amount, amount_to_json(amount))
+    }
+
+    fn serialize_blob(&mut self, field_name: &str, blob: &Blob) -> Result<(), BinaryCodecError> {
+        self.insert_field(field_name, TypeCode::Blob, Value::String(blob.to_hex()))
+    }
+
+    fn serialize_hash128(
+        &mut self,
+        field_name: &str,
+        hash128: Hash128,
+    ) -> Result<(), BinaryCodecError> {
+        self.insert_field(
+            field_name,
+            TypeCode::Hash128,
+            Value::String(hash128.to_hex()),
+        )
+    }
+
+    fn serialize_hash160(
+        &mut self,
+        field_name: &str,
+        hash160: Hash160,
+    ) -> Result<(), BinaryCodecError> {
+        self.insert_field(
+            field_name,
+            TypeCode::Hash160,
+            Value::String(hash160.to_hex()),
+        )
+    }
+
+    fn serialize_hash256(
+        &mut self,
+        field_name: &str,
+        hash256: Hash256,
+    ) -> Result<(), BinaryCodecError> {
+        self.insert_field(
+            field_name,
+            TypeCode::Hash256,
+            Value::String(hash256.to_hex()),
+        )
+    }
+
+    fn serialize_uint8(&mut self, field_name: &str, uint8: UInt8) -> Result<(), BinaryCodecError> {
+        let value = uint8_to_json(self.definitions, field_name, uint8);
+        self.insert_field(field_name, TypeCode::UInt8, value)
+    }
+
+    fn serialize_uint16(
+        &mut self,
+        field_name: &str,
+        uint16: UInt16,
+    ) -> Result<(), BinaryCodecError> {
+        let value = uint16_to_json(self.definitions, field_name, uint16);
+        self.insert_field(field_name, TypeCode::UInt16, value)
+    }
+
+    fn serialize_uint32(
+        &mut self,
+        field_name: &str,
+        uint32: UInt32,
+    ) -> Result<(), BinaryCodecError> {
+        self.insert_field(field_name, TypeCode::UInt32, Value::from(uint32))
+    }
+
+    fn serialize_uint64(
+        &mut self,
+        field_name: &str,
+        uint64: UInt64,
+    ) -> Result<(), BinaryCodecError> {
+        self.insert_field(field_name, TypeCode::UInt64, uint64_to_json(uint64))
+    }
+
+    fn serialize_path_set(
+        &mut self,
+        field_name: &str,
+        path_set: &PathSet,
+    ) -> Result<(), BinaryCodecError> {
+        self.insert_field(field_name, TypeCode::PathSet, path_set_to_json(path_set))
+    }
+
+    fn serialize_vector256(
+        &mut self,
+        field_name: &str,
+        vector256: &[Hash256],
+    ) -> Result<(), BinaryCodecError> {
+        self.insert_field(
+            field_name,
+            TypeCode::Vector256,
+            vector256_to_json(vector256),
+        )
+    }
+
+    fn serialize_issue(
+        &mut self,
+        field_name: &str,
+        issue: Currency,
+    ) -> Result<(), BinaryCodecError> {
+        self.insert_field(field_name, TypeCode::Issue, issue_to_json(issue))
+    }
+
+    fn serialize_xchain_bridge(
+        &mut self,
+        field_name: &str,
+        xchain_bridge: &XChainBridge,
+    ) -> Result<(), BinaryCodecError> {
+        self.insert_field(
+            field_name,
+            TypeCode::XChainBridge,
+            xchain_bridge_to_json(xchain_bridge),
+        )
+    }
+
+    fn serialize_array(
+        &mut self,
+        field_name: &str,
+    ) -> Result<JsonArraySerializer<'_, 'd>, BinaryCodecError> {
+        self.check_field(field_name, TypeCode::Array)?;
+        Ok(JsonArraySerializer {
+            serializer: self,
+            field_name: field_name.to_string(),
+            elements: Vec::new(),
+        })
+    }
+
+    fn serialize_object<T: Serialize>(
+        &mut self,
+        field_name: &str,
+        object: &T,
+    ) -> Result<(), BinaryCodecError> {
+        let value = self.object_to_json(object)?;
+        self.insert_field(field_name, TypeCode::Object, value)
+    }
+}
+
+/// Serializes array elements, each represented as an object with a single field
+#[derive(Debug)]
+pub struct JsonArraySerializer<'a, 'd> {
+    serializer: &'a mut JsonSerializer<'d>,
+    field_name: String,
+    elements: Vec<Value>,
+}
+
+impl serialize::ArraySerializer for JsonArraySerializer<'_, '_> {
+    type Error = BinaryCodecError;
+
+    fn serialize_object<T: Serialize>(
+        &mut self,
+        field_name: &str,
+        object: &T,
+    ) -> Result<(), BinaryCodecError> {
+        self.serializer.check_field(field_name, TypeCode::Object)?;
+        let mut element = Map::new();
+        element.insert(
+            field_name.to_string(),
+            self.serializer.object_to_json(object)?,
+        );
+        self.elements.push(Value::Object(element));
+        Ok(())
+    }
+
+    fn end(self) -> Result<(), BinaryCodecError> {
+        self.serializer
+            .json
+            .insert(self.field_name, Value::Array(self.elements));
+        Ok(())
+    }
+}
@@ -0,0 +1,353 @@
+use crate::alloc::vec::Vec;
+use crate::hash;
+use xrpl_types::{AccountId, Currency, CurrencyCode, Hash256, UInt32};
+
+/// Space keys that prefix the data hashed into ledger object indexes.
+/// See `LedgerNameSpace` in <https://github.com/XRPLF/rippled/blob/develop/src/libxrpl/protocol/Indexes.cpp>
+#[derive(Debug, Clone, Copy)]
+#[repr(u16)]
+enum LedgerNameSpace {
+    Account = b'a' as u16,
+    DirNode = b'd' as u16,
+    TrustLine = b'r' as u16,
+    Offer = b'o' as u16,
+    OwnerDir = b'O' as u16,
+    BookDir = b'B' as u16,
+    Escrow = b'u' as u16,
+    Ticket = b'T' as u16,
+    SignerList = b'S' as u16,
+    PayChannel = b'x' as u16,
+    Check = b'C' as u16,
+    DepositPreauth = b'p' as u16,
+    Amm = b'A' as u16,
+    Did = b'I' as u16,
+}
+
+/// SHA-512Half of the space key followed by the given data
+fn index_hash(space: LedgerNameSpace, parts: &[&[u8]]) -> Hash256 {
+    let mut data = Vec::with_capacity(2 + parts.iter().map(|part| part.len()).sum::<usize>());
+    data.extend_from_slice(&(space as u16).to_be_bytes());
+    for part in parts {
+        data.extend_from_slice(part);
+    }
+    hash::sha512_half(&data)
+}
+
+/// Currency code as 160 bits <https://xrpl.org/serialization.html#currency-codes>
+fn currency_code_bytes(currency_code: CurrencyCode) -> [u8; 20] {
+    let mut bytes = [0u8; 20];
+    match currency_code {
+        CurrencyCode::Xrp => {}
+        CurrencyCode::Standard(code) => bytes[12..15].copy_from_slice(&code.as_bytes()),
+        CurrencyCode::NonStandard(code) => bytes.copy_from_slice(code.as_bytes()),
+    }
+    bytes
+}
+
+/// Currency code and issuer as 160 bits each. XRP has zero currency code and issuer.
+fn currency_issue_bytes(currency: Currency) -> ([u8; 20], [u8; 20]) {
+    match currency {
+        Currency::Xrp => ([0u8; 20], [0u8; 20]),
+        Currency::Issued(issued) => (
+            currency_code_bytes(issued.currency_code()),
+            issued.issuer().0,
+        ),
+    }
+}
+
+/// Index of `AccountRoot` <https://xrpl.org/accountroot.html#accountroot-id-format>
+pub fn account_root(account: AccountId) -> Hash256 {
+    index_hash(LedgerNameSpace::Account, &[&account.0])
+}
+
+/// Index of `RippleState` (trust line) between two accounts <https://xrpl.org/ripplestate.html#ripplestate-id-format>.
+/// The accounts can be given in any order.
+pub fn ripple_state(
+    account: AccountId,
+    other_account: AccountId,
+    currency_code: CurrencyCode,
+) -> Hash256 {
+    let (low, high) = if account.0 <= other_account.0 {
+        (account, other_account)
+    } else {
+        (other_account, account)
+    };
+    index_hash(
+        LedgerNameSpace::TrustLine,
+        &[&low.0, &high.0, &currency_code_bytes(currency_code)],
+    )
+}
+
+/// Index of `Offer` <https://xrpl.org/offer.html#offer-id-format>
+pub fn offer(account: AccountId, sequence: UInt32) -> Hash256 {
+    index_hash(
+        LedgerNameSpace::Offer,
+        &[&account.0, &sequence.to_be_bytes()],
+    )
+}
+
+/// Index of `Check` <https://xrpl.org/check.html#check-id-format>
+pub fn check(account: AccountId, sequence: UInt32) -> Hash256 {
+    index_hash(
+        LedgerNameSpace::Check,
+        &[&account.0, &sequence.to_be_bytes()],
+    )
+}
+
+/// Index of `Escrow` <https://xrpl.org/escrow-object.html#escrow-id-format>
+pub fn escrow(account: AccountId, sequence: UInt32) -> Hash256 {
+    index_hash(
+        LedgerNameSpace::Escrow,
+        &[&account.0, &sequence.to_be_bytes()],
+    )
+}
+
+/// Index of `PayChannel` <https://xrpl.org/paychannel.html#paychannel-id-format>
+pub fn pay_channel(account: AccountId, destination: AccountId, sequence: UInt32) -> Hash256 {
+    index_hash(
+        LedgerNameSpace::PayChannel,
+        &[&account.0, &destination.0, &sequence.to_be_bytes()],
+    )
+}
+
+/// Index of `Ticket` <https://xrpl.org/ticket.html#ticket-id-format>
+pub fn ticket(account: AccountId, ticket_sequence: UInt32) -> Hash256 {
+    index_hash(
+        LedgerNameSpace::Ticket,
+        &[&account.0, &ticket_sequence.to_be_bytes()],
+    )
+}
+
+/// Index of `SignerList` <https://xrpl.org/signerlist.html#signerlist-id-format>
+pub fn signer_list(account: AccountId) -> Hash256 {
+    // The signer list id is always 0
+    index_hash(
+        LedgerNameSpace::SignerList,
+        &[&account.0, &0u32.to_be_bytes()],
+    )
+}
+
+/// Index of `DepositPreauth` <https://xrpl.org/depositpreauth-object.html#depositpreauth-id-format>
+pub fn deposit_preauth(account: AccountId, authorized: AccountId) -> Hash256 {
+    index_hash(
+        LedgerNameSpace::DepositPreauth,
+        &[&account.0, &authorized.0],
+    )
+}
+
+/// Index of the root page of the owner directory of an account <https://xrpl.org/directorynode.html#directory-id-formats>
+pub fn owner_directory(account: AccountId) -> Hash256 {
+    index_hash(LedgerNameSpace::OwnerDir, &[&account.0])
+}
+
+/// Index of the first page of the order book directory with the best quality <https://xrpl.org/directorynode.html#directory-id-formats>.
+/// Offers in the book are sorted by quality, which is stored in the last 64 bits of the
+/// directory index, so the index returned has quality zero.
+pub fn book_directory(taker_pays: Currency, taker_gets: Currency) -> Hash256 {
+    let (pays_currency, pays_issuer) = currency_issue_bytes(taker_pays);
+    let (gets_currency, gets_issuer) = currency_issue_bytes(taker_gets);
+    let mut index = index_hash(
+        LedgerNameSpace::BookDir,
+        &[&pays_currency, &gets_currency, &pays_issuer, &gets_issuer],
+    );
+    index.0[24..].fill(0);
+    index
+}
+
+/// Index of the given page of a directory <https://xrpl.org/directorynode.html#directory-id-formats>.
+/// Page 0 is the root page itself.
+pub fn directory_page(root: Hash256, page: u64) -> Hash256 {
+    if page == 0 {
+        root
+    } else {
+        index_hash(LedgerNameSpace::DirNode, &[&root.0, &page.to_be_bytes()])
+    }
+}
+
+/// Lowest possible index of an `NFTokenPage` of the given owner <https://xrpl.org/nftokenpage.html#nftokenpage-id-format>
+pub fn nftoken_page_min(owner: AccountId) -> Hash256 {
+    let mut index = Hash256([0; 32]);
+    index.0[..20].copy_from_slice(&owner.0);
+    index
+}
+
+/// Highest possible index of an `NFTokenPage` of the given owner, which is the index of the
+/// last page <https://xrpl.org/nftokenpage.html#nftokenpage-id-format>
+pub fn nftoken_page_max(owner: AccountId) -> Hash256 {
+    let mut index = Hash256([0xFF; 32]);
+    index.0[..20].copy_from_slice(&owner.0);
+    index
+}
+
+/// Index composed of the owner and the low 96 bits of the token id <https://xrpl.org/nftokenpage.html#nftokenpage-id-format>.
+/// The token is stored in the first page with an index equal to or greater than this.
+pub fn nftoken_page(owner: AccountId, nftoken_id: Hash256) -> Hash256 {
+    let mut index = nftoken_id;
+    index.0[..20].copy_from_slice(&owner.0);
+    index
+}
+
+/// Index of `AMM` for the given asset pair <https://xrpl.org/amm.html#amm-id-format>.
+/// The assets can be given in any order.
+pub fn amm(asset: Currency, other_asset: Currency) -> Hash256 {
+    let (currency, issuer) = currency_issue_bytes(asset);
+    let (other_currency, other_issuer) = currency_issue_bytes(other_asset);
+    let ((min_currency, min_issuer), (max_currency, max_issuer)) =
+        if (currency, issuer) <= (other_currency, other_issuer) {
+            ((currency, issuer), (other_currency, other_issuer))
+        } else {
+            ((other_currency, other_issuer), (currency, issuer))
+        };
+    index_hash(
+        LedgerNameSpace::Amm,
+        &[&min_issuer, &min_currency, &max_issuer, &max_currency],
+    )
+}
+
+/// Index of `DID` <https://xrpl.org/did.html#did-id-format>
+pub fn did(account: AccountId) -> Hash256 {
+    index_hash(LedgerNameSpace::Did, &[&account.0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ascii::AsciiChar;
+
+    fn genesis() -> AccountId {
+        AccountId::from_address("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh").unwrap()
+    }
+
+    fn other() -> AccountId {
+        AccountId::from_address("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap()
+    }
+
+    fn usd() -> CurrencyCode {
+        CurrencyCode::standard([AsciiChar::U, AsciiChar::S, AsciiChar::D]).unwrap()
+    }
+
+    fn hash(hex: &str) -> Hash256 {
+        Hash256::from_hex(hex).unwrap()
+    }
+
+    /// Test vectors from <https://github.com/XRPLF/xrpl.js/blob/main/packages/xrpl/test/utils/hashes.test.ts>
+    #[test]
+    fn test_xrpl_js_vectors() {
+        assert_eq!(
+            account_root(genesis()),
+            hash("2B6AC232AA4C4BE41BF49D2459FA4A0347E1B543A4C92FCEE0821C0201E2E9A8")
+        );
+        assert_eq!(
+            offer(
+                AccountId::from_address("r32UufnaCGL82HubijgJGDmdE5hac7ZvLw").unwrap(),
+                137
+            ),
+            hash("03F0AED09DEEE74CEF85CD57A0429D6113507CF759C597BABB4ADB752F734CE3")
+        );
+        assert_eq!(
+            signer_list(genesis()),
+            hash("778365D5180F5DF3016817D1F318527AD7410D83F8636CF48C43E8AF72AB49BF")
+        );
+        assert_eq!(
+            escrow(
+                AccountId::from_address("rDx69ebzbowuqztksVDmZXjizTd12BVr4x").unwrap(),
+                84
+            ),
+            hash("61E8E8ED53FA2CEBE192B23897071E9A75217BF5A410E9CB5B45AAB7AECA567A")
+        );
+        assert_eq!(
+            pay_channel(
+                AccountId::from_address("rDx69ebzbowuqztksVDmZXjizTd12BVr4x").unwrap(),
+                AccountId::from_address("rLFtVprxUEfsH54eCWKsZrEQzMDsx1wqso").unwrap(),
+                82
+            ),
+            hash("E35708503B3C3143FB522D749AAFCC296E8060F0FB371A9A56FAE0B1ED127366")
+        );
+    }
+
+    #[test]
+    fn test_ripple_state() {
+        let expected = hash("2B28107DC6E4B479907DB3AD9EB787E0ADB87D4B5BCE4A30FDCC37ED76EF77A1");
+        assert_eq!(ripple_state(genesis(), other(), usd()), expected);
+        assert_eq!(ripple_state(other(), genesis(), usd()), expected);
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(
+            check(genesis(), 5),
+            hash("7F640CCE9CBA5B9DEF70D455B9BFFB1C9D500A5409B7AA84275C542AC0C35AE5")
+        );
+    }
+
+    #[test]
+    fn test_ticket() {
+        assert_eq!(
+            ticket(genesis(), 7),
+            hash("38EF979A371455DF7B79A56CFB7F6840741BD83A26E07708C8964D9606909CA4")
+        );
+    }
+
+    #[test]
+    fn test_deposit_preauth() {
+        assert_eq!(
+            deposit_preauth(genesis(), other()),
+            hash("4D8F0BE316DAB5C1934C56E6AD7965B46766E6DD10A9940983D8F598BDD09350")
+        );
+    }
+
+    #[test]
+    fn test_owner_directory() {
+        let root = owner_directory(genesis());
+        assert_eq!(
+            root,
+            hash("D8120FC732737A2CF2E9968FDF3797A43B457F2A81AA06D2653171A1EA635204")
+        );
+        assert_eq!(directory_page(root, 0), root);
+        assert_eq!(
+            directory_page(root, 3),
+            hash("C7E21AA48111FC6E64FE5D521A1025FCCED43626EC34B2D96BBE60F40A919FC5")
+        );
+    }
+
+    #[test]
+    fn test_book_directory() {
+        assert_eq!(
+            book_directory(Currency::issued(usd(), other()).unwrap(), Currency::xrp()),
+            hash("43B83ADC452B85FCBADA6CAEAC5181C255A213630D58FFD40000000000000000")
+        );
+    }
+
+    #[test]
+    fn test_nftoken_page() {
+        let nftoken_id = hash("000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D65");
+        assert_eq!(
+            nftoken_page_min(genesis()),
+            hash("B5F762798A53D543A014CAF8B297CFF8F2F937E8000000000000000000000000")
+        );
+        assert_eq!(
+            nftoken_page_max(genesis()),
+            hash("B5F762798A53D543A014CAF8B297CFF8F2F937E8FFFFFFFFFFFFFFFFFFFFFFFF")
+        );
+        assert_eq!(
+            nftoken_page(genesis(), nftoken_id),
+            hash("B5F762798A53D543A014CAF8B297CFF8F2F937E842540EE208C3098E00000D65")
+        );
+    }
+
+    #[test]
+    fn test_amm() {
+        let expected = hash("CAA00DBC62557892BCD1632268B1FBA53FE6103F29CB63FD795301814486C2B4");
+        let usd = Currency::issued(usd(), other()).unwrap();
+        assert_eq!(amm(usd, Currency::xrp()), expected);
+        assert_eq!(amm(Currency::xrp(), usd), expected);
+    }
+
+    #[test]
+    fn test_did() {
+        assert_eq!(
+            did(genesis()),
+            hash("5D71AD4469C5444C5FBAAFCF6DAE6E625D32F74995098F4FEF3E0B0F7B36B8F9")
+        );
+    }
+}
@@ -0,0 +1,389 @@
+use crate::alloc::{format, string::ToString};
+use crate::{hash, BinaryCodecError};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+use xrpl_address_codec::SEED_LENGTH;
+use xrpl_types::{AccountId, Blob};
+
+pub use xrpl_address_codec::Algorithm;
+
+/// Prefix of ed25519 keys in their 33 byte representation, see <https://xrpl.org/cryptographic-keys.html#ed25519-key-derivation>
+pub const ED25519_KEY_PREFIX: u8 = 0xED;
+
+/// Prefix of secp256k1 secret keys in their 33 byte representation
+const SECP256K1_SECRET_KEY_PREFIX: u8 = 0x00;
+
+/// Public key of either signing algorithm, see <https://xrpl.org/cryptographic-keys.html#public-key>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PublicKey {
+    Secp256k1(libsecp256k1::PublicKey),
+    Ed25519(ed25519_dalek::VerifyingKey),
+}
+
+impl PublicKey {
+    /// Parses public key from its 33 byte representation. Ed25519 keys are prefixed with `0xED`,
+    /// secp256k1 keys are in compressed form.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BinaryCodecError> {
+        let bytes: &[u8; 33] = bytes.try_into().map_err(|_| {
+            BinaryCodecError::InvalidKey(format!(
+                "public key is {} bytes, expected 33",
+                bytes.len()
+            ))
+        })?;
+        if bytes[0] == ED25519_KEY_PREFIX {
+            let key =
+                ed25519_dalek::VerifyingKey::from_bytes(bytes[1..].try_into().expect("length 32"))
+                    .map_err(|err| BinaryCodecError::InvalidKey(err.to_string()))?;
+            Ok(Self::Ed25519(key))
+        } else {
+            let key = libsecp256k1::PublicKey::parse_compressed(bytes)
+                .map_err(|err| BinaryCodecError::InvalidKey(err.to_string()))?;
+            Ok(Self::Secp256k1(key))
+        }
+    }
+
+    /// Parses public key from the hex of its 33 byte representation
+    pub fn from_hex(hex: &str) -> Result<Self, BinaryCodecError> {
+        let bytes = hex::decode(hex)
+            .map_err(|err| BinaryCodecError::InvalidKey(format!("invalid hex: {}", err)))?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// The 33 byte representation of the key, as used in `SigningPubKey`
+    pub fn to_bytes(&self) -> [u8; 33] {
+        match self {
+            Self::Secp256k1(key) => key.serialize_compressed(),
+            Self::Ed25519(key) => {
+                let mut bytes = [ED25519_KEY_PREFIX; 33];
+                bytes[1..].copy_from_slice(key.as_bytes());
+                bytes
+            }
+        }
+    }
+
+    pub fn to_blob(&self) -> Blob {
+        Blob(self.to_bytes().to_vec())
+    }
+
+    pub fn algorithm(&self) -> Algorithm {
+        match self {
+            Self::Secp256k1(_) => Algorithm::Secp256k1,
+            Self::Ed25519(_) => Algorithm::Ed25519,
+        }
+    }
+
+    /// Account id derived from the public key, see <https://xrpl.org/cryptographic-keys.html#account-id-and-address>
+    pub fn account_id(&self) -> AccountId {
+        let sha256 = Sha256::digest(self.to_bytes());
+        let ripemd160 = Ripemd160::digest(sha256);
+        AccountId(ripemd160.into())
+    }
+}
+
+impl From<libsecp256k1::PublicKey> for PublicKey {
+    fn from(key: libsecp256k1::PublicKey) -> Self {
+        Self::Secp256k1(key)
+    }
+}
+
+impl From<ed25519_dalek::VerifyingKey> for PublicKey {
+    fn from(key: ed25519_dalek::VerifyingKey) -> Self {
+        Self::Ed25519(key)
+    }
+}
+
+/// Secret key of either signing algorithm, see <https://xrpl.org/cryptographic-keys.html#private-key>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecretKey {
+    Secp256k1(libsecp256k1::SecretKey),
+    Ed25519(ed25519_dalek::SigningKey),
+}
+
+impl SecretKey {
+    /// Parses secret key from its 33 byte representation, where ed25519 keys are prefixed with
+    /// `0xED` and secp256k1 keys with `0x00`. Unprefixed 32 byte keys are interpreted as secp256k1.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BinaryCodecError> {
+        match bytes {
+            [ED25519_KEY_PREFIX, key @ ..] if key.len() == 32 => Ok(Self::Ed25519(
+                ed25519_dalek::SigningKey::from_bytes(key.try_into().expect("length 32")),
+            )),
+            [SECP256K1_SECRET_KEY_PREFIX, key @ ..] if key.len() == 32 => {
+                Self::secp256k1_from_bytes(key)
+            }
+            key if key.len() == 32 => Self::secp256k1_from_bytes(key),
+            _ => Err(BinaryCodecError::InvalidKey(format!(
+                "secret key is {} bytes, expected 32 or 33",
+                bytes.len()
+            ))),
+        }
+    }
+
+    /// Parses secret key from the hex of its 32 or 33 byte representation
+    pub fn from_hex(hex: &str) -> Result<Self, BinaryCodecError> {
+        let bytes = hex::decode(hex)
+            .map_err(|err| BinaryCodecError::InvalidKey(format!("invalid hex: {}", err)))?;
+        Self::from_bytes(&bytes)
+    }
+
+    fn secp256k1_from_bytes(bytes: &[u8]) -> Result<Self, BinaryCodecError> {
+        let key = libsecp256k1::SecretKey::parse_slice(bytes)
+            .map_err(|err| BinaryCodecError::InvalidKey(err.to_string()))?;
+        Ok(Self::Secp256k1(key))
+    }
+
+    /// The 33 byte representation of the key, prefixed with `0x00` for secp256k1 and `0xED`
+    /// for ed25519
+    pub fn to_bytes(&self) -> [u8; 33] {
+        let (prefix, key) = match self {
+            Self::Secp256k1(key) => (SECP256K1_SECRET_KEY_PREFIX, key.serialize()),
+            Self::Ed25519(key) => (ED25519_KEY_PREFIX, key.to_bytes()),
+        };
+        let mut bytes = [prefix; 33];
+        bytes[1..].copy_from_slice(&key);
+        bytes
+    }
+
+    pub fn algorithm(&self) -> Algorithm {
+        match self {
+            Self::Secp256k1(_) => Algorithm::Secp256k1,
+            Self::Ed25519(_) => Algorithm::Ed25519,
+        }
+    }
+
+    /// Public key belonging to the secret key
+    pub fn public_key(&self) -> PublicKey {
+        match self {
+            Self::Secp256k1(key) => {
+                PublicKey::Secp256k1(libsecp256k1::PublicKey::from_secret_key(key))
+            }
+            Self::Ed25519(key) => PublicKey::Ed25519(key.verifying_key()),
+        }
+    }
+}
+
+impl From<libsecp256k1::SecretKey> for SecretKey {
+    fn from(key: libsecp256k1::SecretKey) -> Self {
+        Self::Secp256k1(key)
+    }
+}
+
+impl From<ed25519_dalek::SigningKey> for SecretKey {
+    fn from(key: ed25519_dalek::SigningKey) -> Self {
+        Self::Ed25519(key)
+    }
+}
+
+/// Public and secret key pair
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keypair {
+    pub public_key: PublicKey,
+    pub secret_key: SecretKey,
+}
+
+impl Keypair {
+    pub fn from_secret_key(secret_key: SecretKey) -> Self {
+        Self {
+            public_key: secret_key.public_key(),
+            secret_key,
+        }
+    }
+
+    /// Derives key pair from an encoded seed (`s...` for secp256k1 or `sEd...` for ed25519),
+    /// see <https://xrpl.org/cryptographic-keys.html#key-derivation>
+    pub fn from_seed(seed: &str) -> Result<Self, BinaryCodecError> {
+        let (seed, algorithm) = xrpl_address_codec::decode_seed(seed)
+            .map_err(|err| BinaryCodecError::InvalidKey(format!("invalid seed: {}", err)))?;
+        Self::derive(&seed, algorithm)
+    }
+
+    /// Derives key pair from seed using the given algorithm, see <https://xrpl.org/cryptographic-keys.html#key-derivation>
+    pub fn derive(
+        seed: &[u8; SEED_LENGTH],
+        algorithm: Algorithm,
+    ) -> Result<Self, BinaryCodecError> {
+        let secret_key = match algorithm {
+            Algorithm::Secp256k1 => SecretKey::Secp256k1(derive_secp256k1_secret_key(seed)?),
+            Algorithm::Ed25519 => SecretKey::Ed25519(derive_ed25519_secret_key(seed)),
+        };
+        Ok(Self::from_secret_key(secret_key))
+    }
+
+    pub fn algorithm(&self) -> Algorithm {
+        self.secret_key.algorithm()
+    }
+
+    /// Account id derived from the public key, see <https://xrpl.org/cryptographic-keys.html#account-id-and-address>
+    pub fn account_id(&self) -> AccountId {
+        self.public_key.account_id()
+    }
+}
+
+/// Ed25519 key derivation <https://xrpl.org/cryptographic-keys.html#ed25519-key-derivation>
+fn derive_ed25519_secret_key(seed: &[u8; SEED_LENGTH]) -> ed25519_dalek::SigningKey {
+    ed25519_dalek::SigningKey::from_bytes(&hash::sha512_half(seed).0)
+}
+
+/// Secp256k1 key derivation <https://xrpl.org/cryptographic-keys.html#secp256k1-key-derivation>.
+/// Derives the root key pair from the seed and then the intermediate key for account index 0.
+fn derive_secp256k1_secret_key(
+    seed: &[u8; SEED_LENGTH],
+) -> Result<libsecp256k1::SecretKey, BinaryCodecError> {
+    // rippled implementation: https://github.com/XRPLF/rippled/blob/develop/src/libxrpl/protocol/SecretKey.cpp
+    let root_secret_key = derive_secp256k1_scalar(seed, None)?;
+    let root_public_key =
+        libsecp256k1::PublicKey::from_secret_key(&root_secret_key).serialize_compressed();
+
+    let account_index = 0u32;
+    let intermediate_key = derive_secp256k1_scalar(&root_public_key, Some(account_index))?;
+
+    let mut secret_key = root_secret_key;
+    secret_key
+        .tweak_add_assign(&intermediate_key)
+        .map_err(|err| BinaryCodecError::InvalidKey(err.to_string()))?;
+    Ok(secret_key)
+}
+
+/// Finds the first SHA-512Half of `bytes || [account_index] || sequence` that is a valid secp256k1
+/// secret key
+fn derive_secp256k1_scalar(
+    bytes: &[u8],
+    account_index: Option<u32>,
+) -> Result<libsecp256k1::SecretKey, BinaryCodecError> {
+    for sequence in 0..=u32::MAX {
+        let mut hasher = sha2::Sha512::new();
+        hasher.update(bytes);
+        if let Some(account_index) = account_index {
+            hasher.update(account_index.to_be_bytes());
+        }
+        hasher.update(sequence.to_be_bytes());
+        let hash: [u8; 64] = hasher.finalize().into();
+        let candidate: [u8; 32] = hash[..32].try_into().expect("length 64");
+
+        // Parsing fails if the candidate is zero or not less than the curve order
+        if let Ok(key) = libsecp256k1::SecretKey::parse(&candidate) {
+            return Ok(key);
+        }
+    }
+    Err(BinaryCodecError::InvalidKey(
+        "no valid secp256k1 key found for seed".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    /// Test vector from <https://github.com/XRPLF/xrpl.js/blob/main/packages/ripple-keypairs/test/fixtures/api.json>
+    #[test]
+    fn test_derive_secp256k1() {
+        let keypair = Keypair::from_seed("sp5fghtJtpUorTwvof1NpDXAzNwf5").unwrap();
+        assert_eq!(keypair.algorithm(), Algorithm::Secp256k1);
+        assert_eq!(
+            hex::encode_upper(keypair.secret_key.to_bytes()),
+            "00D78B9735C3F26501C7337B8A5727FD53A6EFDBC6AA55984F098488561F985E23"
+        );
+        assert_eq!(
+            hex::encode_upper(keypair.public_key.to_bytes()),
+            "030D58EB48B4420B1F7B9DF55087E0E29FEF0E8468F9A6825B01CA2C361042D435"
+        );
+        assert_eq!(
+            keypair.account_id().to_address(),
+            "rU6K7V3Po4snVhBBaU29sesqs2qTQJWDw1"
+        );
+    }
+
+    /// Test vector from <https://github.com/XRPLF/xrpl.js/blob/main/packages/ripple-keypairs/test/fixtures/api.json>
+    #[test]
+    fn test_derive_ed25519() {
+        let keypair = Keypair::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r").unwrap();
+        assert_eq!(keypair.algorithm(), Algorithm::Ed25519);
+        assert_eq!(
+            hex::encode_upper(keypair.secret_key.to_bytes()),
+            "EDB4C4E046826BD26190D09715FC31F4E6A728204EADD112905B08B14B7F15C4F3"
+        );
+        assert_eq!(
+            hex::encode_upper(keypair.public_key.to_bytes()),
+            "ED01FA53FA5A7E77798F882ECE20B1ABC00BB358A9E55A202D0D0676BD0CE37A63"
+        );
+        assert_eq!(
+            keypair.account_id().to_address(),
+            "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD"
+        );
+    }
+
+    /// The genesis account derived from the seed of the passphrase `masterpassphrase`,
+    /// see <https://xrpl.org/accounts.html#special-addresses>
+    #[test]
+    fn test_derive_genesis_account() {
+        let keypair = Keypair::from_seed("snoPBrXtMeMyMHUVTgbuqAfg1SUTb").unwrap();
+        assert_eq!(
+            hex::encode_upper(keypair.public_key.to_bytes()),
+            "0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020"
+        );
+        assert_eq!(
+            keypair.account_id().to_address(),
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"
+        );
+    }
+
+    #[test]
+    fn test_derive_from_seed_bytes() {
+        let seed = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let keypair = Keypair::derive(&seed, Algorithm::Ed25519).unwrap();
+        assert_eq!(
+            keypair.account_id().to_address(),
+            "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD"
+        );
+    }
+
+    #[test]
+    fn test_from_seed_invalid() {
+        let result = Keypair::from_seed("rU6K7V3Po4snVhBBaU29sesqs2qTQJWDw1");
+        assert_matches!(result, Err(BinaryCodecError::InvalidKey(message)) => {
+            assert!(message.contains("invalid seed"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_public_key_from_bytes() {
+        let bytes =
+            hex::decode("ED01FA53FA5A7E77798F882ECE20B1ABC00BB358A9E55A202D0D0676BD0CE37A63")
+                .unwrap();
+        let public_key = PublicKey::from_bytes(&bytes).unwrap();
+        assert_eq!(public_key.algorithm(), Algorithm::Ed25519);
+        assert_eq!(public_key.to_bytes().as_slice(), bytes.as_slice());
+
+        let bytes =
+            hex::decode("030D58EB48B4420B1F7B9DF55087E0E29FEF0E8468F9A6825B01CA2C361042D435")
+                .unwrap();
+        let public_key = PublicKey::from_bytes(&bytes).unwrap();
+        assert_eq!(public_key.algorithm(), Algorithm::Secp256k1);
+        assert_eq!(public_key.to_bytes().as_slice(), bytes.as_slice());
+    }
+
+    #[test]
+    fn test_public_key_from_bytes_invalid_length() {
+        let result = PublicKey::from_bytes(&[0x02; 32]);
+        assert_matches!(result, Err(BinaryCodecError::InvalidKey(message)) => {
+            assert!(message.contains("public key is 32 bytes"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_secret_key_from_hex() {
+        let secret_key = SecretKey::from_hex(
+            "00D78B9735C3F26501C7337B8A5727FD53A6EFDBC6AA55984F098488561F985E23",
+        )
+        .unwrap();
+        assert_eq!(
+            secret_key,
+            SecretKey::from_hex("D78B9735C3F26501C7337B8A5727FD53A6EFDBC6AA55984F098488561F985E23")
+                .unwrap()
+        );
+        assert_eq!(
+            secret_key.public_key().account_id().to_address(),
+            "rU6K7V3Po4snVhBBaU29sesqs2qTQJWDw1"
+        );
+    }
+}
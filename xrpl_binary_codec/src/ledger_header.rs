@@ -0,0 +1,112 @@
+use crate::alloc::format;
+use crate::alloc::vec::Vec;
+use crate::BinaryCodecError;
+use bytes::{Buf, BufMut};
+use xrpl_types::{Hash256, LedgerHeader};
+
+/// Length of the serialized ledger header
+pub const LEDGER_HEADER_LENGTH: usize = 4 + 8 + 3 * 32 + 4 + 4 + 1 + 1;
+
+/// Serializes the ledger header as given in the `ledger_data` field returned by rippled for
+/// `"binary": true` requests. This is also the data hashed into the ledger hash.
+pub fn encode(header: &LedgerHeader) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(LEDGER_HEADER_LENGTH);
+    bytes.put_u32(header.ledger_index);
+    bytes.put_u64(header.total_coins);
+    bytes.put_slice(&header.parent_hash.0);
+    bytes.put_slice(&header.transaction_hash.0);
+    bytes.put_slice(&header.account_hash.0);
+    bytes.put_u32(header.parent_close_time);
+    bytes.put_u32(header.close_time);
+    bytes.put_u8(header.close_time_resolution);
+    bytes.put_u8(header.close_flags);
+    bytes
+}
+
+/// Deserializes the ledger header given in the `ledger_data` field returned by rippled for
+/// `"binary": true` requests
+pub fn decode(mut bytes: &[u8]) -> Result<LedgerHeader, BinaryCodecError> {
+    if bytes.len() != LEDGER_HEADER_LENGTH {
+        return Err(BinaryCodecError::InvalidLength(format!(
+            "Ledger header must be {} bytes, found {}",
+            LEDGER_HEADER_LENGTH,
+            bytes.len()
+        )));
+    }
+    Ok(LedgerHeader {
+        ledger_index: bytes.get_u32(),
+        total_coins: bytes.get_u64(),
+        parent_hash: read_hash256(&mut bytes),
+        transaction_hash: read_hash256(&mut bytes),
+        account_hash: read_hash256(&mut bytes),
+        parent_close_time: bytes.get_u32(),
+        close_time: bytes.get_u32(),
+        close_time_resolution: bytes.get_u8(),
+        close_flags: bytes.get_u8(),
+    })
+}
+
+fn read_hash256(bytes: &mut &[u8]) -> Hash256 {
+    let mut hash = [0u8; 32];
+    bytes.copy_to_slice(&mut hash);
+    Hash256(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash;
+    use assert_matches::assert_matches;
+
+    /// Header of ledger 15202439 from <https://xrpl.org/ledger-header.html>
+    const LEDGER_DATA: &str = "00E7F8870163446858B8673D12724A65B030C15A1573AA28B1BBB5DF3DA4589AA3623675A31CAE69B23B1C4E325EACC5271322539EEEC2D6A5292471EF1B3E72AE7180533EFC3B8F0AD435C8D9ABF622DA26EEEE48203085D4BC23B0F77DC6F8724AC33D975DA3CA492D2E441D5D574C1D5D57560A00";
+    const LEDGER_HASH: &str = "F4D865D83EB88C1A1911B9E90641919A1314F36E1B099F8E95FE3B7C77BE3349";
+
+    fn ledger_header() -> LedgerHeader {
+        LedgerHeader {
+            ledger_index: 15202439,
+            total_coins: 99998831688050493,
+            parent_hash: Hash256::from_hex(
+                "12724A65B030C15A1573AA28B1BBB5DF3DA4589AA3623675A31CAE69B23B1C4E",
+            )
+            .unwrap(),
+            transaction_hash: Hash256::from_hex(
+                "325EACC5271322539EEEC2D6A5292471EF1B3E72AE7180533EFC3B8F0AD435C8",
+            )
+            .unwrap(),
+            account_hash: Hash256::from_hex(
+                "D9ABF622DA26EEEE48203085D4BC23B0F77DC6F8724AC33D975DA3CA492D2E44",
+            )
+            .unwrap(),
+            parent_close_time: 492656460,
+            close_time: 492656470,
+            close_time_resolution: 10,
+            close_flags: 0,
+        }
+    }
+
+    #[test]
+    fn test_decode() {
+        let bytes = hex::decode(LEDGER_DATA).unwrap();
+        assert_eq!(decode(&bytes).unwrap(), ledger_header());
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(hex::encode_upper(encode(&ledger_header())), LEDGER_DATA);
+    }
+
+    #[test]
+    fn test_ledger_hash() {
+        assert_eq!(hash::ledger_hash(&ledger_header()).to_hex(), LEDGER_HASH);
+    }
+
+    #[test]
+    fn test_decode_invalid_length() {
+        let bytes = hex::decode(LEDGER_DATA).unwrap();
+        let result = decode(&bytes[..bytes.len() - 1]);
+        assert_matches!(result, Err(BinaryCodecError::InvalidLength(message)) => {
+            assert!(message.contains("117"), "message: {}", message);
+        });
+    }
+}
@@ -4,14 +4,32 @@
 extern crate alloc;
 extern crate core;
 
+/// Definitions of fields and types, by default built-in or loaded from `definitions.json`
+pub mod definitions;
+pub mod deserialize;
 pub mod deserializer;
 mod error;
+mod field;
 pub mod hash;
+/// Serialization to and from the JSON representation <https://xrpl.org/serialization.html#json-format>,
+/// and conversion between JSON and the canonical binary format
+pub mod json;
+/// Implements ledger object index computation <https://xrpl.org/ledger-object-ids.html>
+pub mod keylet;
+/// Implements key derivation according to <https://xrpl.org/cryptographic-keys.html#key-derivation>
+pub mod keypair;
+/// Binary codec for ledger headers <https://xrpl.org/ledger-header.html>
+pub mod ledger_header;
+/// Validator manifests <https://xrpl.org/manifest.html>
+pub mod manifest;
+/// Verification of Merkle proofs that transactions and ledger objects are included in a ledger
+pub mod proof;
 pub mod serialize;
 /// Implements serialization according to <https://xrpl.org/serialization.html>
 pub mod serializer;
+pub mod shamap;
 pub mod sign;
-mod field;
-pub mod deserialize;
+/// Verification of published validator lists, which define the trusted validators (UNL)
+pub mod validator_list;
 
 pub use error::*;
@@ -0,0 +1,270 @@
+use crate::alloc::format;
+use crate::alloc::string::{String, ToString};
+use crate::alloc::vec::Vec;
+use crate::keypair::{PublicKey, SecretKey};
+use crate::{deserialize, hash, serialize, sign, BinaryCodecError};
+use base64::Engine;
+use xrpl_types::{Blob, Manifest};
+
+/// Decodes manifest from the canonical binary format
+pub fn decode(bytes: &[u8]) -> Result<Manifest, BinaryCodecError> {
+    deserialize::deserialize_canonical(bytes)
+}
+
+/// Decodes manifest from base64, as returned in the `manifest` field of the `manifest` method
+/// <https://xrpl.org/manifest.html>
+pub fn decode_base64(manifest: &str) -> Result<Manifest, BinaryCodecError> {
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(manifest)
+        .map_err(|err| BinaryCodecError::InvalidField(format!("invalid base64: {}", err)))?;
+    decode(&bytes)
+}
+
+/// Encodes manifest to the canonical binary format
+pub fn encode(manifest: &Manifest) -> Result<Vec<u8>, BinaryCodecError> {
+    serialize::serialize(manifest)
+}
+
+/// Encodes manifest to base64, see [`decode_base64`]
+pub fn encode_base64(manifest: &Manifest) -> Result<String, BinaryCodecError> {
+    Ok(base64::engine::general_purpose::STANDARD.encode(encode(manifest)?))
+}
+
+/// Signs the manifest with the master key and, unless it is a revocation, with the ephemeral
+/// key given by `SigningPubKey`
+pub fn sign_manifest(
+    manifest: &mut Manifest,
+    master_secret_key: &SecretKey,
+    signing_secret_key: Option<&SecretKey>,
+) -> Result<(), BinaryCodecError> {
+    check_key(&manifest.public_key, master_secret_key, "master")?;
+    let data = signing_data(manifest)?;
+    manifest.master_signature =
+        sign::signature(hash::HASH_PREFIX_MANIFEST, &data, master_secret_key);
+    manifest.signature = match (&manifest.signing_pub_key, signing_secret_key) {
+        (Some(signing_pub_key), Some(signing_secret_key)) => {
+            check_key(signing_pub_key, signing_secret_key, "signing")?;
+            Some(sign::signature(
+                hash::HASH_PREFIX_MANIFEST,
+                &data,
+                signing_secret_key,
+            ))
+        }
+        (None, None) => None,
+        _ => {
+            return Err(BinaryCodecError::InvalidKey(
+                "signing secret key must be given exactly if manifest has SigningPubKey"
+                    .to_string(),
+            ))
+        }
+    };
+    Ok(())
+}
+
+/// Verifies the master signature and, unless the manifest is a revocation, the signature by
+/// the ephemeral key <https://xrpl.org/cryptographic-keys.html#signing-algorithms>.
+/// Both secp256k1 and ed25519 keys are supported.
+pub fn verify_manifest(manifest: &Manifest) -> Result<(), BinaryCodecError> {
+    let data = signing_data(manifest)?;
+    let master_key = PublicKey::from_bytes(&manifest.public_key.0)?;
+    sign::verify_signature(
+        hash::HASH_PREFIX_MANIFEST,
+        &data,
+        &manifest.master_signature.0,
+        &master_key,
+    )
+    .map_err(|err| BinaryCodecError::InvalidSignature(format!("master signature: {}", err)))?;
+
+    if manifest.is_revocation() {
+        return Ok(());
+    }
+    let (Some(signing_pub_key), Some(signature)) = (&manifest.signing_pub_key, &manifest.signature)
+    else {
+        return Err(BinaryCodecError::InvalidSignature(
+            "manifest has no signing public key or signature".to_string(),
+        ));
+    };
+    if signing_pub_key == &manifest.public_key {
+        return Err(BinaryCodecError::InvalidKey(
+            "signing public key is the master key".to_string(),
+        ));
+    }
+    let signing_key = PublicKey::from_bytes(&signing_pub_key.0)?;
+    sign::verify_signature(
+        hash::HASH_PREFIX_MANIFEST,
+        &data,
+        &signature.0,
+        &signing_key,
+    )
+    .map_err(|err| BinaryCodecError::InvalidSignature(format!("signature: {}", err)))
+}
+
+/// The signed data is the manifest serialized without the signatures
+fn signing_data(manifest: &Manifest) -> Result<Vec<u8>, BinaryCodecError> {
+    struct Unsigned<'a>(&'a Manifest);
+
+    impl xrpl_types::serialize::Serialize for Unsigned<'_> {
+        fn serialize<S: xrpl_types::serialize::Serializer>(
+            &self,
+            s: &mut S,
+        ) -> Result<(), S::Error> {
+            let manifest = self.0;
+            s.serialize_blob("PublicKey", &manifest.public_key)?;
+            s.serialize_uint32("Sequence", manifest.sequence)?;
+            if let Some(signing_pub_key) = &manifest.signing_pub_key {
+                s.serialize_blob("SigningPubKey", signing_pub_key)?;
+            }
+            if let Some(domain) = &manifest.domain {
+                s.serialize_blob("Domain", domain)?;
+            }
+            if let Some(version) = manifest.version {
+                s.serialize_uint16("Version", version)?;
+            }
+            Ok(())
+        }
+    }
+
+    serialize::serialize(&Unsigned(manifest))
+}
+
+fn check_key(
+    public_key: &Blob,
+    secret_key: &SecretKey,
+    name: &str,
+) -> Result<(), BinaryCodecError> {
+    if public_key.0 != secret_key.public_key().to_bytes() {
+        return Err(BinaryCodecError::InvalidKey(format!(
+            "{} secret key does not match public key in manifest",
+            name
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keypair::Keypair;
+    use assert_matches::assert_matches;
+    use xrpl_address_codec::encode_node_public_key;
+
+    /// Manifest with ed25519 master key and secp256k1 ephemeral key, signatures checked with
+    /// an independent implementation
+    const MANIFEST: &str = "JAAAAARxIe0B+lP6Wn53eY+ILs4gsavAC7NYqeVaIC0NBna9DON6Y3MhAw1Y60i0Qgsfe531UIfg4p/vDoRo+aaCWwHKLDYQQtQ1dkYwRAIgEWD/9JF2tdkNe+R6XTRQMKnzei7lHvxGuQcdAR4HQ24CIEX/XMBQ3DFzZYsIRksZOvCn/TfcgecTib+FCZkjI6rHdwtleGFtcGxlLmNvbXASQEZndV/4s7CUTSpxxUxO13szjNZAefL2Z2q+nQt5DR/XQPs/GTL8tfOsWyVWOKDhdOk/5f8ZY9aiN0T02mYu4gM=";
+
+    fn node_public_key(blob: &Blob) -> String {
+        encode_node_public_key(blob.0.as_slice().try_into().unwrap())
+    }
+
+    #[test]
+    fn test_decode_base64() {
+        let manifest = decode_base64(MANIFEST).unwrap();
+        assert_eq!(manifest.sequence, 4);
+        assert_eq!(
+            node_public_key(&manifest.public_key),
+            "nHB4JiHScG1RWKhfzXTFahpv1gcTn3BkZLRqhVGj4rNRoN9MQ43P"
+        );
+        assert_eq!(
+            node_public_key(manifest.signing_pub_key.as_ref().unwrap()),
+            "n9LbfzJKqB6Xc6n76ZkgrCTkuEL7uZqmNmgWThPjKNJD5K9cHqNw"
+        );
+        assert_eq!(manifest.domain, Some(Blob(b"example.com".to_vec())));
+        assert!(!manifest.is_revocation());
+        assert_eq!(encode_base64(&manifest).unwrap(), MANIFEST);
+
+        verify_manifest(&manifest).unwrap();
+    }
+
+    #[test]
+    fn test_decode_base64_invalid() {
+        let result = decode_base64("not base64!");
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(_)));
+    }
+
+    #[test]
+    fn test_verify_manifest_tampered() {
+        let mut manifest = decode_base64(MANIFEST).unwrap();
+        manifest.sequence = 5;
+        let result = verify_manifest(&manifest);
+        assert_matches!(result, Err(BinaryCodecError::InvalidSignature(message)) => {
+            assert!(message.contains("master signature"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_sign_verify_manifest() {
+        for (master_seed, signing_seed) in [
+            (
+                "sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r",
+                "sp5fghtJtpUorTwvof1NpDXAzNwf5",
+            ),
+            (
+                "sp5fghtJtpUorTwvof1NpDXAzNwf5",
+                "sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r",
+            ),
+        ] {
+            let master = Keypair::from_seed(master_seed).unwrap();
+            let signing = Keypair::from_seed(signing_seed).unwrap();
+            let mut manifest = Manifest {
+                public_key: master.public_key.to_blob(),
+                sequence: 3,
+                signing_pub_key: Some(signing.public_key.to_blob()),
+                signature: None,
+                master_signature: Blob(Vec::new()),
+                domain: Some(Blob(b"example.com".to_vec())),
+                version: None,
+            };
+            sign_manifest(&mut manifest, &master.secret_key, Some(&signing.secret_key)).unwrap();
+            verify_manifest(&manifest).unwrap();
+
+            let decoded = decode_base64(&encode_base64(&manifest).unwrap()).unwrap();
+            assert_eq!(decoded, manifest);
+
+            // Signature by the ephemeral key is verified as well
+            let mut tampered = manifest.clone();
+            tampered.signature = tampered.signature.map(|mut signature| {
+                let len = signature.0.len();
+                signature.0[len - 2] ^= 1;
+                signature
+            });
+            let result = verify_manifest(&tampered);
+            assert_matches!(result, Err(BinaryCodecError::InvalidSignature(message)) => {
+                assert!(message.starts_with("signature"), "message: {}", message);
+            });
+        }
+    }
+
+    #[test]
+    fn test_verify_revocation() {
+        let master = Keypair::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r").unwrap();
+        let mut manifest = Manifest {
+            public_key: master.public_key.to_blob(),
+            sequence: Manifest::REVOCATION_SEQUENCE,
+            signing_pub_key: None,
+            signature: None,
+            master_signature: Blob(Vec::new()),
+            domain: None,
+            version: None,
+        };
+        sign_manifest(&mut manifest, &master.secret_key, None).unwrap();
+        assert!(manifest.is_revocation());
+        verify_manifest(&manifest).unwrap();
+    }
+
+    #[test]
+    fn test_sign_manifest_wrong_key() {
+        let master = Keypair::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r").unwrap();
+        let other = Keypair::from_seed("sp5fghtJtpUorTwvof1NpDXAzNwf5").unwrap();
+        let mut manifest = Manifest {
+            public_key: master.public_key.to_blob(),
+            sequence: 1,
+            signing_pub_key: None,
+            signature: None,
+            master_signature: Blob(Vec::new()),
+            domain: None,
+            version: None,
+        };
+        let result = sign_manifest(&mut manifest, &other.secret_key, None);
+        assert_matches!(result, Err(BinaryCodecError::InvalidKey(_)));
+    }
+}
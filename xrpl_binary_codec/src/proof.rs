@@ -0,0 +1,339 @@
+use crate::alloc::format;
+#[cfg(feature = "api")]
+use crate::alloc::string::ToString;
+use crate::hash;
+use crate::shamap::{self, ShaMapProof};
+use crate::BinaryCodecError;
+use xrpl_types::{Hash256, LedgerHeader};
+
+/// Verifies that the ledger header hashes to the given ledger hash, e.g. the hash of a
+/// validated ledger. A header verified like this can be trusted to verify proofs against.
+pub fn verify_ledger_header(
+    header: &LedgerHeader,
+    ledger_hash: Hash256,
+) -> Result<(), BinaryCodecError> {
+    let hash = hash::ledger_hash(header);
+    if hash != ledger_hash {
+        return Err(BinaryCodecError::InvalidProof(format!(
+            "Ledger header hashes to {}, expected {}",
+            hash.to_hex(),
+            ledger_hash.to_hex()
+        )));
+    }
+    Ok(())
+}
+
+/// Verifies that the transaction with metadata is included in the transaction tree of the
+/// ledger with the given header. The blobs are `tx_blob` and `meta` as returned by rippled
+/// for `"binary": true` requests. Returns the transaction id.
+pub fn verify_transaction(
+    header: &LedgerHeader,
+    tx_blob: &[u8],
+    meta_blob: &[u8],
+    proof: &ShaMapProof,
+) -> Result<Hash256, BinaryCodecError> {
+    let transaction_id = hash::transaction_id_from_blob(tx_blob);
+    let leaf_hash = shamap::transaction_leaf_hash(transaction_id, tx_blob, meta_blob)?;
+    proof.verify(transaction_id, leaf_hash, header.transaction_hash)?;
+    Ok(transaction_id)
+}
+
+/// Verifies that the ledger object is included in the state tree of the ledger with the
+/// given header. The index and data are `index` and `node_binary` as returned by `ledger_entry`
+/// for `"binary": true` requests.
+pub fn verify_ledger_object(
+    header: &LedgerHeader,
+    index: Hash256,
+    data: &[u8],
+    proof: &ShaMapProof,
+) -> Result<(), BinaryCodecError> {
+    let leaf_hash = shamap::ledger_object_leaf_hash(index, data);
+    proof.verify(index, leaf_hash, header.account_hash)
+}
+
+/// Verifies that the transaction of a `tx` response for a `"binary": true` request is included
+/// in the transaction tree of the ledger with the given header. Returns the transaction id.
+/// No RPC method returns the proof, see [`ShaMapProof::from_wire_nodes`].
+#[cfg(feature = "api")]
+pub fn verify_tx_response(
+    header: &LedgerHeader,
+    binary: &xrpl_api::TxBinaryResponse,
+    proof: &ShaMapProof,
+) -> Result<Hash256, BinaryCodecError> {
+    check_ledger_index(header, binary.ledger_index)?;
+    let tx_blob = decode_hex("tx", &binary.tx_blob)?;
+    let meta_blob = binary
+        .meta_blob
+        .as_deref()
+        .ok_or_else(|| BinaryCodecError::MissingField("meta".to_string()))?;
+    let meta_blob = decode_hex("meta", meta_blob)?;
+    let transaction_id = verify_transaction(header, &tx_blob, &meta_blob, proof)?;
+    if !binary.hash.eq_ignore_ascii_case(&transaction_id.to_hex()) {
+        return Err(BinaryCodecError::InvalidProof(format!(
+            "Transaction hashes to {}, response has hash {}",
+            transaction_id.to_hex(),
+            binary.hash
+        )));
+    }
+    Ok(transaction_id)
+}
+
+/// Verifies that the ledger object of a `ledger_entry` response for a `"binary": true` request
+/// is included in the state tree of the ledger with the given header. No RPC method returns the
+/// proof, see [`ShaMapProof::from_wire_nodes`].
+#[cfg(feature = "api")]
+pub fn verify_ledger_entry_response(
+    header: &LedgerHeader,
+    response: &xrpl_api::LedgerEntryResponse,
+    proof: &ShaMapProof,
+) -> Result<(), BinaryCodecError> {
+    check_ledger_index(header, response.ledger_spec.ledger_index)?;
+    if let Some(ledger_hash) = response.ledger_spec.ledger_hash.as_deref() {
+        let hash = hash::ledger_hash(header);
+        if !ledger_hash.eq_ignore_ascii_case(&hash.to_hex()) {
+            return Err(BinaryCodecError::InvalidProof(format!(
+                "Response is for ledger {}, header hashes to {}",
+                ledger_hash,
+                hash.to_hex()
+            )));
+        }
+    }
+    let index = Hash256::from_hex(&response.index)
+        .map_err(|err| BinaryCodecError::InvalidField(format!("index: {}", err)))?;
+    let data = response.node_binary.as_deref().ok_or_else(|| {
+        BinaryCodecError::MissingField("ledger_entry response has no node_binary".to_string())
+    })?;
+    verify_ledger_object(header, index, &decode_hex("node_binary", data)?, proof)
+}
+
+/// Checks that the ledger index of a response, if present, is the index of the header
+#[cfg(feature = "api")]
+fn check_ledger_index(
+    header: &LedgerHeader,
+    ledger_index: Option<u32>,
+) -> Result<(), BinaryCodecError> {
+    match ledger_index {
+        Some(ledger_index) if ledger_index != header.ledger_index => {
+            Err(BinaryCodecError::InvalidProof(format!(
+                "Response is for ledger index {}, header has ledger index {}",
+                ledger_index, header.ledger_index
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(feature = "api")]
+fn decode_hex(field_name: &str, hex: &str) -> Result<alloc::vec::Vec<u8>, BinaryCodecError> {
+    hex::decode(hex)
+        .map_err(|err| BinaryCodecError::InvalidField(format!("{}: {}", field_name, err)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shamap::ShaMap;
+    use assert_matches::assert_matches;
+
+    /// Example from <https://xrpl.org/submit.html>
+    const TX_BLOB: &str = "1200002280000000240000016861D4838D7EA4C6800000000000000000000000000055534400000000004B4E9C06F24296074F7BC48F92A97916C6DC5EA9684000000000002710732103AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB7446304402200E5C2DD81FDF0BE9AB2A8D797885ED49E804DBF28E806604D878756410CA98B102203349581946B0DDA06B36B35DBC20EDA27552C1F167BCF5C6ECFF49C6A46F858081144B4E9C06F24296074F7BC48F92A97916C6DC5EA983143E9D4A2B8AA0780F682D136F7A56D6724EF53754";
+    const META_BLOB: &str = "201C00000003F8E5110061E72200000000E1E1F1031000";
+
+    fn ledger_header(transaction_hash: Hash256, account_hash: Hash256) -> LedgerHeader {
+        LedgerHeader {
+            ledger_index: 1000,
+            total_coins: 99998831688050493,
+            parent_hash: Hash256([1; 32]),
+            transaction_hash,
+            account_hash,
+            parent_close_time: 492656460,
+            close_time: 492656470,
+            close_time_resolution: 10,
+            close_flags: 0,
+        }
+    }
+
+    #[test]
+    fn test_verify_transaction() {
+        let tx_blob = hex::decode(TX_BLOB).unwrap();
+        let meta_blob = hex::decode(META_BLOB).unwrap();
+        let mut tree = ShaMap::new();
+        let transaction_id = tree.add_transaction(&tx_blob, &meta_blob).unwrap();
+        tree.add_transaction(&[0x12, 0x00, 0x00], &meta_blob)
+            .unwrap();
+        let header = ledger_header(tree.root_hash(), Hash256([0; 32]));
+        let proof = tree.proof(transaction_id).unwrap();
+
+        assert_eq!(
+            verify_transaction(&header, &tx_blob, &meta_blob, &proof).unwrap(),
+            transaction_id
+        );
+
+        let result = verify_transaction(&header, &tx_blob, &meta_blob[1..], &proof);
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(_)));
+    }
+
+    #[test]
+    fn test_verify_ledger_object() {
+        let index = Hash256([0xAA; 32]);
+        let mut tree = ShaMap::new();
+        tree.add_ledger_object(index, &[1, 2, 3]);
+        tree.add_ledger_object(Hash256([0xAB; 32]), &[4, 5, 6]);
+        let header = ledger_header(Hash256([0; 32]), tree.root_hash());
+        let proof = tree.proof(index).unwrap();
+
+        verify_ledger_object(&header, index, &[1, 2, 3], &proof).unwrap();
+
+        let result = verify_ledger_object(&header, index, &[1, 2, 4], &proof);
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(_)));
+
+        // Proof is for the state tree, not the transaction tree
+        let result = proof.verify(
+            index,
+            shamap::ledger_object_leaf_hash(index, &[1, 2, 3]),
+            header.transaction_hash,
+        );
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(_)));
+    }
+
+    #[test]
+    fn test_verify_ledger_header() {
+        let header = ledger_header(Hash256([2; 32]), Hash256([3; 32]));
+        verify_ledger_header(&header, hash::ledger_hash(&header)).unwrap();
+
+        let result = verify_ledger_header(&header, Hash256([4; 32]));
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(message)) => {
+            assert!(message.contains("Ledger header hashes to"), "message: {}", message);
+        });
+    }
+
+    /// Proof path in the wire format of the peer protocol, with the given leaf in wire format
+    #[cfg(feature = "api")]
+    fn wire_nodes(proof: &ShaMapProof, leaf: Vec<u8>) -> Vec<Vec<u8>> {
+        let mut nodes = vec![leaf];
+        for children in proof.path.iter().rev() {
+            let mut node: Vec<u8> = children.iter().flat_map(|child| child.0).collect();
+            node.push(shamap::WIRE_TYPE_INNER);
+            nodes.push(node);
+        }
+        nodes
+    }
+
+    #[cfg(feature = "api")]
+    #[test]
+    fn test_verify_tx_response() {
+        let tx_blob = hex::decode(TX_BLOB).unwrap();
+        let meta_blob = hex::decode(META_BLOB).unwrap();
+        let mut tree = ShaMap::new();
+        let transaction_id = tree.add_transaction(&tx_blob, &meta_blob).unwrap();
+        tree.add_transaction(&[0x12, 0x00, 0x00], &meta_blob)
+            .unwrap();
+        let header = ledger_header(tree.root_hash(), Hash256([0; 32]));
+
+        let mut leaf = Vec::new();
+        crate::serializer::put_vl_prefix(&mut leaf, tx_blob.len()).unwrap();
+        leaf.extend_from_slice(&tx_blob);
+        crate::serializer::put_vl_prefix(&mut leaf, meta_blob.len()).unwrap();
+        leaf.extend_from_slice(&meta_blob);
+        leaf.extend_from_slice(&transaction_id.0);
+        leaf.push(shamap::WIRE_TYPE_TRANSACTION_WITH_META);
+        let nodes = wire_nodes(&tree.proof(transaction_id).unwrap(), leaf);
+        let proof = ShaMapProof::from_wire_nodes(transaction_id, &nodes).unwrap();
+
+        let response: xrpl_api::TxBinaryResponse = serde_json::from_value(serde_json::json!({
+            "hash": transaction_id.to_hex(),
+            "ledger_index": 1000,
+            "meta": META_BLOB,
+            "tx": TX_BLOB,
+            "validated": true
+        }))
+        .unwrap();
+        assert_eq!(
+            verify_tx_response(&header, &response, &proof).unwrap(),
+            transaction_id
+        );
+
+        let response: xrpl_api::TxBinaryResponse = serde_json::from_value(serde_json::json!({
+            "hash": transaction_id.to_hex(),
+            "ledger_index": 1001,
+            "meta": META_BLOB,
+            "tx": TX_BLOB
+        }))
+        .unwrap();
+        let result = verify_tx_response(&header, &response, &proof);
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(message)) => {
+            assert!(message.contains("ledger index 1001"), "message: {}", message);
+        });
+
+        let response: xrpl_api::TxBinaryResponse = serde_json::from_value(serde_json::json!({
+            "hash": transaction_id.to_hex(),
+            "meta": "201C00000004F8E5110061E72200000000E1E1F1031000",
+            "tx": TX_BLOB
+        }))
+        .unwrap();
+        let result = verify_tx_response(&header, &response, &proof);
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(_)));
+
+        let response: xrpl_api::TxBinaryResponse = serde_json::from_value(serde_json::json!({
+            "hash": Hash256([5; 32]).to_hex(),
+            "meta": META_BLOB,
+            "tx": TX_BLOB
+        }))
+        .unwrap();
+        let result = verify_tx_response(&header, &response, &proof);
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(message)) => {
+            assert!(message.contains("response has hash"), "message: {}", message);
+        });
+    }
+
+    #[cfg(feature = "api")]
+    #[test]
+    fn test_verify_ledger_entry_response() {
+        let index = Hash256([0xAA; 32]);
+        let mut tree = ShaMap::new();
+        tree.add_ledger_object(index, &[1, 2, 3]);
+        tree.add_ledger_object(Hash256([0xAB; 32]), &[4, 5, 6]);
+        let header = ledger_header(Hash256([0; 32]), tree.root_hash());
+
+        let mut leaf = vec![1, 2, 3];
+        leaf.extend_from_slice(&index.0);
+        leaf.push(shamap::WIRE_TYPE_ACCOUNT_STATE);
+        let nodes = wire_nodes(&tree.proof(index).unwrap(), leaf);
+        let proof = ShaMapProof::from_wire_nodes(index, &nodes).unwrap();
+
+        let response: xrpl_api::LedgerEntryResponse = serde_json::from_value(serde_json::json!({
+            "index": index.to_hex(),
+            "ledger_hash": hash::ledger_hash(&header).to_hex(),
+            "ledger_index": 1000,
+            "node_binary": "010203",
+            "validated": true
+        }))
+        .unwrap();
+        verify_ledger_entry_response(&header, &response, &proof).unwrap();
+
+        let response: xrpl_api::LedgerEntryResponse = serde_json::from_value(serde_json::json!({
+            "index": index.to_hex(),
+            "node_binary": "010204"
+        }))
+        .unwrap();
+        let result = verify_ledger_entry_response(&header, &response, &proof);
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(_)));
+
+        let response: xrpl_api::LedgerEntryResponse = serde_json::from_value(serde_json::json!({
+            "index": index.to_hex(),
+            "ledger_hash": Hash256([6; 32]).to_hex(),
+            "node_binary": "010203"
+        }))
+        .unwrap();
+        let result = verify_ledger_entry_response(&header, &response, &proof);
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(message)) => {
+            assert!(message.contains("header hashes to"), "message: {}", message);
+        });
+
+        // Response for a request without "binary": true
+        let response: xrpl_api::LedgerEntryResponse =
+            serde_json::from_value(serde_json::json!({ "index": index.to_hex() })).unwrap();
+        let result = verify_ledger_entry_response(&header, &response, &proof);
+        assert_matches!(result, Err(BinaryCodecError::MissingField(_)));
+    }
+}
@@ -1,7 +1,9 @@
 use crate::alloc::vec::Vec;
-use crate::serializer::Serializer;
+use crate::definitions::Definitions;
+use crate::serializer::{canonical, Serializer};
 use crate::BinaryCodecError;
-use xrpl_types::serialize::Serialize;
+use bytes::BufMut;
+use xrpl_types::serialize::{CanonicalSerialize, Serialize};
 
 /// Serializes the given object in the canonical binary format <https://xrpl.org/serialization.html>
 pub fn serialize(object: &impl Serialize) -> Result<Vec<u8>, BinaryCodecError> {
@@ -9,3 +11,29 @@ pub fn serialize(object: &impl Serialize) -> Result<Vec<u8>, BinaryCodecError> {
     object.serialize(&mut s)?;
     s.into_bytes()
 }
+
+/// Serializes the given object in the canonical binary format <https://xrpl.org/serialization.html>,
+/// looking up fields in the given definitions
+pub fn serialize_with_definitions(
+    object: &impl Serialize,
+    definitions: &Definitions,
+) -> Result<Vec<u8>, BinaryCodecError> {
+    let mut s = Serializer::with_definitions(definitions);
+    object.serialize(&mut s)?;
+    s.into_bytes()
+}
+
+/// Serializes the given object in the canonical binary format <https://xrpl.org/serialization.html>
+/// directly into the given buffer. The fields are written in a single pass, without sorting and
+/// copying them, which requires the object to serialize its fields in canonical field order.
+/// Fields out of order fail with [`BinaryCodecError::FieldOrder`], in which case a part of the
+/// object may already have been written to the buffer
+pub fn serialize_into(
+    object: &impl CanonicalSerialize,
+    buffer: impl BufMut,
+) -> Result<(), BinaryCodecError> {
+    let mut s = canonical::Serializer::new(buffer);
+    object.serialize(&mut s)?;
+    s.finish()?;
+    Ok(())
+}
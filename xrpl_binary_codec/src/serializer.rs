@@ -1,17 +1,36 @@
+use crate::definitions::Definitions;
 use crate::error::BinaryCodecError;
-use crate::field::{field_info, FieldCode, FieldId, TypeCode};
+use crate::field::{
+    FieldCode, FieldId, TypeCode, PATH_SEPARATOR_BYTE, PATH_SET_END_BYTE, PATH_STEP_ACCOUNT,
+    PATH_STEP_CURRENCY, PATH_STEP_ISSUER,
+};
 use alloc::{format, string::ToString, vec::Vec};
-use core::fmt::Display;
 use bytes::BufMut;
-use xrpl_types::{serialize, serialize::{Serialize}, AccountId, Amount, Blob, CurrencyCode, DropsAmount, Hash128, Hash160, Hash256, IssuedValue, UInt16, UInt32, UInt64, UInt8};
+use core::fmt::Display;
 use xrpl_types::serialize::SerError;
+use xrpl_types::{
+    serialize, serialize::Serialize, AccountId, Amount, Blob, Currency, CurrencyCode, DropsAmount,
+    Hash128, Hash160, Hash256, IssuedValue, PathSet, PathStep, UInt16, UInt32, UInt64, UInt8,
+    XChainBridge,
+};
+
+/// Single-pass serialization of objects that serialize their fields in canonical order
+pub mod canonical;
 
-#[derive(Debug, Default)]
-pub struct Serializer {
+#[derive(Debug)]
+pub struct Serializer<'d> {
     /// Buffer in which fields are initially serialized. Fields are not sorted in this buffer
     buffer: Vec<u8>,
     /// Tracks which fields have been serialized to the buffer
     serialized_fields: Vec<SerializedFieldIndex>,
+    /// Definitions used to look up fields
+    definitions: &'d Definitions,
+}
+
+impl Default for Serializer<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl SerError for BinaryCodecError {
@@ -20,9 +39,12 @@ impl SerError for BinaryCodecError {
     }
 }
 
-impl xrpl_types::serialize::Serializer for Serializer {
+impl<'d> xrpl_types::serialize::Serializer for Serializer<'d> {
     type Error = BinaryCodecError;
-    type ArraySerializer<'a> = ArraySerializer<'a>;
+    type ArraySerializer<'a>
+        = ArraySerializer<'a, 'd>
+    where
+        Self: 'a;
 
     fn serialize_account_id(
         &mut self,
@@ -30,7 +52,7 @@ impl xrpl_types::serialize::Serializer for Serializer {
         account_id: AccountId,
     ) -> Result<(), BinaryCodecError> {
         self.serialize_field(field_name, TypeCode::AccountId, |ser| {
-            ser.push_account_id(account_id)?;
+            ser.buffer.push_account_id(account_id)?;
             Ok(())
         })
     }
@@ -41,14 +63,14 @@ impl xrpl_types::serialize::Serializer for Serializer {
         amount: Amount,
     ) -> Result<(), BinaryCodecError> {
         self.serialize_field(field_name, TypeCode::Amount, |ser| {
-            ser.push_amount(amount)?;
+            ser.buffer.push_amount(amount)?;
             Ok(())
         })
     }
 
     fn serialize_blob(&mut self, field_name: &str, blob: &Blob) -> Result<(), BinaryCodecError> {
         self.serialize_field(field_name, TypeCode::Blob, |ser| {
-            ser.push_blob(blob)?;
+            ser.buffer.push_blob(blob)?;
             Ok(())
         })
     }
@@ -59,7 +81,7 @@ impl xrpl_types::serialize::Serializer for Serializer {
         hash128: Hash128,
     ) -> Result<(), BinaryCodecError> {
         self.serialize_field(field_name, TypeCode::Hash128, |ser| {
-            ser.push_hash128(hash128)?;
+            ser.buffer.push_hash128(hash128)?;
             Ok(())
         })
     }
@@ -70,7 +92,7 @@ impl xrpl_types::serialize::Serializer for Serializer {
         hash160: Hash160,
     ) -> Result<(), BinaryCodecError> {
         self.serialize_field(field_name, TypeCode::Hash160, |ser| {
-            ser.push_hash160(hash160)?;
+            ser.buffer.push_hash160(hash160)?;
             Ok(())
         })
     }
@@ -81,14 +103,14 @@ impl xrpl_types::serialize::Serializer for Serializer {
         hash256: Hash256,
     ) -> Result<(), BinaryCodecError> {
         self.serialize_field(field_name, TypeCode::Hash256, |ser| {
-            ser.push_hash256(hash256)?;
+            ser.buffer.push_hash256(hash256)?;
             Ok(())
         })
     }
 
     fn serialize_uint8(&mut self, field_name: &str, uint8: UInt8) -> Result<(), BinaryCodecError> {
         self.serialize_field(field_name, TypeCode::UInt8, |ser| {
-            ser.push_uint8(uint8)?;
+            ser.buffer.push_uint8(uint8)?;
             Ok(())
         })
     }
@@ -99,7 +121,7 @@ impl xrpl_types::serialize::Serializer for Serializer {
         uint16: UInt16,
     ) -> Result<(), BinaryCodecError> {
         self.serialize_field(field_name, TypeCode::UInt16, |ser| {
-            ser.push_uint16(uint16)?;
+            ser.buffer.push_uint16(uint16)?;
             Ok(())
         })
     }
@@ -110,7 +132,7 @@ impl xrpl_types::serialize::Serializer for Serializer {
         uint32: UInt32,
     ) -> Result<(), BinaryCodecError> {
         self.serialize_field(field_name, TypeCode::UInt32, |ser| {
-            ser.push_uint32(uint32)?;
+            ser.buffer.push_uint32(uint32)?;
             Ok(())
         })
     }
@@ -121,7 +143,51 @@ impl xrpl_types::serialize::Serializer for Serializer {
         uint64: UInt64,
     ) -> Result<(), BinaryCodecError> {
         self.serialize_field(field_name, TypeCode::UInt64, |ser| {
-            ser.push_uint64(uint64)?;
+            ser.buffer.push_uint64(uint64)?;
+            Ok(())
+        })
+    }
+
+    fn serialize_path_set(
+        &mut self,
+        field_name: &str,
+        path_set: &PathSet,
+    ) -> Result<(), BinaryCodecError> {
+        self.serialize_field(field_name, TypeCode::PathSet, |ser| {
+            ser.buffer.push_path_set(path_set)?;
+            Ok(())
+        })
+    }
+
+    fn serialize_vector256(
+        &mut self,
+        field_name: &str,
+        vector256: &[Hash256],
+    ) -> Result<(), BinaryCodecError> {
+        self.serialize_field(field_name, TypeCode::Vector256, |ser| {
+            ser.buffer.push_vector256(vector256)?;
+            Ok(())
+        })
+    }
+
+    fn serialize_issue(
+        &mut self,
+        field_name: &str,
+        issue: Currency,
+    ) -> Result<(), BinaryCodecError> {
+        self.serialize_field(field_name, TypeCode::Issue, |ser| {
+            ser.buffer.push_issue(issue)?;
+            Ok(())
+        })
+    }
+
+    fn serialize_xchain_bridge(
+        &mut self,
+        field_name: &str,
+        xchain_bridge: &XChainBridge,
+    ) -> Result<(), BinaryCodecError> {
+        self.serialize_field(field_name, TypeCode::XChainBridge, |ser| {
+            ser.buffer.push_xchain_bridge(xchain_bridge)?;
             Ok(())
         })
     }
@@ -136,15 +202,26 @@ impl xrpl_types::serialize::Serializer for Serializer {
             start_index,
         })
     }
+
+    fn serialize_object<T: Serialize>(
+        &mut self,
+        field_name: &str,
+        object: &T,
+    ) -> Result<(), BinaryCodecError> {
+        self.serialize_field(field_name, TypeCode::Object, |ser| {
+            ser.push_object(object)?;
+            Ok(())
+        })
+    }
 }
 
 #[derive(Debug)]
-pub struct ArraySerializer<'a> {
-    serializer: &'a mut Serializer,
+pub struct ArraySerializer<'a, 'd> {
+    serializer: &'a mut Serializer<'d>,
     start_index: SerializeFieldStartIndex,
 }
 
-impl<'a> serialize::ArraySerializer for ArraySerializer<'a> {
+impl serialize::ArraySerializer for ArraySerializer<'_, '_> {
     type Error = BinaryCodecError;
 
     fn serialize_object<T: Serialize>(
@@ -152,18 +229,15 @@ impl<'a> serialize::ArraySerializer for ArraySerializer<'a> {
         field_name: &str,
         object: &T,
     ) -> Result<(), Self::Error> {
-        let field_id = get_field_id(field_name, TypeCode::Object)?;
-        self.serializer.push_field_id(field_id)?;
-        let mut object_serializer = Serializer::new();
-        object.serialize(&mut object_serializer)?;
-        object_serializer.into_buffer(&mut self.serializer.buffer)?;
-        self.serializer
-            .push_field_id(FieldId::from_type_field(TypeCode::Object, FieldCode(1)))?;
+        let field_id = field_id(self.serializer.definitions, field_name, TypeCode::Object)?;
+        self.serializer.buffer.push_field_id(field_id)?;
+        self.serializer.push_object(object)?;
         Ok(())
     }
 
     fn end(self) -> Result<(), Self::Error> {
         self.serializer
+            .buffer
             .push_field_id(FieldId::from_type_field(TypeCode::Array, FieldCode(1)))?;
         self.serializer.end_field(self.start_index);
         Ok(())
@@ -204,11 +278,17 @@ impl SerializeFieldStartIndex {
     }
 }
 
-impl Serializer {
+impl<'d> Serializer<'d> {
     pub fn new() -> Self {
+        Self::with_definitions(Definitions::builtin())
+    }
+
+    /// Creates serializer that looks up fields in the given definitions
+    pub fn with_definitions(definitions: &'d Definitions) -> Self {
         Self {
             buffer: Vec::new(),
             serialized_fields: Vec::new(),
+            definitions,
         }
     }
 
@@ -239,9 +319,9 @@ impl Serializer {
         field_name: &str,
         field_type: TypeCode,
     ) -> Result<SerializeFieldStartIndex, BinaryCodecError> {
-        let field_id = get_field_id(field_name, field_type)?;
+        let field_id = field_id(self.definitions, field_name, field_type)?;
         let start_index = SerializeFieldStartIndex::new(field_id, self.buffer.len());
-        self.push_field_id(field_id)?;
+        self.buffer.push_field_id(field_id)?;
         Ok(start_index)
     }
 
@@ -254,7 +334,7 @@ impl Serializer {
         &mut self,
         field_name: &str,
         field_type: TypeCode,
-        serialize_field_data_closure: impl FnOnce(&mut Serializer) -> Result<(), BinaryCodecError>,
+        serialize_field_data_closure: impl FnOnce(&mut Self) -> Result<(), BinaryCodecError>,
     ) -> Result<(), BinaryCodecError> {
         let start_index = self.start_field(field_name, field_type)?;
         serialize_field_data_closure(self)?;
@@ -263,17 +343,64 @@ impl Serializer {
         Ok(())
     }
 
-    fn push(&mut self, value: u8) -> Result<(), BinaryCodecError> {
-        self.push_slice(&[value])
+    /// Pushes object fields in canonical order followed by the object end marker
+    /// <https://xrpl.org/serialization.html#object-fields>
+    fn push_object<T: Serialize>(&mut self, object: &T) -> Result<(), BinaryCodecError> {
+        let mut object_serializer = Serializer::with_definitions(self.definitions);
+        object.serialize(&mut object_serializer)?;
+        object_serializer.into_buffer(&mut self.buffer)?;
+        self.buffer
+            .push_field_id(FieldId::from_type_field(TypeCode::Object, FieldCode(1)))
+    }
+}
+
+/// Looks up the id of the field with the given name and checks the field type
+fn field_id(
+    definitions: &Definitions,
+    field_name: &str,
+    field_type: TypeCode,
+) -> Result<FieldId, BinaryCodecError> {
+    let field_id = definitions.field_id(field_name).ok_or_else(|| {
+        BinaryCodecError::InvalidField(format!("Field with name {} is not known", field_name))
+    })?;
+    if field_type != field_id.type_code {
+        return Err(BinaryCodecError::InvalidField(format!(
+            "Field with name {} must have type {}",
+            field_name, field_id.type_code
+        )));
+    }
+    Ok(field_id)
+}
+
+/// Pushes values in the binary format <https://xrpl.org/serialization.html> to a buffer
+trait PushBinary: BufMut {
+    /// Checks that the buffer has room for the given number of bytes, since writing beyond the
+    /// capacity of a fixed size buffer panics
+    fn check_capacity(&self, length: usize) -> Result<(), BinaryCodecError> {
+        if self.remaining_mut() < length {
+            return Err(BinaryCodecError::OutOfRange(format!(
+                "Buffer has room for {} bytes but {} bytes must be written",
+                self.remaining_mut(),
+                length
+            )));
+        }
+        Ok(())
+    }
+
+    fn push_byte(&mut self, value: u8) -> Result<(), BinaryCodecError> {
+        self.check_capacity(1)?;
+        self.put_u8(value);
+        Ok(())
     }
 
     fn push_slice(&mut self, bytes: &[u8]) -> Result<(), BinaryCodecError> {
-        self.buffer.extend_from_slice(bytes);
+        self.check_capacity(bytes.len())?;
+        self.put_slice(bytes);
         Ok(())
     }
 
     fn push_uint8(&mut self, value: UInt8) -> Result<(), BinaryCodecError> {
-        self.push(value)
+        self.push_byte(value)
     }
 
     fn push_uint16(&mut self, value: UInt16) -> Result<(), BinaryCodecError> {
@@ -312,43 +439,24 @@ impl Serializer {
         let type_code = field_id.type_code as u8;
         let field_code = field_id.field_code.0;
         if type_code < 16 && field_code < 16 {
-            self.push(type_code << 4 | field_code)?;
+            self.push_byte(type_code << 4 | field_code)?;
         } else if type_code < 16 {
-            self.push(type_code << 4)?;
-            self.push(field_code)?;
+            self.push_byte(type_code << 4)?;
+            self.push_byte(field_code)?;
         } else if field_code < 16 {
-            self.push(field_code)?;
-            self.push(type_code)?;
+            self.push_byte(field_code)?;
+            self.push_byte(type_code)?;
         } else {
-            self.push(0)?;
-            self.push(type_code)?;
-            self.push(field_code)?;
+            self.push_byte(0)?;
+            self.push_byte(type_code)?;
+            self.push_byte(field_code)?;
         }
         Ok(())
     }
 
     /// Push length prefix according to <https://xrpl.org/serialization.html#length-prefixing>
     fn push_vl_prefix(&mut self, length: usize) -> Result<(), BinaryCodecError> {
-        if length <= 192 {
-            self.push(length as u8)?;
-            Ok(())
-        } else if length <= 12480 {
-            let length = length - 193;
-            self.push(193 + (length >> 8) as u8)?;
-            self.push((length & 0xff) as u8)?;
-            Ok(())
-        } else if length <= 918744 {
-            let length = length - 12481;
-            self.push(241 + (length >> 16) as u8)?;
-            self.push(((length >> 8) & 0xff) as u8)?;
-            self.push((length & 0xff) as u8)?;
-            Ok(())
-        } else {
-            Err(BinaryCodecError::OutOfRange(format!(
-                "Variable length out of range: {}",
-                length
-            )))
-        }
+        put_vl_prefix(self, length)
     }
 
     /// <https://xrpl.org/serialization.html#amount-fields>
@@ -411,19 +519,106 @@ impl Serializer {
     fn push_account_id_no_length_prefix(&mut self, id: AccountId) -> Result<(), BinaryCodecError> {
         self.push_slice(&id.0)
     }
+
+    /// <https://xrpl.org/serialization.html#pathset-fields>
+    fn push_path_set(&mut self, path_set: &PathSet) -> Result<(), BinaryCodecError> {
+        for (i, path) in path_set.0.iter().enumerate() {
+            if i > 0 {
+                self.push_byte(PATH_SEPARATOR_BYTE)?;
+            }
+            for step in &path.0 {
+                self.push_path_step(step)?;
+            }
+        }
+        self.push_byte(PATH_SET_END_BYTE)
+    }
+
+    /// <https://xrpl.org/serialization.html#vector256-fields>
+    fn push_vector256(&mut self, vector256: &[Hash256]) -> Result<(), BinaryCodecError> {
+        self.push_vl_prefix(vector256.len() * 32)?;
+        for hash in vector256 {
+            self.push_hash256(*hash)?;
+        }
+        Ok(())
+    }
+
+    /// <https://xrpl.org/serialization.html#issue-fields>
+    fn push_issue(&mut self, issue: Currency) -> Result<(), BinaryCodecError> {
+        match issue {
+            Currency::Xrp => self.push_currency_code(CurrencyCode::Xrp),
+            Currency::Issued(issued) => {
+                self.push_currency_code(issued.currency_code())?;
+                self.push_account_id_no_length_prefix(issued.issuer())
+            }
+        }
+    }
+
+    /// <https://xrpl.org/serialization.html#xchainbridge-fields>
+    fn push_xchain_bridge(&mut self, xchain_bridge: &XChainBridge) -> Result<(), BinaryCodecError> {
+        self.push_account_id(xchain_bridge.locking_chain_door)?;
+        self.push_issue(xchain_bridge.locking_chain_issue)?;
+        self.push_account_id(xchain_bridge.issuing_chain_door)?;
+        self.push_issue(xchain_bridge.issuing_chain_issue)
+    }
+
+    /// <https://xrpl.org/serialization.html#pathset-fields>
+    fn push_path_step(&mut self, step: &PathStep) -> Result<(), BinaryCodecError> {
+        let mut step_type = 0;
+        if step.account.is_some() {
+            step_type |= PATH_STEP_ACCOUNT;
+        }
+        if step.currency.is_some() {
+            step_type |= PATH_STEP_CURRENCY;
+        }
+        if step.issuer.is_some() {
+            step_type |= PATH_STEP_ISSUER;
+        }
+        if step_type == 0 {
+            return Err(BinaryCodecError::InvalidField(
+                "Path step must have account, currency or issuer".to_string(),
+            ));
+        }
+        self.push_byte(step_type)?;
+        if let Some(account) = step.account {
+            self.push_account_id_no_length_prefix(account)?;
+        }
+        if let Some(currency) = step.currency {
+            self.push_currency_code(currency)?;
+        }
+        if let Some(issuer) = step.issuer {
+            self.push_account_id_no_length_prefix(issuer)?;
+        }
+        Ok(())
+    }
 }
 
-pub fn get_field_id(field_name: &str, field_type: TypeCode) -> Result<FieldId, BinaryCodecError> {
-    let field_id = *field_info::field_id_by_name(field_name).ok_or_else(|| {
-        BinaryCodecError::InvalidField(format!("Field with name {} is not known", field_name))
-    })?;
-    if field_type != field_id.type_code {
-        return Err(BinaryCodecError::InvalidField(format!(
-            "Field with name {} must have type {}",
-            field_name, field_id.type_code
-        )));
+impl<B: BufMut + ?Sized> PushBinary for B {}
+
+/// Put length prefix according to <https://xrpl.org/serialization.html#length-prefixing>
+pub(crate) fn put_vl_prefix<B: BufMut + ?Sized>(
+    bytes: &mut B,
+    length: usize,
+) -> Result<(), BinaryCodecError> {
+    if length <= 192 {
+        bytes.push_byte(length as u8)?;
+        Ok(())
+    } else if length <= 12480 {
+        let length = length - 193;
+        bytes.push_byte(193 + (length >> 8) as u8)?;
+        bytes.push_byte((length & 0xff) as u8)?;
+        Ok(())
+    } else if length <= 918744 {
+        let length = length - 12481;
+        bytes.push_byte(241 + (length >> 16) as u8)?;
+        bytes.push_byte(((length >> 8) & 0xff) as u8)?;
+        bytes.push_byte((length & 0xff) as u8)?;
+        Ok(())
+    } else {
+        Err(BinaryCodecError::OutOfRange(format!(
+            "Variable length out of range: {}",
+            length
+        )))
     }
-    Ok(field_id)
 }
 
 #[cfg(test)]
@@ -433,15 +628,14 @@ mod tests {
     use alloc::vec;
     use ascii::AsciiChar;
     use assert_matches::assert_matches;
-    use enumflags2::BitFlags;
     use xrpl_types::serialize::{ArraySerializer, Serialize, Serializer};
-    use xrpl_types::OfferCreateTransaction;
+    use xrpl_types::{Flags, OfferCreateTransaction, Path};
 
-    fn serializer() -> super::Serializer {
+    fn serializer() -> super::Serializer<'static> {
         super::Serializer::new()
     }
 
-    fn buffer(serializer: &super::Serializer) -> &[u8] {
+    fn buffer<'a>(serializer: &'a super::Serializer<'_>) -> &'a [u8] {
         &serializer.buffer
     }
 
@@ -475,7 +669,7 @@ mod tests {
     fn test_push_uint8() {
         let mut s = serializer();
         let value = 0x12;
-        s.push_uint8(value).unwrap();
+        s.buffer.push_uint8(value).unwrap();
         assert_eq!(buffer(&s), [0x12u8]);
     }
 
@@ -483,7 +677,7 @@ mod tests {
     fn test_push_uint16() {
         let mut s = serializer();
         let value = 0x12 + (0x34 << 8);
-        s.push_uint16(value).unwrap();
+        s.buffer.push_uint16(value).unwrap();
         assert_eq!(buffer(&s), [0x34, 0x12]);
     }
 
@@ -491,7 +685,7 @@ mod tests {
     fn test_push_uint32() {
         let mut s = serializer();
         let value = 0x12 + (0x34 << 24);
-        s.push_uint32(value).unwrap();
+        s.buffer.push_uint32(value).unwrap();
         assert_eq!(buffer(&s), [0x34, 0x00, 0x00, 0x12]);
     }
 
@@ -499,7 +693,7 @@ mod tests {
     fn test_push_uint64() {
         let mut s = serializer();
         let value = 0x12 + (0x34 << 56);
-        s.push_uint64(value).unwrap();
+        s.buffer.push_uint64(value).unwrap();
         assert_eq!(buffer(&s), [0x34, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x12]);
     }
 
@@ -510,7 +704,7 @@ mod tests {
             0x34, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
             0x00, 0x12,
         ]);
-        s.push_hash128(value).unwrap();
+        s.buffer.push_hash128(value).unwrap();
         assert_eq!(
             buffer(&s),
             [
@@ -527,7 +721,7 @@ mod tests {
             0x34, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
             0x00, 0x00, 0x00, 0x00, 0x00, 0x12,
         ]);
-        s.push_hash160(value).unwrap();
+        s.buffer.push_hash160(value).unwrap();
         assert_eq!(
             buffer(&s),
             [
@@ -545,7 +739,7 @@ mod tests {
             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
             0x00, 0x00, 0x00, 0x12,
         ]);
-        s.push_hash256(value).unwrap();
+        s.buffer.push_hash256(value).unwrap();
         assert_eq!(
             buffer(&s),
             [
@@ -560,7 +754,7 @@ mod tests {
     fn test_push_blob() {
         let mut s = serializer();
         let value = Blob(vec![0x34, 0x00, 0x12]);
-        s.push_blob(&value).unwrap();
+        s.buffer.push_blob(&value).unwrap();
         assert_eq!(buffer(&s), [3, 0x34, 0x00, 0x12]);
     }
 
@@ -571,7 +765,7 @@ mod tests {
             0x34, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
             0x00, 0x00, 0x00, 0x00, 0x00, 0x12,
         ]);
-        s.push_account_id(value).unwrap();
+        s.buffer.push_account_id(value).unwrap();
         assert_eq!(
             buffer(&s),
             [
@@ -588,7 +782,7 @@ mod tests {
             0x34, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
             0x00, 0x00, 0x00, 0x00, 0x00, 0x12,
         ]);
-        s.push_account_id_no_length_prefix(value).unwrap();
+        s.buffer.push_account_id_no_length_prefix(value).unwrap();
         assert_eq!(
             buffer(&s),
             [
@@ -604,31 +798,42 @@ mod tests {
     fn test_push_vl_prefix() {
         // test range 0 to 192
         let mut s = serializer();
-        s.push_vl_prefix(0).unwrap();
-        s.push_vl_prefix(1).unwrap();
-        s.push_vl_prefix(192).unwrap();
+        s.buffer.push_vl_prefix(0).unwrap();
+        s.buffer.push_vl_prefix(1).unwrap();
+        s.buffer.push_vl_prefix(192).unwrap();
         assert_eq!(buffer(&s), [0, 1, 192]);
 
         // test range 193 to 12480
         let mut s = serializer();
-        s.push_vl_prefix(193 + ((193 - 193) * 256) + 0).unwrap();
-        s.push_vl_prefix(193 + ((193 - 193) * 256) + 1).unwrap();
+        s.buffer
+            .push_vl_prefix(193 + ((193 - 193) * 256) + 0)
+            .unwrap();
+        s.buffer
+            .push_vl_prefix(193 + ((193 - 193) * 256) + 1)
+            .unwrap();
         assert_eq!(193 + ((240 - 193) * 256) + 255, 12480);
-        s.push_vl_prefix(193 + ((240 - 193) * 256) + 255).unwrap();
+        s.buffer
+            .push_vl_prefix(193 + ((240 - 193) * 256) + 255)
+            .unwrap();
         assert_eq!(buffer(&s), [193, 0, 193, 1, 240, 255]);
 
         // test range 12481 to 918744
         let mut s = serializer();
-        s.push_vl_prefix(12481 + ((241 - 241) * 65536) + (0 * 256) + 0)
+        s.buffer
+            .push_vl_prefix(12481 + ((241 - 241) * 65536) + (0 * 256) + 0)
             .unwrap();
-        s.push_vl_prefix(12481 + ((241 - 241) * 65536) + (0 * 256) + 1)
+        s.buffer
+            .push_vl_prefix(12481 + ((241 - 241) * 65536) + (0 * 256) + 1)
             .unwrap();
-        s.push_vl_prefix(12481 + ((241 - 241) * 65536) + (1 * 256) + 0)
+        s.buffer
+            .push_vl_prefix(12481 + ((241 - 241) * 65536) + (1 * 256) + 0)
             .unwrap();
-        s.push_vl_prefix(12481 + ((241 - 241) * 65536) + (255 * 256) + 255)
+        s.buffer
+            .push_vl_prefix(12481 + ((241 - 241) * 65536) + (255 * 256) + 255)
             .unwrap();
         assert_eq!(12481 + ((254 - 241) * 65536) + (212 * 256) + 23, 918744);
-        s.push_vl_prefix(12481 + ((254 - 241) * 65536) + (212 * 256) + 23)
+        s.buffer
+            .push_vl_prefix(12481 + ((254 - 241) * 65536) + (212 * 256) + 23)
             .unwrap();
         assert_eq!(
             buffer(&s),
@@ -637,7 +842,7 @@ mod tests {
 
         // test out of range
         let mut s = serializer();
-        let result = s.push_vl_prefix(918745);
+        let result = s.buffer.push_vl_prefix(918745);
         assert_matches!(result, Err(BinaryCodecError::OutOfRange(message)) => {
             assert!(message.contains("Variable length out of range"), "message: {}", message);
         });
@@ -647,7 +852,7 @@ mod tests {
     fn test_push_currency_code_xrp() {
         let mut s = serializer();
         let code = CurrencyCode::xrp();
-        s.push_currency_code(code).unwrap();
+        s.buffer.push_currency_code(code).unwrap();
         assert_eq!(buffer(&s), [0u8; 20]);
     }
 
@@ -655,7 +860,7 @@ mod tests {
     fn test_push_currency_code_standard() {
         let mut s = serializer();
         let code = CurrencyCode::standard([AsciiChar::U, AsciiChar::S, AsciiChar::D]).unwrap();
-        s.push_currency_code(code).unwrap();
+        s.buffer.push_currency_code(code).unwrap();
         let bytes = buffer(&s);
         assert_eq!(bytes[0..12], [0u8; 12]);
         assert_eq!(
@@ -677,7 +882,7 @@ mod tests {
             0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
         ])
         .unwrap();
-        s.push_currency_code(code).unwrap();
+        s.buffer.push_currency_code(code).unwrap();
         assert_eq!(
             buffer(&s),
             [
@@ -691,7 +896,7 @@ mod tests {
     fn test_push_drops_amount() {
         let mut s = serializer();
         let value = DropsAmount::from_drops(10_000).unwrap();
-        s.push_drops_amount(value).unwrap();
+        s.buffer.push_drops_amount(value).unwrap();
         assert_eq!(buffer(&s), [0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x27, 0x10]);
     }
 
@@ -700,7 +905,7 @@ mod tests {
     fn test_push_issued_value_zero() {
         let mut s = serializer();
         let value = IssuedValue::zero();
-        s.push_issued_value(value).unwrap();
+        s.buffer.push_issued_value(value).unwrap();
         assert_eq!(buffer(&s), [0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
     }
 
@@ -709,7 +914,7 @@ mod tests {
     fn test_push_issued_value_positive() {
         let mut s = serializer();
         let value = IssuedValue::from_mantissa_exponent(1_000_000_000_000_000, -10).unwrap();
-        s.push_issued_value(value).unwrap();
+        s.buffer.push_issued_value(value).unwrap();
         let bytes = buffer(&s);
         assert_eq!(
             bytes,
@@ -724,7 +929,7 @@ mod tests {
     fn test_push_issued_value_negative() {
         let mut s = serializer();
         let value = IssuedValue::from_mantissa_exponent(-1_000_000_000_000_000, -10).unwrap();
-        s.push_issued_value(value).unwrap();
+        s.buffer.push_issued_value(value).unwrap();
         let bytes = buffer(&s);
         assert_eq!(
             bytes,
@@ -738,7 +943,7 @@ mod tests {
     fn test_push_amount_drops() {
         let mut s = serializer();
         let value = Amount::drops(10_000).unwrap();
-        s.push_amount(value).unwrap();
+        s.buffer.push_amount(value).unwrap();
         assert_eq!(buffer(&s), [0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x27, 0x10]);
     }
 
@@ -756,7 +961,7 @@ mod tests {
             0x00, 0x00, 0x00, 0x00, 0x00, 0x12,
         ]);
         let amount = Amount::issued(value, currency, issuer).unwrap();
-        s.push_amount(amount).unwrap();
+        s.buffer.push_amount(amount).unwrap();
         let bytes = buffer(&s);
         assert_eq!(
             bytes,
@@ -771,11 +976,107 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_push_path_set() {
+        let mut s = serializer();
+        let account = AccountId([0x11; 20]);
+        let issuer = AccountId([0x22; 20]);
+        let usd = CurrencyCode::standard([AsciiChar::U, AsciiChar::S, AsciiChar::D]).unwrap();
+        let path_set = PathSet(vec![
+            Path(vec![PathStep::account(account)]),
+            Path(vec![
+                PathStep::currency(usd, Some(issuer)),
+                PathStep::currency(CurrencyCode::xrp(), None),
+            ]),
+        ]);
+        s.buffer.push_path_set(&path_set).unwrap();
+        let mut expected = vec![0x01];
+        expected.extend_from_slice(&[0x11; 20]);
+        expected.push(0xFF);
+        expected.push(0x30);
+        expected.extend_from_slice(&[0; 12]);
+        expected.extend_from_slice(b"USD");
+        expected.extend_from_slice(&[0; 5]);
+        expected.extend_from_slice(&[0x22; 20]);
+        expected.push(0x10);
+        expected.extend_from_slice(&[0; 20]);
+        expected.push(0x00);
+        assert_eq!(buffer(&s), expected.as_slice());
+    }
+
+    #[test]
+    fn test_push_path_step_empty() {
+        let mut s = serializer();
+        let result = s
+            .buffer
+            .push_path_set(&PathSet(vec![Path(vec![PathStep::default()])]));
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("Path step must have"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_push_vector256() {
+        let mut s = serializer();
+        s.buffer
+            .push_vector256(&[Hash256([0x11; 32]), Hash256([0x22; 32])])
+            .unwrap();
+        let mut expected = vec![64];
+        expected.extend_from_slice(&[0x11; 32]);
+        expected.extend_from_slice(&[0x22; 32]);
+        assert_eq!(buffer(&s), expected.as_slice());
+    }
+
+    #[test]
+    fn test_push_issue_xrp() {
+        let mut s = serializer();
+        s.buffer.push_issue(Currency::xrp()).unwrap();
+        assert_eq!(buffer(&s), [0; 20]);
+    }
+
+    #[test]
+    fn test_push_issue_issued() {
+        let mut s = serializer();
+        let usd = CurrencyCode::standard([AsciiChar::U, AsciiChar::S, AsciiChar::D]).unwrap();
+        s.buffer
+            .push_issue(Currency::issued(usd, AccountId([0x22; 20])).unwrap())
+            .unwrap();
+        let mut expected = vec![0; 12];
+        expected.extend_from_slice(b"USD");
+        expected.extend_from_slice(&[0; 5]);
+        expected.extend_from_slice(&[0x22; 20]);
+        assert_eq!(buffer(&s), expected.as_slice());
+    }
+
+    #[test]
+    fn test_serialize_xchain_bridge() {
+        let mut s = serializer();
+        let usd = CurrencyCode::standard([AsciiChar::U, AsciiChar::S, AsciiChar::D]).unwrap();
+        let xchain_bridge = XChainBridge {
+            locking_chain_door: AccountId([0x11; 20]),
+            locking_chain_issue: Currency::xrp(),
+            issuing_chain_door: AccountId([0x22; 20]),
+            issuing_chain_issue: Currency::issued(usd, AccountId([0x33; 20])).unwrap(),
+        };
+        s.serialize_xchain_bridge("XChainBridge", &xchain_bridge)
+            .unwrap();
+        let mut expected = vec![0x01, 0x19, 0x14];
+        expected.extend_from_slice(&[0x11; 20]);
+        expected.extend_from_slice(&[0; 20]);
+        expected.push(0x14);
+        expected.extend_from_slice(&[0x22; 20]);
+        expected.extend_from_slice(&[0; 12]);
+        expected.extend_from_slice(b"USD");
+        expected.extend_from_slice(&[0; 5]);
+        expected.extend_from_slice(&[0x33; 20]);
+        assert_eq!(buffer(&s), expected.as_slice());
+    }
+
     #[test]
     fn test_push_field_id_4bit_type_4bit_field() {
         let mut s = serializer();
         let field_id = FieldId::from_type_field(TypeCode::UInt32, FieldCode(0b0100));
-        s.push_field_id(field_id).unwrap();
+        s.buffer.push_field_id(field_id).unwrap();
         assert_eq!(buffer(&s), [0b0010_0100]);
     }
 
@@ -783,7 +1084,7 @@ mod tests {
     fn test_push_field_id_4bit_type_8bit_field() {
         let mut s = serializer();
         let field_id = FieldId::from_type_field(TypeCode::UInt32, FieldCode(0b0001_0100));
-        s.push_field_id(field_id).unwrap();
+        s.buffer.push_field_id(field_id).unwrap();
         assert_eq!(buffer(&s), [0b0010_0000, 0b0001_0100]);
     }
 
@@ -791,7 +1092,7 @@ mod tests {
     fn test_push_field_id_8bit_type_8bit_field() {
         let mut s = serializer();
         let field_id = FieldId::from_type_field(TypeCode::Hash160, FieldCode(0b0001_0100));
-        s.push_field_id(field_id).unwrap();
+        s.buffer.push_field_id(field_id).unwrap();
         assert_eq!(buffer(&s), [0, 0b0001_0001, 0b0001_0100]);
     }
 
@@ -799,7 +1100,7 @@ mod tests {
     fn test_push_field_id_8bit_type_4bit_field() {
         let mut s = serializer();
         let field_id = FieldId::from_type_field(TypeCode::Hash160, FieldCode(0b0100));
-        s.push_field_id(field_id).unwrap();
+        s.buffer.push_field_id(field_id).unwrap();
         assert_eq!(buffer(&s), [0b0000_0100, 0b0001_0001]);
     }
 
@@ -861,6 +1162,39 @@ mod tests {
         );
     }
 
+    /// Test serializing inner object as a field
+    #[test]
+    fn test_serialize_object() {
+        let mut s = serializer();
+        let object = TestObjectSerializeFieldsOutOfOrder {
+            field1: 12,
+            field2: 23,
+        };
+        s.serialize_uint8("TickSize", 12).unwrap(); // type code 16, field code 16
+        s.serialize_object("Memo", &object).unwrap(); // type code 14, field code 10
+        assert_eq!(
+            s.into_bytes().unwrap(),
+            [
+                0b1110_1010,
+                0b0010_0001,
+                0,
+                0,
+                0,
+                12,
+                0b0010_0010,
+                0,
+                0,
+                0,
+                23,
+                0b1110_0001,
+                0b0000_0000,
+                0b0001_0000,
+                0b0001_0000,
+                12,
+            ]
+        );
+    }
+
     /// Test pushing array of objects with out of order fields
     #[test]
     fn test_push_array_out_of_order_fields() {
@@ -1014,7 +1348,7 @@ mod tests {
         ));
         txn.common.txn_signature = Some(Blob(hex::decode("30440220143759437C04F7B61F012563AFE90D8DAFC46E86035E1D965A9CED282C97D4CE02204CFD241E86F17E011298FC1A39B63386C74306A5DE047E213B0F29EFA4571C2C").unwrap()));
         txn.expiration = Some(595640108);
-        txn.flags = BitFlags::from_bits(524288).unwrap();
+        txn.flags = Some(Flags::from_bits(524288));
         txn.offer_sequence = Some(1752791);
 
         let bytes = serialize::serialize(&txn).unwrap();
@@ -0,0 +1,582 @@
+use crate::alloc::collections::BTreeMap;
+use crate::alloc::format;
+use crate::alloc::string::ToString;
+use crate::alloc::vec::Vec;
+use crate::hash::{
+    self, HASH_PREFIX_INNER_NODE, HASH_PREFIX_LEAF_NODE, HASH_PREFIX_TRANSACTION_NODE,
+};
+use crate::serializer::put_vl_prefix;
+use crate::BinaryCodecError;
+use xrpl_types::Hash256;
+
+/// Number of children of an inner node, one for each value of a key nibble
+pub const BRANCH_FACTOR: usize = 16;
+
+/// Wire types of nodes, given by the last byte of a node in the wire format used by rippled
+pub(crate) const WIRE_TYPE_TRANSACTION: u8 = 0;
+pub(crate) const WIRE_TYPE_ACCOUNT_STATE: u8 = 1;
+pub(crate) const WIRE_TYPE_INNER: u8 = 2;
+pub(crate) const WIRE_TYPE_COMPRESSED_INNER: u8 = 3;
+pub(crate) const WIRE_TYPE_TRANSACTION_WITH_META: u8 = 4;
+
+/// Hash tree used for the transaction tree and the state tree of a ledger
+/// <https://xrpl.org/ledgers.html#tree-format>. Leaves are keyed by their 256-bit key,
+/// the transaction id or the ledger object index, and each inner node branches on
+/// the next 4 bits of the key. The root hash is independent of the order leaves are added in,
+/// and can be compared to `transaction_hash` and `account_hash` of the ledger header.
+#[derive(Debug, Clone, Default)]
+pub struct ShaMap {
+    /// Leaf hashes by key
+    leaves: BTreeMap<[u8; 32], Hash256>,
+}
+
+impl ShaMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds transaction with metadata, as in the transaction tree of a ledger. The blobs
+    /// are `tx_blob` and `meta` as returned by rippled for `"binary": true` requests.
+    /// Returns the transaction id, which is the key of the leaf.
+    pub fn add_transaction(
+        &mut self,
+        tx_blob: &[u8],
+        meta_blob: &[u8],
+    ) -> Result<Hash256, BinaryCodecError> {
+        let transaction_id = hash::transaction_id_from_blob(tx_blob);
+        let leaf_hash = transaction_leaf_hash(transaction_id, tx_blob, meta_blob)?;
+        self.add_leaf(transaction_id, leaf_hash);
+        Ok(transaction_id)
+    }
+
+    /// Adds ledger object, as in the state tree of a ledger. The blob is the object serialized
+    /// in the canonical binary format, e.g. `data` returned by `ledger_data` for `"binary": true`.
+    pub fn add_ledger_object(&mut self, index: Hash256, data: &[u8]) {
+        self.add_leaf(index, ledger_object_leaf_hash(index, data));
+    }
+
+    /// Adds leaf with the given key and leaf hash. A leaf already present with the same key
+    /// is replaced.
+    pub fn add_leaf(&mut self, key: Hash256, leaf_hash: Hash256) {
+        self.leaves.insert(key.0, leaf_hash);
+    }
+
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Hash of the root node. The root of an empty tree has the zero hash.
+    ///
+    /// Inner node hashes are not cached: each call copies the leaves and hashes all inner nodes,
+    /// which is linear in the number of leaves.
+    pub fn root_hash(&self) -> Hash256 {
+        if self.leaves.is_empty() {
+            return Hash256([0; 32]);
+        }
+        inner_node_hash(&children_hashes(&self.sorted_leaves(), 0))
+    }
+
+    /// Proof that the leaf with the given key is included in the tree. Returns `None` if
+    /// there is no leaf with the key.
+    ///
+    /// Like [`Self::root_hash`], each call hashes all inner nodes, so proofs for all leaves of a
+    /// tree take time quadratic in the number of leaves.
+    pub fn proof(&self, key: Hash256) -> Option<ShaMapProof> {
+        let leaves = self.sorted_leaves();
+        let mut remaining = leaves.as_slice();
+        let mut path = Vec::new();
+        for depth in 0..64 {
+            path.push(children_hashes(remaining, depth));
+            let branch_index = branch(&key.0, depth);
+            let start = remaining.partition_point(|(key, _)| branch(key, depth) < branch_index);
+            let end = remaining.partition_point(|(key, _)| branch(key, depth) <= branch_index);
+            remaining = &remaining[start..end];
+            match remaining {
+                [] => return None,
+                [(leaf_key, _)] => {
+                    return (*leaf_key == key.0).then_some(ShaMapProof { path });
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    fn sorted_leaves(&self) -> Vec<([u8; 32], Hash256)> {
+        self.leaves
+            .iter()
+            .map(|(key, hash)| (*key, *hash))
+            .collect()
+    }
+}
+
+/// Merkle proof that a leaf is included in a [`ShaMap`] with a given root hash
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ShaMapProof {
+    /// Hashes of the children of each inner node on the path from the root to the leaf,
+    /// starting with the root
+    pub path: Vec<[Hash256; BRANCH_FACTOR]>,
+}
+
+impl ShaMapProof {
+    /// Decodes a proof path in the wire format of the rippled peer protocol: the `path` of a
+    /// `TMProofPathResponse` message, holding the nodes on the path starting with the leaf and
+    /// ending with the root. No RPC method returns proof paths, they are requested from a rippled
+    /// peer with a `TMProofPathRequest` message. The leaf must have the given key. Only the hashes
+    /// of the inner nodes are kept, the leaf is verified by the leaf hash given to
+    /// [`ShaMapProof::verify`]
+    pub fn from_wire_nodes<T: AsRef<[u8]>>(
+        key: Hash256,
+        nodes: &[T],
+    ) -> Result<Self, BinaryCodecError> {
+        let Some((leaf, inner_nodes)) = nodes.split_first() else {
+            return Err(BinaryCodecError::InvalidProof(
+                "Proof path has no nodes".to_string(),
+            ));
+        };
+        let leaf_key = wire_leaf_key(leaf.as_ref())?;
+        if leaf_key != key {
+            return Err(BinaryCodecError::InvalidProof(format!(
+                "Leaf has key {}, expected {}",
+                leaf_key.to_hex(),
+                key.to_hex()
+            )));
+        }
+        let path = inner_nodes
+            .iter()
+            .rev()
+            .map(|node| wire_inner_node_children(node.as_ref()))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { path })
+    }
+
+    /// Verifies that the leaf with the given key and leaf hash is included in the tree with
+    /// the given root hash
+    pub fn verify(
+        &self,
+        key: Hash256,
+        leaf_hash: Hash256,
+        root_hash: Hash256,
+    ) -> Result<(), BinaryCodecError> {
+        if self.path.is_empty() || self.path.len() > 64 {
+            return Err(BinaryCodecError::InvalidProof(format!(
+                "Path length must be between 1 and 64, found {}",
+                self.path.len()
+            )));
+        }
+        let mut hash = leaf_hash;
+        for (depth, children) in self.path.iter().enumerate().rev() {
+            if children[branch(&key.0, depth)] != hash {
+                return Err(BinaryCodecError::InvalidProof(format!(
+                    "Inner node at depth {} does not contain hash {}",
+                    depth,
+                    hash.to_hex()
+                )));
+            }
+            hash = inner_node_hash(children);
+        }
+        if hash != root_hash {
+            return Err(BinaryCodecError::InvalidProof(format!(
+                "Root hash {} does not match expected {}",
+                hash.to_hex(),
+                root_hash.to_hex()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Key of a leaf node in wire format
+fn wire_leaf_key(node: &[u8]) -> Result<Hash256, BinaryCodecError> {
+    let Some((&wire_type, data)) = node.split_last() else {
+        return Err(BinaryCodecError::InsufficientBytes(
+            "Empty node".to_string(),
+        ));
+    };
+    match wire_type {
+        WIRE_TYPE_TRANSACTION => Ok(hash::transaction_id_from_blob(data)),
+        WIRE_TYPE_ACCOUNT_STATE | WIRE_TYPE_TRANSACTION_WITH_META => {
+            let key = data
+                .len()
+                .checked_sub(32)
+                .map(|start| &data[start..])
+                .ok_or_else(|| {
+                    BinaryCodecError::InsufficientBytes(
+                        "Leaf node shorter than its key".to_string(),
+                    )
+                })?;
+            Ok(Hash256(key.try_into().expect("key has 32 bytes")))
+        }
+        WIRE_TYPE_INNER | WIRE_TYPE_COMPRESSED_INNER => Err(BinaryCodecError::InvalidProof(
+            "First node of the proof path is not a leaf".to_string(),
+        )),
+        _ => Err(BinaryCodecError::InvalidProof(format!(
+            "Unknown node wire type {}",
+            wire_type
+        ))),
+    }
+}
+
+/// Hashes of the children of an inner node in wire format. A full inner node lists the hashes
+/// of all children, a compressed inner node lists the non-empty children each followed by its
+/// branch.
+fn wire_inner_node_children(node: &[u8]) -> Result<[Hash256; BRANCH_FACTOR], BinaryCodecError> {
+    let Some((&wire_type, data)) = node.split_last() else {
+        return Err(BinaryCodecError::InsufficientBytes(
+            "Empty node".to_string(),
+        ));
+    };
+    let mut children = [Hash256([0; 32]); BRANCH_FACTOR];
+    match wire_type {
+        WIRE_TYPE_INNER => {
+            if data.len() != BRANCH_FACTOR * 32 {
+                return Err(BinaryCodecError::InvalidLength(format!(
+                    "Inner node has {} bytes, expected {}",
+                    data.len(),
+                    BRANCH_FACTOR * 32
+                )));
+            }
+            for (child, hash) in children.iter_mut().zip(data.chunks_exact(32)) {
+                child.0.copy_from_slice(hash);
+            }
+        }
+        WIRE_TYPE_COMPRESSED_INNER => {
+            if data.len() % 33 != 0 {
+                return Err(BinaryCodecError::InvalidLength(format!(
+                    "Compressed inner node has {} bytes, expected a multiple of 33",
+                    data.len()
+                )));
+            }
+            for entry in data.chunks_exact(33) {
+                let branch_index = entry[32] as usize;
+                if branch_index >= BRANCH_FACTOR {
+                    return Err(BinaryCodecError::OutOfRange(format!(
+                        "Branch {} of compressed inner node",
+                        branch_index
+                    )));
+                }
+                children[branch_index].0.copy_from_slice(&entry[..32]);
+            }
+        }
+        _ => {
+            return Err(BinaryCodecError::InvalidProof(format!(
+                "Expected inner node, found node wire type {}",
+                wire_type
+            )))
+        }
+    }
+    Ok(children)
+}
+
+/// Hash of a transaction tree leaf, which holds the transaction and its metadata
+pub fn transaction_leaf_hash(
+    transaction_id: Hash256,
+    tx_blob: &[u8],
+    meta_blob: &[u8],
+) -> Result<Hash256, BinaryCodecError> {
+    let mut data = Vec::with_capacity(tx_blob.len() + meta_blob.len() + 6 + 32);
+    put_vl_prefix(&mut data, tx_blob.len())?;
+    data.extend_from_slice(tx_blob);
+    put_vl_prefix(&mut data, meta_blob.len())?;
+    data.extend_from_slice(meta_blob);
+    data.extend_from_slice(&transaction_id.0);
+    Ok(hash::hash(HASH_PREFIX_TRANSACTION_NODE, &data))
+}
+
+/// Hash of a state tree leaf, which holds a ledger object
+pub fn ledger_object_leaf_hash(index: Hash256, data: &[u8]) -> Hash256 {
+    let mut bytes = Vec::with_capacity(data.len() + 32);
+    bytes.extend_from_slice(data);
+    bytes.extend_from_slice(&index.0);
+    hash::hash(HASH_PREFIX_LEAF_NODE, &bytes)
+}
+
+/// Hash of an inner node given the hashes of its children. Empty branches have the zero hash.
+pub fn inner_node_hash(children: &[Hash256; BRANCH_FACTOR]) -> Hash256 {
+    let mut bytes = Vec::with_capacity(BRANCH_FACTOR * 32);
+    for child in children {
+        bytes.extend_from_slice(&child.0);
+    }
+    hash::hash(HASH_PREFIX_INNER_NODE, &bytes)
+}
+
+/// Nibble of the key that selects the branch of the inner node at the given depth
+pub(crate) fn branch(key: &[u8; 32], depth: usize) -> usize {
+    let byte = key[depth / 2];
+    if depth % 2 == 1 {
+        (byte & 0x0F) as usize
+    } else {
+        (byte >> 4) as usize
+    }
+}
+
+/// Hashes of the children of the inner node at the given depth, holding the given leaves
+/// sorted by key
+pub(crate) fn children_hashes(
+    leaves: &[([u8; 32], Hash256)],
+    depth: usize,
+) -> [Hash256; BRANCH_FACTOR] {
+    let mut children = [Hash256([0; 32]); BRANCH_FACTOR];
+    let mut remaining = leaves;
+    while let Some((key, _)) = remaining.first() {
+        let branch_index = branch(key, depth);
+        let end = remaining.partition_point(|(key, _)| branch(key, depth) == branch_index);
+        children[branch_index] = node_hash(&remaining[..end], depth + 1);
+        remaining = &remaining[end..];
+    }
+    children
+}
+
+/// Hash of the node at the given depth holding the given non-empty leaves sorted by key.
+/// A single leaf is held directly, several leaves are held by an inner node.
+fn node_hash(leaves: &[([u8; 32], Hash256)], depth: usize) -> Hash256 {
+    match leaves {
+        [(_, leaf_hash)] => *leaf_hash,
+        _ => inner_node_hash(&children_hashes(leaves, depth)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    fn key(hex: &str) -> Hash256 {
+        Hash256::from_hex(&format!("{:0<64}", hex)).unwrap()
+    }
+
+    #[test]
+    fn test_empty_root_hash() {
+        assert_eq!(ShaMap::new().root_hash(), Hash256([0; 32]));
+    }
+
+    #[test]
+    fn test_single_leaf_root_hash() {
+        let mut shamap = ShaMap::new();
+        let leaf_hash = Hash256([0xAB; 32]);
+        shamap.add_leaf(key("3"), leaf_hash);
+
+        let mut children = [Hash256([0; 32]); BRANCH_FACTOR];
+        children[3] = leaf_hash;
+        assert_eq!(shamap.root_hash(), inner_node_hash(&children));
+    }
+
+    #[test]
+    fn test_root_hash() {
+        let mut shamap = ShaMap::new();
+        for (index, hex) in ["0", "1", "11", "12", "7000DE", "7000DF", "F"]
+            .iter()
+            .enumerate()
+        {
+            shamap.add_ledger_object(key(hex), &[index as u8; 10]);
+        }
+        assert_eq!(shamap.len(), 7);
+        assert_eq!(
+            shamap.root_hash().to_hex(),
+            "3DC4C725DFB0F4213D654DCC8242584371B73C4D7399DD52768EF0AA2C595DFB"
+        );
+    }
+
+    #[test]
+    fn test_root_hash_independent_of_order() {
+        let keys = ["0", "1", "11", "12", "7000DE", "7000DF", "F"];
+        let mut shamap = ShaMap::new();
+        for hex in keys {
+            shamap.add_ledger_object(key(hex), hex.as_bytes());
+        }
+        let mut reversed = ShaMap::new();
+        for hex in keys.iter().rev() {
+            reversed.add_ledger_object(key(hex), hex.as_bytes());
+        }
+        assert_eq!(shamap.root_hash(), reversed.root_hash());
+    }
+
+    #[test]
+    fn test_transaction_leaf_hash() {
+        let transaction_id = Hash256([1; 32]);
+        assert_eq!(
+            transaction_leaf_hash(transaction_id, &[2; 3], &[3; 200])
+                .unwrap()
+                .to_hex(),
+            "77E6B2CF61796D95691C2F2960F0580425B3CCDA644DC3CF2E332CD1421BF481"
+        );
+    }
+
+    #[test]
+    fn test_proof() {
+        let keys = ["0", "1", "11", "12", "7000DE", "7000DF", "F"];
+        let mut shamap = ShaMap::new();
+        for hex in keys {
+            shamap.add_ledger_object(key(hex), hex.as_bytes());
+        }
+        let root_hash = shamap.root_hash();
+
+        for hex in keys {
+            let proof = shamap.proof(key(hex)).unwrap();
+            let leaf_hash = ledger_object_leaf_hash(key(hex), hex.as_bytes());
+            proof.verify(key(hex), leaf_hash, root_hash).unwrap();
+        }
+        assert_eq!(shamap.proof(key("7000DE")).unwrap().path.len(), 6);
+        assert_eq!(shamap.proof(key("F")).unwrap().path.len(), 1);
+    }
+
+    #[test]
+    fn test_proof_missing_key() {
+        let mut shamap = ShaMap::new();
+        for hex in ["11", "12", "7000DE"] {
+            shamap.add_ledger_object(key(hex), hex.as_bytes());
+        }
+        assert_eq!(shamap.proof(key("0")), None);
+        assert_eq!(shamap.proof(key("13")), None);
+        assert_eq!(shamap.proof(key("7000DF")), None);
+    }
+
+    #[test]
+    fn test_proof_rejected() {
+        let mut shamap = ShaMap::new();
+        for hex in ["11", "12", "7000DE"] {
+            shamap.add_ledger_object(key(hex), hex.as_bytes());
+        }
+        let root_hash = shamap.root_hash();
+        let proof = shamap.proof(key("12")).unwrap();
+
+        let tampered_leaf_hash = ledger_object_leaf_hash(key("12"), b"13");
+        let result = proof.verify(key("12"), tampered_leaf_hash, root_hash);
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(message)) => {
+            assert!(message.contains("depth 1"), "message: {}", message);
+        });
+
+        let leaf_hash = ledger_object_leaf_hash(key("12"), b"12");
+        let result = proof.verify(key("12"), leaf_hash, Hash256([1; 32]));
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(message)) => {
+            assert!(message.contains("Root hash"), "message: {}", message);
+        });
+
+        let result = ShaMapProof { path: Vec::new() }.verify(key("12"), leaf_hash, root_hash);
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(_)));
+    }
+
+    /// Inner node in wire format, compressed if it has less than 12 children like rippled does
+    fn wire_inner_node(children: &[Hash256; BRANCH_FACTOR]) -> Vec<u8> {
+        let mut node = Vec::new();
+        let non_empty = children.iter().filter(|child| child.0 != [0; 32]).count();
+        if non_empty < 12 {
+            for (branch_index, child) in children.iter().enumerate() {
+                if child.0 != [0; 32] {
+                    node.extend_from_slice(&child.0);
+                    node.push(branch_index as u8);
+                }
+            }
+            node.push(WIRE_TYPE_COMPRESSED_INNER);
+        } else {
+            for child in children {
+                node.extend_from_slice(&child.0);
+            }
+            node.push(WIRE_TYPE_INNER);
+        }
+        node
+    }
+
+    /// Proof path in wire format, given the leaf in wire format
+    fn wire_nodes(proof: &ShaMapProof, leaf: Vec<u8>) -> Vec<Vec<u8>> {
+        let mut nodes = vec![leaf];
+        nodes.extend(proof.path.iter().rev().map(wire_inner_node));
+        nodes
+    }
+
+    fn wire_ledger_object_leaf(index: Hash256, data: &[u8]) -> Vec<u8> {
+        let mut node = data.to_vec();
+        node.extend_from_slice(&index.0);
+        node.push(WIRE_TYPE_ACCOUNT_STATE);
+        node
+    }
+
+    #[test]
+    fn test_proof_from_wire_nodes() {
+        let keys = ["0", "1", "11", "12", "7000DE", "7000DF", "F"];
+        let mut shamap = ShaMap::new();
+        for hex in keys {
+            shamap.add_ledger_object(key(hex), hex.as_bytes());
+        }
+        let mut full = ShaMap::new();
+        for index in 0..=255u8 {
+            full.add_ledger_object(Hash256([index; 32]), &[index]);
+        }
+
+        for (shamap, key) in keys
+            .iter()
+            .map(|hex| (&shamap, key(hex)))
+            .chain([(&full, Hash256([0x12; 32]))])
+        {
+            let proof = shamap.proof(key).unwrap();
+            let nodes = wire_nodes(&proof, wire_ledger_object_leaf(key, b"data"));
+            assert_eq!(ShaMapProof::from_wire_nodes(key, &nodes).unwrap(), proof);
+        }
+        // Root of full tree is not compressed
+        let nodes = wire_nodes(&full.proof(Hash256([0x12; 32])).unwrap(), Vec::new());
+        assert_eq!(nodes.last().unwrap().last(), Some(&WIRE_TYPE_INNER));
+    }
+
+    #[test]
+    fn test_proof_from_wire_nodes_transaction_leaf() {
+        let tx_blob = [0x12, 0x00, 0x00];
+        let transaction_id = hash::transaction_id_from_blob(&tx_blob);
+        let mut leaf = tx_blob.to_vec();
+        leaf.push(WIRE_TYPE_TRANSACTION);
+        let root = [Hash256([1; 32]); BRANCH_FACTOR];
+
+        let proof =
+            ShaMapProof::from_wire_nodes(transaction_id, &[leaf, wire_inner_node(&root)]).unwrap();
+        assert_eq!(proof.path, vec![root]);
+    }
+
+    #[test]
+    fn test_proof_from_wire_nodes_rejected() {
+        let root = wire_inner_node(&[Hash256([1; 32]); BRANCH_FACTOR]);
+        let leaf = wire_ledger_object_leaf(key("12"), b"12");
+
+        let result = ShaMapProof::from_wire_nodes::<Vec<u8>>(key("12"), &[]);
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(message)) => {
+            assert!(message.contains("no nodes"), "message: {}", message);
+        });
+
+        let result = ShaMapProof::from_wire_nodes(key("13"), &[leaf.clone(), root.clone()]);
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(message)) => {
+            assert!(message.contains("Leaf has key"), "message: {}", message);
+        });
+
+        let result = ShaMapProof::from_wire_nodes(key("12"), &[root.clone(), root.clone()]);
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(message)) => {
+            assert!(message.contains("not a leaf"), "message: {}", message);
+        });
+
+        let result = ShaMapProof::from_wire_nodes(key("12"), &[leaf.clone(), leaf.clone()]);
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(message)) => {
+            assert!(message.contains("Expected inner node"), "message: {}", message);
+        });
+
+        let result = ShaMapProof::from_wire_nodes(key("12"), &[leaf.clone(), root[1..].to_vec()]);
+        assert_matches!(result, Err(BinaryCodecError::InvalidLength(_)));
+
+        let mut compressed = vec![0; 33];
+        compressed[32] = 16;
+        compressed.push(WIRE_TYPE_COMPRESSED_INNER);
+        let result = ShaMapProof::from_wire_nodes(key("12"), &[leaf.clone(), compressed]);
+        assert_matches!(result, Err(BinaryCodecError::OutOfRange(_)));
+
+        let result = ShaMapProof::from_wire_nodes(key("12"), &[vec![7]]);
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(message)) => {
+            assert!(message.contains("Unknown node wire type 7"), "message: {}", message);
+        });
+
+        let result = ShaMapProof::from_wire_nodes(key("12"), &[vec![0; 5], vec![]]);
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(_)));
+
+        let result = ShaMapProof::from_wire_nodes(key("12"), &[vec![WIRE_TYPE_ACCOUNT_STATE]]);
+        assert_matches!(result, Err(BinaryCodecError::InsufficientBytes(_)));
+    }
+}
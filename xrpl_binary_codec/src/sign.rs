@@ -1,16 +1,59 @@
+use crate::alloc::string::ToString;
+use crate::deserializer::borrowed::{self, FieldCode, FieldId, RawField, TypeCode};
+use crate::keypair::{PublicKey, SecretKey};
 use crate::{hash, serialize, BinaryCodecError};
-use libsecp256k1::{Message, PublicKey, SecretKey};
-use xrpl_types::{Blob, TransactionTrait};
+use alloc::format;
+use alloc::vec::Vec;
+use ed25519_dalek::Signer as _;
+use libsecp256k1::Message;
+use xrpl_types::serialize::CanonicalSerialize;
+use xrpl_types::{AccountId, Blob, Object, Signer, TransactionTrait};
 
-/// Sign given transaction with secp256k1 <https://xrpl.org/cryptographic-keys.html#signing-algorithms>
+const ACCOUNT: FieldId = FieldId::from_type_field(TypeCode::AccountId, FieldCode(1));
+const SIGNING_PUB_KEY: FieldId = FieldId::from_type_field(TypeCode::Blob, FieldCode(3));
+pub(crate) const TXN_SIGNATURE: FieldId = FieldId::from_type_field(TypeCode::Blob, FieldCode(4));
+const SIGNERS: FieldId = FieldId::from_type_field(TypeCode::Array, FieldCode(3));
+
+/// Sign given transaction with secp256k1 or ed25519, depending on the type of the given keys
+/// <https://xrpl.org/cryptographic-keys.html#signing-algorithms>
 pub fn sign_transaction<T: TransactionTrait>(
     transaction: &mut T,
     public_key: &PublicKey,
     secret_key: &SecretKey,
 ) -> Result<(), BinaryCodecError> {
-    transaction.common_mut().signing_pub_key =
-        Some(Blob(public_key.serialize_compressed().to_vec()));
-    let serialized = serialize::serialize(transaction)?;
+    sign_transaction_serialized_with(transaction, public_key, secret_key, serialize::serialize)
+}
+
+/// Sign given transaction like [`sign_transaction`], serializing it in a single pass with
+/// [`serialize::serialize_into`]
+pub fn sign_canonical_transaction<T: TransactionTrait + CanonicalSerialize>(
+    transaction: &mut T,
+    public_key: &PublicKey,
+    secret_key: &SecretKey,
+) -> Result<(), BinaryCodecError> {
+    sign_transaction_serialized_with(transaction, public_key, secret_key, |transaction| {
+        let mut serialized = Vec::new();
+        serialize::serialize_into(transaction, &mut serialized)?;
+        Ok(serialized)
+    })
+}
+
+fn sign_transaction_serialized_with<T: TransactionTrait>(
+    transaction: &mut T,
+    public_key: &PublicKey,
+    secret_key: &SecretKey,
+    serialize: impl FnOnce(&T) -> Result<Vec<u8>, BinaryCodecError>,
+) -> Result<(), BinaryCodecError> {
+    if public_key.algorithm() != secret_key.algorithm() {
+        return Err(BinaryCodecError::InvalidKey(format!(
+            "public key is {:?} but secret key is {:?}",
+            public_key.algorithm(),
+            secret_key.algorithm()
+        )));
+    }
+    transaction.common_mut().signing_pub_key = Some(public_key.to_blob());
+    transaction.common_mut().txn_signature = None;
+    let serialized = serialize(transaction)?;
     let signature = signature(
         hash::HASH_PREFIX_UNSIGNED_TRANSACTION_SINGLE,
         &serialized,
@@ -20,41 +63,367 @@ pub fn sign_transaction<T: TransactionTrait>(
     Ok(())
 }
 
-/// Calculate secp256k1 signature <https://xrpl.org/cryptographic-keys.html#signing-algorithms>
-fn signature(prefix: [u8; 4], data: &[u8], secret_key: &SecretKey) -> Blob {
-    let hash = hash::hash(prefix, data);
-    let message = Message::parse(&hash.0);
-    let (signature, _) = libsecp256k1::sign(&message, secret_key);
-    Blob(signature.serialize_der().as_ref().to_vec())
+/// Add the signature of given signer to the `Signers` of given transaction <https://xrpl.org/multi-signing.html>,
+/// keeping `Signers` sorted by account. Sets `SigningPubKey` to empty, as required for
+/// multi-signed transactions. An existing signature by the same signer is replaced.
+pub fn sign_transaction_for<T: TransactionTrait>(
+    transaction: &mut T,
+    account: AccountId,
+    public_key: &PublicKey,
+    secret_key: &SecretKey,
+) -> Result<(), BinaryCodecError> {
+    if public_key.algorithm() != secret_key.algorithm() {
+        return Err(BinaryCodecError::InvalidKey(format!(
+            "public key is {:?} but secret key is {:?}",
+            public_key.algorithm(),
+            secret_key.algorithm()
+        )));
+    }
+    transaction.common_mut().signing_pub_key = Some(Blob(Vec::new()));
+    transaction.common_mut().txn_signature = None;
+    let serialized = serialize_for_multi_signing(transaction)?;
+    let signature = signature(
+        hash::HASH_PREFIX_UNSIGNED_TRANSACTION_MULTI,
+        &multi_signing_data(serialized, account),
+        secret_key,
+    );
+
+    let signers = &mut transaction.common_mut().signers;
+    signers.retain(|signer| signer.account != account);
+    signers.push(Signer {
+        account,
+        txn_signature: signature,
+        signing_pub_key: public_key.to_blob(),
+        unknown_fields: Object::default(),
+    });
+    sort_signers(signers);
+    Ok(())
+}
+
+/// Combine copies of the same transaction, each signed by one or more signers with [`sign_transaction_for`],
+/// into a single transaction with all signatures in `Signers`, sorted by account as required
+/// <https://xrpl.org/multi-signing.html>. Fails if the copies differ in anything but `Signers`.
+pub fn combine_signed_transactions<T: TransactionTrait>(
+    transactions: impl IntoIterator<Item = T>,
+) -> Result<T, BinaryCodecError> {
+    let mut transactions = transactions.into_iter();
+    let Some(mut combined) = transactions.next() else {
+        return Err(BinaryCodecError::InvalidSignature(
+            "no transactions to combine".to_string(),
+        ));
+    };
+    let serialized = serialize_for_multi_signing(&mut combined)?;
+
+    for mut transaction in transactions {
+        if serialize_for_multi_signing(&mut transaction)? != serialized {
+            return Err(BinaryCodecError::InvalidSignature(
+                "transactions to combine are not identical".to_string(),
+            ));
+        }
+        for signer in core::mem::take(&mut transaction.common_mut().signers) {
+            let signers = &mut combined.common_mut().signers;
+            match signers
+                .iter()
+                .find(|existing| existing.account == signer.account)
+            {
+                Some(existing) if *existing == signer => {}
+                Some(_) => {
+                    return Err(BinaryCodecError::InvalidSignature(format!(
+                        "conflicting signatures for signer {}",
+                        signer.account.to_address()
+                    )));
+                }
+                None => signers.push(signer),
+            }
+        }
+    }
+
+    sort_signers(&mut combined.common_mut().signers);
+    Ok(combined)
+}
+
+/// Signers must be sorted by the numeric value of the account id <https://xrpl.org/transaction-common-fields.html#signers-field>
+fn sort_signers(signers: &mut [Signer]) {
+    signers.sort_by_key(|signer| signer.account.0);
+}
+
+/// Serializes the transaction without `Signers`, which are not part of the signed data
+fn serialize_for_multi_signing<T: TransactionTrait>(
+    transaction: &mut T,
+) -> Result<Vec<u8>, BinaryCodecError> {
+    let signers = core::mem::take(&mut transaction.common_mut().signers);
+    let serialized = serialize::serialize(transaction);
+    transaction.common_mut().signers = signers;
+    serialized
+}
+
+/// The data signed by a multi-signer is the serialized transaction followed by the signer account id
+fn multi_signing_data(mut serialized: Vec<u8>, account: AccountId) -> Vec<u8> {
+    serialized.extend_from_slice(&account.0);
+    serialized
+}
+
+/// Calculate signature <https://xrpl.org/cryptographic-keys.html#signing-algorithms> of the prefixed data
+pub(crate) fn signature(prefix: [u8; 4], data: &[u8], secret_key: &SecretKey) -> Blob {
+    message_signature(&prefixed(prefix, data), secret_key)
+}
+
+/// Calculate signature <https://xrpl.org/cryptographic-keys.html#signing-algorithms> of the message.
+/// Secp256k1 signs the SHA-512Half of the message and produces a DER encoded signature,
+/// ed25519 signs the message itself.
+pub(crate) fn message_signature(message: &[u8], secret_key: &SecretKey) -> Blob {
+    match secret_key {
+        SecretKey::Secp256k1(secret_key) => {
+            let hash = hash::sha512_half(message);
+            let message = Message::parse(&hash.0);
+            let (signature, _) = libsecp256k1::sign(&message, secret_key);
+            Blob(signature.serialize_der().as_ref().to_vec())
+        }
+        SecretKey::Ed25519(secret_key) => Blob(secret_key.sign(message).to_bytes().to_vec()),
+    }
+}
+
+fn prefixed(prefix: [u8; 4], data: &[u8]) -> Vec<u8> {
+    let mut message = Vec::with_capacity(prefix.len() + data.len());
+    message.extend_from_slice(&prefix);
+    message.extend_from_slice(data);
+    message
+}
+
+/// Verify the signatures of given transaction <https://xrpl.org/cryptographic-keys.html#signing-algorithms>.
+/// For single-signed transactions, checks that `TxnSignature` is a valid signature by `SigningPubKey`
+/// and that `SigningPubKey` derives to `Account`. For multi-signed transactions, the same is checked
+/// for each entry in `Signers`. Note that it is not checked that the signers are part of the signer
+/// list of the account. Secp256k1 signatures must be fully canonical, i.e. strictly DER encoded
+/// with low S <https://xrpl.org/transaction-malleability.html>.
+pub fn verify_transaction<T: TransactionTrait + Clone>(
+    transaction: &T,
+) -> Result<(), BinaryCodecError> {
+    let common = transaction.common();
+    match &common.signing_pub_key {
+        Some(signing_pub_key) if !signing_pub_key.0.is_empty() => {
+            let public_key = PublicKey::from_bytes(&signing_pub_key.0)?;
+            let Some(signature) = &common.txn_signature else {
+                return Err(BinaryCodecError::InvalidSignature(
+                    "transaction has no signature".to_string(),
+                ));
+            };
+            check_account(&public_key, common.account)?;
+
+            let mut unsigned = transaction.clone();
+            unsigned.common_mut().txn_signature = None;
+            let serialized = serialize::serialize(&unsigned)?;
+
+            verify_signature(
+                hash::HASH_PREFIX_UNSIGNED_TRANSACTION_SINGLE,
+                &serialized,
+                &signature.0,
+                &public_key,
+            )
+        }
+        Some(_) if !common.signers.is_empty() => {
+            if common.txn_signature.is_some() {
+                return Err(BinaryCodecError::InvalidSignature(
+                    "multi-signed transaction has TxnSignature".to_string(),
+                ));
+            }
+            let serialized = serialize_for_multi_signing(&mut transaction.clone())?;
+            for signer in &common.signers {
+                let public_key = PublicKey::from_bytes(&signer.signing_pub_key.0)?;
+                check_account(&public_key, signer.account)?;
+                verify_signature(
+                    hash::HASH_PREFIX_UNSIGNED_TRANSACTION_MULTI,
+                    &multi_signing_data(serialized.clone(), signer.account),
+                    &signer.txn_signature.0,
+                    &public_key,
+                )?;
+            }
+            Ok(())
+        }
+        _ => Err(BinaryCodecError::InvalidSignature(
+            "transaction has no signing public key".to_string(),
+        )),
+    }
+}
+
+fn check_account(public_key: &PublicKey, account: AccountId) -> Result<(), BinaryCodecError> {
+    let account_id = public_key.account_id();
+    if account_id != account {
+        return Err(BinaryCodecError::InvalidSignature(format!(
+            "signing public key belongs to {} but account is {}",
+            account_id.to_address(),
+            account.to_address()
+        )));
+    }
+    Ok(())
+}
+
+/// Verify the signatures of the given serialized transaction, see [`verify_transaction`].
+/// The signed data is computed from the raw fields of the blob, with the signature fields left out
+/// and the other fields re-emitted as they are, such that the transaction need not be modelled and
+/// may contain fields that are not known.
+pub fn verify_transaction_blob(bytes: &[u8]) -> Result<(), BinaryCodecError> {
+    let fields = borrowed::raw_fields(bytes)?;
+    let account = required_field(&fields, ACCOUNT)?
+        .field_accessor()
+        .deserialize_account_id()?;
+    let signing_pub_key = raw_field(&fields, SIGNING_PUB_KEY)
+        .map(|field| field.field_accessor().deserialize_blob())
+        .transpose()?;
+    let signers = raw_field(&fields, SIGNERS)
+        .map(|field| field.inner_fields())
+        .transpose()?
+        .unwrap_or_default();
+    match signing_pub_key {
+        Some(signing_pub_key) if !signing_pub_key.0.is_empty() => {
+            let public_key = PublicKey::from_bytes(signing_pub_key.0)?;
+            let Some(signature) = raw_field(&fields, TXN_SIGNATURE) else {
+                return Err(BinaryCodecError::InvalidSignature(
+                    "transaction has no signature".to_string(),
+                ));
+            };
+            check_account(&public_key, account)?;
+
+            verify_signature(
+                hash::HASH_PREFIX_UNSIGNED_TRANSACTION_SINGLE,
+                &fields_without(&fields, TXN_SIGNATURE),
+                signature.field_accessor().deserialize_blob()?.0,
+                &public_key,
+            )
+        }
+        Some(_) if !signers.is_empty() => {
+            if raw_field(&fields, TXN_SIGNATURE).is_some() {
+                return Err(BinaryCodecError::InvalidSignature(
+                    "multi-signed transaction has TxnSignature".to_string(),
+                ));
+            }
+            let serialized = fields_without(&fields, SIGNERS);
+            for signer in signers {
+                let signer_fields = signer.inner_fields()?;
+                let account = required_field(&signer_fields, ACCOUNT)?
+                    .field_accessor()
+                    .deserialize_account_id()?;
+                let signing_pub_key = required_field(&signer_fields, SIGNING_PUB_KEY)?
+                    .field_accessor()
+                    .deserialize_blob()?;
+                let signature = required_field(&signer_fields, TXN_SIGNATURE)?
+                    .field_accessor()
+                    .deserialize_blob()?;
+                let public_key = PublicKey::from_bytes(signing_pub_key.0)?;
+                check_account(&public_key, account)?;
+                verify_signature(
+                    hash::HASH_PREFIX_UNSIGNED_TRANSACTION_MULTI,
+                    &multi_signing_data(serialized.clone(), account),
+                    signature.0,
+                    &public_key,
+                )?;
+            }
+            Ok(())
+        }
+        _ => Err(BinaryCodecError::InvalidSignature(
+            "transaction has no signing public key".to_string(),
+        )),
+    }
+}
+
+fn raw_field<'a, 'b>(fields: &'b [RawField<'a>], field_id: FieldId) -> Option<&'b RawField<'a>> {
+    fields.iter().find(|field| field.field_id == field_id)
+}
+
+fn required_field<'a, 'b>(
+    fields: &'b [RawField<'a>],
+    field_id: FieldId,
+) -> Result<&'b RawField<'a>, BinaryCodecError> {
+    raw_field(fields, field_id).ok_or_else(|| {
+        BinaryCodecError::InvalidSignature(format!(
+            "field {} missing",
+            field_id.name().unwrap_or_default()
+        ))
+    })
+}
+
+/// Concatenates the encoded fields, leaving out the field with the given id
+pub(crate) fn fields_without(fields: &[RawField<'_>], field_id: FieldId) -> Vec<u8> {
+    let mut serialized = Vec::new();
+    for field in fields.iter().filter(|field| field.field_id != field_id) {
+        serialized.extend_from_slice(field.bytes);
+    }
+    serialized
+}
+
+/// Verify signature <https://xrpl.org/cryptographic-keys.html#signing-algorithms> of the prefixed data
+pub(crate) fn verify_signature(
+    prefix: [u8; 4],
+    data: &[u8],
+    signature: &[u8],
+    public_key: &PublicKey,
+) -> Result<(), BinaryCodecError> {
+    verify_message_signature(&prefixed(prefix, data), signature, public_key)
+}
+
+/// Verify signature <https://xrpl.org/cryptographic-keys.html#signing-algorithms> of the message
+pub(crate) fn verify_message_signature(
+    message: &[u8],
+    signature: &[u8],
+    public_key: &PublicKey,
+) -> Result<(), BinaryCodecError> {
+    let valid = match public_key {
+        PublicKey::Secp256k1(public_key) => {
+            let der = signature;
+            let signature = libsecp256k1::Signature::parse_der(der)
+                .map_err(|err| BinaryCodecError::InvalidSignature(err.to_string()))?;
+            if signature.serialize_der().as_ref() != der {
+                return Err(BinaryCodecError::InvalidSignature(
+                    "secp256k1 signature is not strictly DER encoded".to_string(),
+                ));
+            }
+            if signature.s.is_high() {
+                return Err(BinaryCodecError::InvalidSignature(
+                    "secp256k1 signature is not fully canonical (high S)".to_string(),
+                ));
+            }
+            let hash = hash::sha512_half(message);
+            let message = Message::parse(&hash.0);
+            libsecp256k1::verify(&message, &signature, public_key)
+        }
+        PublicKey::Ed25519(public_key) => {
+            let signature = ed25519_dalek::Signature::from_slice(signature)
+                .map_err(|err| BinaryCodecError::InvalidSignature(err.to_string()))?;
+            public_key.verify_strict(message, &signature).is_ok()
+        }
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(BinaryCodecError::InvalidSignature(
+            "signature does not match signing public key".to_string(),
+        ))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use enumflags2::BitFlags;
-    use xrpl_types::{AccountId, Amount, DropsAmount, PaymentTransaction};
+    use crate::keypair::Keypair;
+    use assert_matches::assert_matches;
+    use xrpl_types::{
+        AccountId, Amount, DropsAmount, Flags, OfferCreateTransaction, PaymentTransaction,
+    };
 
     #[test]
     fn test_sign_transaction() {
         let public_key = "037D37332B158AC75D7BA8E7EF1F3F4C7C0FA7B4BD8818B9C03545D3AED40BB3A9";
         let secret_key = "165F2F406B5DCC37E666B7A0C9686CD4C92B67D5D362C618A96627E394F2FF45";
 
-        let secret_key = SecretKey::parse_slice(&hex::decode(secret_key).unwrap()).unwrap();
-        let public_key = PublicKey::parse_compressed(
-            &hex::decode(public_key)
-                .unwrap()
-                .as_slice()
-                .try_into()
-                .unwrap(),
-        )
-        .unwrap();
+        let secret_key = SecretKey::from_hex(secret_key).unwrap();
+        let public_key = PublicKey::from_hex(public_key).unwrap();
 
         let mut tx = PaymentTransaction::new(
             AccountId::from_address("rB48JG388ovDA9fmPJbqgnSK3tnndSxgAe").unwrap(),
             Amount::drops(22_000_000).unwrap(),
             AccountId::from_address("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe").unwrap(),
         );
-        tx.flags = BitFlags::from_bits(2147483648).unwrap();
+        tx.flags = Some(Flags::from_bits(2147483648));
         tx.common.last_ledger_sequence = Some(18311743);
         tx.common.fee = Some(DropsAmount::from_drops(12).unwrap());
         tx.common.sequence = Some(18311659);
@@ -65,4 +434,300 @@ mod tests {
 
         assert_eq!(tx_hex, "120000228000000024011769EB201B01176A3F6140000000014FB18068400000000000000C7321037D37332B158AC75D7BA8E7EF1F3F4C7C0FA7B4BD8818B9C03545D3AED40BB3A974463044022059E8475EF21F380A0A8FF70FF976F53DFB2EEAADD98860F642BF4004A008BEF7022014279499218DD1460B753135AEAED5A63935ACE5975869C3204886B1F346569E811471CFCE39CE9B97E7E519AF8B282DDBE140A278748314F667B0CA50CC7709A220B0561B85E53A48461FA8");
     }
+
+    #[test]
+    fn test_sign_transaction_ed25519() {
+        let keypair = Keypair::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r").unwrap();
+
+        let mut tx = PaymentTransaction::new(
+            keypair.account_id(),
+            Amount::drops(22_000_000).unwrap(),
+            AccountId::from_address("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe").unwrap(),
+        );
+        tx.common.last_ledger_sequence = Some(18311743);
+        tx.common.fee = Some(DropsAmount::from_drops(12).unwrap());
+        tx.common.sequence = Some(18311659);
+
+        sign_transaction(&mut tx, &keypair.public_key, &keypair.secret_key).unwrap();
+
+        assert_eq!(
+            tx.common.signing_pub_key,
+            Some(Blob(
+                hex::decode("ED01FA53FA5A7E77798F882ECE20B1ABC00BB358A9E55A202D0D0676BD0CE37A63")
+                    .unwrap()
+            ))
+        );
+
+        let tx_hex = hex::encode_upper(serialize::serialize(&tx).unwrap());
+
+        assert_eq!(tx_hex, "120000220000000024011769EB201B01176A3F6140000000014FB18068400000000000000C7321ED01FA53FA5A7E77798F882ECE20B1ABC00BB358A9E55A202D0D0676BD0CE37A637440CE15753F5289FC63808972745083460DB36CBFF6FE62EF5EAB0A365B8DD5E39D56B9089EB0E8B404111A3C36FF798D98288991A66D901D132F23EB09ACFB1D0C8114D28B177E48D9A8D057E70F7E464B498367281B988314F667B0CA50CC7709A220B0561B85E53A48461FA8");
+    }
+
+    /// Tests that signing an already signed transaction replaces the signature, and does not
+    /// sign the previous signature
+    #[test]
+    fn test_sign_transaction_again() {
+        let keypair = Keypair::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r").unwrap();
+        let other_keypair = Keypair::from_seed("sp5fghtJtpUorTwvof1NpDXAzNwf5").unwrap();
+
+        let mut tx = OfferCreateTransaction::new(
+            keypair.account_id(),
+            Amount::drops(22_000_000).unwrap(),
+            Amount::drops(1_000).unwrap(),
+        );
+        tx.common.fee = Some(DropsAmount::from_drops(12).unwrap());
+        tx.common.sequence = Some(18311659);
+        let mut signed_once = tx.clone();
+        sign_transaction(&mut signed_once, &keypair.public_key, &keypair.secret_key).unwrap();
+
+        sign_transaction(
+            &mut tx,
+            &other_keypair.public_key,
+            &other_keypair.secret_key,
+        )
+        .unwrap();
+        sign_transaction(&mut tx, &keypair.public_key, &keypair.secret_key).unwrap();
+        assert_eq!(tx.common.txn_signature, signed_once.common.txn_signature);
+        verify_transaction(&tx).unwrap();
+
+        sign_canonical_transaction(&mut tx, &keypair.public_key, &keypair.secret_key).unwrap();
+        assert_eq!(tx.common.txn_signature, signed_once.common.txn_signature);
+    }
+
+    /// Tests that signing in a single pass gives the same signature as signing with sorting
+    #[test]
+    fn test_sign_canonical_transaction() {
+        let keypair = Keypair::from_seed("sp5fghtJtpUorTwvof1NpDXAzNwf5").unwrap();
+        let mut tx = OfferCreateTransaction::new(
+            keypair.account_id(),
+            Amount::drops(22_000_000).unwrap(),
+            Amount::drops(1_000).unwrap(),
+        );
+        tx.common.fee = Some(DropsAmount::from_drops(12).unwrap());
+        tx.common.sequence = Some(18311659);
+        let mut canonical_tx = tx.clone();
+
+        sign_transaction(&mut tx, &keypair.public_key, &keypair.secret_key).unwrap();
+        sign_canonical_transaction(&mut canonical_tx, &keypair.public_key, &keypair.secret_key)
+            .unwrap();
+
+        assert_eq!(canonical_tx.common.txn_signature, tx.common.txn_signature);
+        verify_transaction(&canonical_tx).unwrap();
+    }
+
+    #[test]
+    fn test_sign_transaction_mismatching_keys() {
+        let ed25519 = Keypair::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r").unwrap();
+        let secp256k1 = Keypair::from_seed("sp5fghtJtpUorTwvof1NpDXAzNwf5").unwrap();
+
+        let mut tx = PaymentTransaction::new(
+            ed25519.account_id(),
+            Amount::drops(22_000_000).unwrap(),
+            secp256k1.account_id(),
+        );
+
+        let result = sign_transaction(&mut tx, &ed25519.public_key, &secp256k1.secret_key);
+        assert_matches!(result, Err(BinaryCodecError::InvalidKey(_)));
+    }
+
+    fn signed_payment(seed: &str) -> PaymentTransaction {
+        let keypair = Keypair::from_seed(seed).unwrap();
+        let mut tx = PaymentTransaction::new(
+            keypair.account_id(),
+            Amount::drops(22_000_000).unwrap(),
+            AccountId::from_address("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe").unwrap(),
+        );
+        tx.common.fee = Some(DropsAmount::from_drops(12).unwrap());
+        tx.common.sequence = Some(18311659);
+        sign_transaction(&mut tx, &keypair.public_key, &keypair.secret_key).unwrap();
+        tx
+    }
+
+    #[test]
+    fn test_verify_transaction() {
+        for seed in [
+            "sp5fghtJtpUorTwvof1NpDXAzNwf5",
+            "sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r",
+        ] {
+            let tx = signed_payment(seed);
+            verify_transaction(&tx).unwrap();
+
+            let blob = serialize::serialize(&tx).unwrap();
+            verify_transaction_blob(&blob).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_verify_transaction_tampered() {
+        for seed in [
+            "sp5fghtJtpUorTwvof1NpDXAzNwf5",
+            "sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r",
+        ] {
+            let mut tx = signed_payment(seed);
+            tx.amount = Amount::drops(23_000_000).unwrap();
+            let result = verify_transaction(&tx);
+            assert_matches!(result, Err(BinaryCodecError::InvalidSignature(message)) => {
+                assert!(message.contains("does not match"), "message: {}", message);
+            });
+        }
+    }
+
+    #[test]
+    fn test_verify_transaction_key_not_matching_account() {
+        let mut tx = signed_payment("sp5fghtJtpUorTwvof1NpDXAzNwf5");
+        tx.common.account = AccountId::from_address("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe").unwrap();
+        let result = verify_transaction(&tx);
+        assert_matches!(result, Err(BinaryCodecError::InvalidSignature(message)) => {
+            assert!(message.contains("signing public key belongs to"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_verify_transaction_unsigned() {
+        let mut tx = signed_payment("sp5fghtJtpUorTwvof1NpDXAzNwf5");
+        tx.common.txn_signature = None;
+        let result = verify_transaction(&tx);
+        assert_matches!(result, Err(BinaryCodecError::InvalidSignature(message)) => {
+            assert!(message.contains("no signature"), "message: {}", message);
+        });
+    }
+
+    /// Test that the malleated signature (r, n - s) is rejected even though it is mathematically valid
+    #[test]
+    fn test_verify_transaction_high_s() {
+        let mut tx = signed_payment("sp5fghtJtpUorTwvof1NpDXAzNwf5");
+        let mut signature =
+            libsecp256k1::Signature::parse_der(&tx.common.txn_signature.as_ref().unwrap().0)
+                .unwrap();
+        assert!(!signature.s.is_high());
+        signature.s = -signature.s;
+        tx.common.txn_signature = Some(Blob(signature.serialize_der().as_ref().to_vec()));
+
+        let result = verify_transaction(&tx);
+        assert_matches!(result, Err(BinaryCodecError::InvalidSignature(message)) => {
+            assert!(message.contains("high S"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_verify_transaction_blob_tampered() {
+        let tx = signed_payment("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r");
+        let mut blob = serialize::serialize(&tx).unwrap();
+        // Last byte of the destination account
+        *blob.last_mut().unwrap() ^= 1;
+        let result = verify_transaction_blob(&blob);
+        assert_matches!(result, Err(BinaryCodecError::InvalidSignature(_)));
+    }
+
+    /// Test that a blob with fields that are not known is verified against the exact signed bytes
+    #[test]
+    fn test_verify_transaction_blob_unknown_field() {
+        let keypair = Keypair::from_seed("sp5fghtJtpUorTwvof1NpDXAzNwf5").unwrap();
+        let mut tx = signed_payment("sp5fghtJtpUorTwvof1NpDXAzNwf5");
+        // AccountId field with field code 200, ordered after `Destination`
+        let unknown_field = [[0x80, 200, 20].as_slice(), &[0xAB; 20]].concat();
+
+        tx.common.txn_signature = None;
+        let unsigned = [serialize::serialize(&tx).unwrap(), unknown_field.clone()].concat();
+        tx.common.txn_signature = Some(signature(
+            hash::HASH_PREFIX_UNSIGNED_TRANSACTION_SINGLE,
+            &unsigned,
+            &keypair.secret_key,
+        ));
+        let mut blob = [serialize::serialize(&tx).unwrap(), unknown_field].concat();
+        verify_transaction_blob(&blob).unwrap();
+
+        *blob.last_mut().unwrap() ^= 1;
+        let result = verify_transaction_blob(&blob);
+        assert_matches!(result, Err(BinaryCodecError::InvalidSignature(message)) => {
+            assert!(message.contains("does not match"), "message: {}", message);
+        });
+    }
+
+    fn multi_signed_payment(seed: &str) -> PaymentTransaction {
+        let keypair = Keypair::from_seed(seed).unwrap();
+        let mut tx = PaymentTransaction::new(
+            AccountId::from_address("rB48JG388ovDA9fmPJbqgnSK3tnndSxgAe").unwrap(),
+            Amount::drops(22_000_000).unwrap(),
+            AccountId::from_address("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe").unwrap(),
+        );
+        tx.common.fee = Some(DropsAmount::from_drops(36).unwrap());
+        tx.common.sequence = Some(18311659);
+        sign_transaction_for(
+            &mut tx,
+            keypair.account_id(),
+            &keypair.public_key,
+            &keypair.secret_key,
+        )
+        .unwrap();
+        tx
+    }
+
+    #[test]
+    fn test_sign_transaction_for() {
+        let tx = multi_signed_payment("sp5fghtJtpUorTwvof1NpDXAzNwf5");
+
+        assert_eq!(tx.common.signing_pub_key, Some(Blob(Vec::new())));
+        assert_eq!(tx.common.txn_signature, None);
+        assert_eq!(tx.common.signers.len(), 1);
+        assert_eq!(
+            tx.common.signers[0].account.to_address(),
+            "rU6K7V3Po4snVhBBaU29sesqs2qTQJWDw1"
+        );
+        verify_transaction(&tx).unwrap();
+    }
+
+    #[test]
+    fn test_combine_signed_transactions() {
+        let secp256k1 = multi_signed_payment("sp5fghtJtpUorTwvof1NpDXAzNwf5");
+        let ed25519 = multi_signed_payment("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r");
+
+        let tx = combine_signed_transactions([ed25519.clone(), secp256k1.clone()]).unwrap();
+
+        // Sorted by account id (0x8049.. before 0xD28B..), not by address
+        let signers: Vec<_> = tx
+            .common
+            .signers
+            .iter()
+            .map(|signer| signer.account.to_address())
+            .collect();
+        assert_eq!(
+            signers,
+            [
+                "rU6K7V3Po4snVhBBaU29sesqs2qTQJWDw1",
+                "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD"
+            ]
+        );
+        verify_transaction(&tx).unwrap();
+
+        let blob = serialize::serialize(&tx).unwrap();
+        verify_transaction_blob(&blob).unwrap();
+
+        // Order of combination does not matter
+        let reversed = combine_signed_transactions([secp256k1, ed25519]).unwrap();
+        assert_eq!(serialize::serialize(&reversed).unwrap(), blob);
+    }
+
+    #[test]
+    fn test_combine_signed_transactions_not_identical() {
+        let secp256k1 = multi_signed_payment("sp5fghtJtpUorTwvof1NpDXAzNwf5");
+        let mut ed25519 = multi_signed_payment("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r");
+        ed25519.common.sequence = Some(18311660);
+
+        let result = combine_signed_transactions([secp256k1, ed25519]);
+        assert_matches!(result, Err(BinaryCodecError::InvalidSignature(message)) => {
+            assert!(message.contains("not identical"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_verify_multi_signed_transaction_tampered() {
+        let secp256k1 = multi_signed_payment("sp5fghtJtpUorTwvof1NpDXAzNwf5");
+        let ed25519 = multi_signed_payment("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r");
+        let mut tx = combine_signed_transactions([secp256k1, ed25519]).unwrap();
+        tx.common.signers[1].account = tx.common.signers[0].account;
+
+        let result = verify_transaction(&tx);
+        assert_matches!(result, Err(BinaryCodecError::InvalidSignature(_)));
+    }
 }
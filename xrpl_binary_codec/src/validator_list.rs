@@ -0,0 +1,430 @@
+use crate::alloc::format;
+use crate::alloc::string::{String, ToString};
+use crate::alloc::vec::Vec;
+use crate::keypair::PublicKey;
+use crate::{manifest, sign, BinaryCodecError};
+use base64::Engine;
+use xrpl_address_codec::encode_node_public_key;
+use xrpl_types::{Blob, LedgerTimestamp, Manifest};
+
+/// Validator list as published by a validator list site, e.g. <https://vl.ripple.com>, or as
+/// stored in a local file
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct PublishedValidatorList {
+    /// Master public key of the publisher, as hex
+    pub public_key: String,
+    /// Manifest of the publisher, as base64
+    pub manifest: String,
+    /// The signed validator list, as base64 of its JSON
+    pub blob: String,
+    /// Signature of the blob by the ephemeral key of the publisher, as hex
+    pub signature: String,
+    pub version: u32,
+}
+
+/// Content of the `blob` of a published validator list
+#[derive(serde::Deserialize)]
+struct ValidatorListBlob {
+    sequence: u32,
+    effective: Option<LedgerTimestamp>,
+    expiration: LedgerTimestamp,
+    validators: Vec<ValidatorJson>,
+}
+
+#[derive(serde::Deserialize)]
+struct ValidatorJson {
+    validation_public_key: String,
+    manifest: Option<String>,
+}
+
+/// Verified validator list, i.e. the validators trusted by a UNL
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ValidatorList {
+    /// Master public key of the publisher
+    pub publisher_key: Blob,
+    pub sequence: u32,
+    /// Time the list becomes valid, if given
+    pub effective: Option<LedgerTimestamp>,
+    /// Time the list expires
+    pub expiration: LedgerTimestamp,
+    /// Validators in the list whose master key is not revoked
+    pub validators: Vec<Validator>,
+    /// Validators in the list whose manifest revokes their master key. They are not trusted and
+    /// hence not part of `validators`
+    pub revoked: Vec<Validator>,
+}
+
+/// Validator in a validator list
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Validator {
+    /// Master public key of the validator
+    pub public_key: Blob,
+    /// Manifest of the validator, if included in the list
+    pub manifest: Option<Manifest>,
+}
+
+impl ValidatorList {
+    /// Returns true if the list is valid at the given time, i.e. it is effective and not expired
+    pub fn is_valid_at(&self, now: LedgerTimestamp) -> bool {
+        now < self.expiration && self.effective.is_none_or(|effective| now >= effective)
+    }
+
+    /// Minimum number of trusted validators that must agree, which is 80% of the validators
+    /// rounded up
+    pub fn quorum(&self) -> usize {
+        (self.validators.len() * 4).div_ceil(5)
+    }
+
+    /// Master public keys of the validators, encoded like `nHU...`
+    pub fn node_public_keys(&self) -> Vec<String> {
+        self.validators
+            .iter()
+            .filter_map(|validator| {
+                Some(encode_node_public_key(
+                    validator.public_key.0.as_slice().try_into().ok()?,
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Parses and verifies the JSON of a published validator list, see [`verify_validator_list`]
+pub fn verify_validator_list_json(
+    json: &str,
+    publisher_key: &PublicKey,
+    now: LedgerTimestamp,
+) -> Result<ValidatorList, BinaryCodecError> {
+    let published: PublishedValidatorList = serde_json::from_str(json).map_err(|err| {
+        BinaryCodecError::InvalidField(format!("invalid validator list: {}", err))
+    })?;
+    verify_validator_list(&published, publisher_key, now)
+}
+
+/// Verifies a published validator list (version 1). Checks that the publisher manifest is signed
+/// by the given trusted publisher key and is not revoked, that the blob is signed by the ephemeral
+/// key of the publisher, that the list is valid at the given time, and that the manifests of the
+/// validators are valid. Validators with a revocation manifest are returned in
+/// [`ValidatorList::revoked`].
+pub fn verify_validator_list(
+    published: &PublishedValidatorList,
+    publisher_key: &PublicKey,
+    now: LedgerTimestamp,
+) -> Result<ValidatorList, BinaryCodecError> {
+    if published.version != 1 {
+        return Err(BinaryCodecError::InvalidField(format!(
+            "unsupported validator list version {}",
+            published.version
+        )));
+    }
+    if !published
+        .public_key
+        .eq_ignore_ascii_case(&hex::encode(publisher_key.to_bytes()))
+    {
+        return Err(BinaryCodecError::InvalidKey(format!(
+            "validator list is published by {}, expected {}",
+            published.public_key,
+            hex::encode_upper(publisher_key.to_bytes())
+        )));
+    }
+
+    let publisher_manifest = manifest::decode_base64(&published.manifest)?;
+    manifest::verify_manifest(&publisher_manifest)?;
+    if publisher_manifest.public_key.0 != publisher_key.to_bytes() {
+        return Err(BinaryCodecError::InvalidKey(
+            "publisher manifest is not for the publisher key".to_string(),
+        ));
+    }
+    let Some(signing_pub_key) = publisher_manifest.signing_pub_key else {
+        return Err(BinaryCodecError::InvalidKey(
+            "publisher key is revoked".to_string(),
+        ));
+    };
+
+    let blob = decode_base64(&published.blob)?;
+    let signature = hex::decode(&published.signature)
+        .map_err(|err| BinaryCodecError::InvalidSignature(format!("invalid hex: {}", err)))?;
+    sign::verify_message_signature(
+        &blob,
+        &signature,
+        &PublicKey::from_bytes(&signing_pub_key.0)?,
+    )?;
+
+    let blob: ValidatorListBlob = serde_json::from_slice(&blob)
+        .map_err(|err| BinaryCodecError::InvalidField(format!("invalid blob: {}", err)))?;
+    let (revoked, validators) = blob
+        .validators
+        .into_iter()
+        .map(validator)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .partition(|validator| {
+            validator
+                .manifest
+                .as_ref()
+                .is_some_and(Manifest::is_revocation)
+        });
+    let list = ValidatorList {
+        publisher_key: publisher_manifest.public_key,
+        sequence: blob.sequence,
+        effective: blob.effective,
+        expiration: blob.expiration,
+        validators,
+        revoked,
+    };
+    if !list.is_valid_at(now) {
+        return Err(BinaryCodecError::OutOfRange(format!(
+            "validator list is only valid from {:?} until {}, time is {}",
+            list.effective, list.expiration, now
+        )));
+    }
+    Ok(list)
+}
+
+fn validator(json: ValidatorJson) -> Result<Validator, BinaryCodecError> {
+    let public_key = Blob(
+        hex::decode(&json.validation_public_key)
+            .map_err(|err| BinaryCodecError::InvalidKey(format!("invalid hex: {}", err)))?,
+    );
+    PublicKey::from_bytes(&public_key.0)?;
+    let manifest = match json.manifest {
+        Some(manifest) => {
+            let manifest = manifest::decode_base64(&manifest)?;
+            manifest::verify_manifest(&manifest)?;
+            if manifest.public_key != public_key {
+                return Err(BinaryCodecError::InvalidKey(format!(
+                    "manifest is not for validator {}",
+                    json.validation_public_key
+                )));
+            }
+            Some(manifest)
+        }
+        None => None,
+    };
+    Ok(Validator {
+        public_key,
+        manifest,
+    })
+}
+
+fn decode_base64(base64: &str) -> Result<Vec<u8>, BinaryCodecError> {
+    base64::engine::general_purpose::STANDARD
+        .decode(base64)
+        .map_err(|err| BinaryCodecError::InvalidField(format!("invalid base64: {}", err)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keypair::{Algorithm, Keypair};
+    use crate::manifest::{encode_base64, sign_manifest};
+    use assert_matches::assert_matches;
+
+    const PUBLISHER_SEED: &str = "sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r";
+    const PUBLISHER_SIGNING_SEED: &str = "sp5fghtJtpUorTwvof1NpDXAzNwf5";
+
+    fn signed_manifest(master: &Keypair, signing: &Keypair) -> Manifest {
+        let mut manifest = Manifest {
+            public_key: master.public_key.to_blob(),
+            sequence: 1,
+            signing_pub_key: Some(signing.public_key.to_blob()),
+            signature: None,
+            master_signature: Blob(Vec::new()),
+            domain: None,
+            version: None,
+        };
+        sign_manifest(&mut manifest, &master.secret_key, Some(&signing.secret_key)).unwrap();
+        manifest
+    }
+
+    fn validator_keypair(index: u8) -> Keypair {
+        Keypair::derive(&[index; 16], Algorithm::Ed25519).unwrap()
+    }
+
+    fn revocation_manifest(master: &Keypair) -> Manifest {
+        let mut manifest = Manifest {
+            public_key: master.public_key.to_blob(),
+            sequence: Manifest::REVOCATION_SEQUENCE,
+            signing_pub_key: None,
+            signature: None,
+            master_signature: Blob(Vec::new()),
+            domain: None,
+            version: None,
+        };
+        sign_manifest(&mut manifest, &master.secret_key, None).unwrap();
+        manifest
+    }
+
+    fn published_list(expiration: LedgerTimestamp) -> PublishedValidatorList {
+        published_list_with_revoked(expiration, &[])
+    }
+
+    /// Published list of five validators, of which the validators with the given indexes have
+    /// revoked their master key
+    fn published_list_with_revoked(
+        expiration: LedgerTimestamp,
+        revoked: &[u8],
+    ) -> PublishedValidatorList {
+        let publisher = Keypair::from_seed(PUBLISHER_SEED).unwrap();
+        let publisher_signing = Keypair::from_seed(PUBLISHER_SIGNING_SEED).unwrap();
+
+        let validators: Vec<_> = (1..=5)
+            .map(|index| {
+                let master = validator_keypair(index);
+                let manifest = if revoked.contains(&index) {
+                    revocation_manifest(&master)
+                } else {
+                    signed_manifest(&master, &validator_keypair(index + 100))
+                };
+                serde_json::json!({
+                    "validation_public_key": hex::encode_upper(master.public_key.to_bytes()),
+                    "manifest": encode_base64(&manifest).unwrap(),
+                })
+            })
+            .collect();
+        let blob = serde_json::json!({
+            "sequence": 7,
+            "expiration": expiration,
+            "validators": validators,
+        })
+        .to_string();
+
+        PublishedValidatorList {
+            public_key: hex::encode_upper(publisher.public_key.to_bytes()),
+            manifest: encode_base64(&signed_manifest(&publisher, &publisher_signing)).unwrap(),
+            blob: base64::engine::general_purpose::STANDARD.encode(&blob),
+            signature: hex::encode_upper(
+                sign::message_signature(blob.as_bytes(), &publisher_signing.secret_key).0,
+            ),
+            version: 1,
+        }
+    }
+
+    fn publisher_key() -> PublicKey {
+        Keypair::from_seed(PUBLISHER_SEED).unwrap().public_key
+    }
+
+    #[test]
+    fn test_verify_validator_list() {
+        let published = published_list(800_000_000);
+        let list = verify_validator_list(&published, &publisher_key(), 700_000_000).unwrap();
+        assert_eq!(list.sequence, 7);
+        assert_eq!(list.expiration, 800_000_000);
+        assert_eq!(list.validators.len(), 5);
+        assert!(list.revoked.is_empty());
+        assert_eq!(list.quorum(), 4);
+        assert!(list
+            .validators
+            .iter()
+            .all(|validator| validator.manifest.is_some()));
+
+        let node_public_keys = list.node_public_keys();
+        assert_eq!(node_public_keys.len(), 5);
+        assert!(node_public_keys.iter().all(|key| key.starts_with('n')));
+        assert_eq!(
+            node_public_keys[0],
+            encode_node_public_key(&validator_keypair(1).public_key.to_bytes())
+        );
+    }
+
+    #[test]
+    fn test_verify_validator_list_json() {
+        let published = published_list(800_000_000);
+        let json = serde_json::json!({
+            "public_key": published.public_key,
+            "manifest": published.manifest,
+            "blob": published.blob,
+            "signature": published.signature,
+            "version": 1,
+        })
+        .to_string();
+        let list = verify_validator_list_json(&json, &publisher_key(), 700_000_000).unwrap();
+        assert_eq!(list.validators.len(), 5);
+    }
+
+    #[test]
+    fn test_verify_validator_list_expired() {
+        let published = published_list(800_000_000);
+        let result = verify_validator_list(&published, &publisher_key(), 800_000_000);
+        assert_matches!(result, Err(BinaryCodecError::OutOfRange(message)) => {
+            assert!(message.contains("only valid"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_verify_validator_list_revoked_validators() {
+        let published = published_list_with_revoked(800_000_000, &[4, 5]);
+        let list = verify_validator_list(&published, &publisher_key(), 700_000_000).unwrap();
+        assert_eq!(list.validators.len(), 3);
+        assert_eq!(list.quorum(), 3);
+        assert_eq!(
+            list.revoked
+                .iter()
+                .map(|validator| validator.public_key.clone())
+                .collect::<Vec<_>>(),
+            [
+                validator_keypair(4).public_key.to_blob(),
+                validator_keypair(5).public_key.to_blob()
+            ]
+        );
+        assert!(!list.node_public_keys().contains(&encode_node_public_key(
+            &validator_keypair(4).public_key.to_bytes()
+        )));
+    }
+
+    #[test]
+    fn test_is_valid_at() {
+        let list = ValidatorList {
+            publisher_key: Blob(Vec::new()),
+            sequence: 1,
+            effective: Some(600),
+            expiration: 800,
+            validators: Vec::new(),
+            revoked: Vec::new(),
+        };
+        assert!(!list.is_valid_at(599));
+        assert!(list.is_valid_at(600));
+        assert!(list.is_valid_at(799));
+        assert!(!list.is_valid_at(800));
+    }
+
+    #[test]
+    fn test_verify_validator_list_tampered_blob() {
+        let mut published = published_list(800_000_000);
+        let blob = decode_base64(&published.blob).unwrap();
+        let tampered = String::from_utf8(blob)
+            .unwrap()
+            .replace("\"sequence\":7", "\"sequence\":8");
+        published.blob = base64::engine::general_purpose::STANDARD.encode(tampered);
+        let result = verify_validator_list(&published, &publisher_key(), 700_000_000);
+        assert_matches!(result, Err(BinaryCodecError::InvalidSignature(_)));
+    }
+
+    #[test]
+    fn test_verify_validator_list_untrusted_publisher() {
+        let published = published_list(800_000_000);
+        let other_key = validator_keypair(1).public_key;
+        let result = verify_validator_list(&published, &other_key, 700_000_000);
+        assert_matches!(result, Err(BinaryCodecError::InvalidKey(message)) => {
+            assert!(message.contains("published by"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_quorum() {
+        let validator = Validator {
+            public_key: Blob(Vec::new()),
+            manifest: None,
+        };
+        for (count, quorum) in [(1, 1), (5, 4), (6, 5), (35, 28)] {
+            let list = ValidatorList {
+                publisher_key: Blob(Vec::new()),
+                sequence: 1,
+                effective: None,
+                expiration: 0,
+                validators: vec![validator.clone(); count],
+                revoked: Vec::new(),
+            };
+            assert_eq!(list.quorum(), quorum);
+        }
+    }
+}
@@ -3,7 +3,10 @@ pub mod list_offers;
 pub mod offer_info;
 pub mod remove_offer;
 
+use anyhow::bail;
 use clap::ArgMatches;
+use xrpl_binary_codec::keypair::{Keypair, PublicKey, SecretKey};
+use xrpl_types::AccountId;
 
 use self::create_offer::create_offer;
 use self::list_offers::list_offers;
@@ -18,26 +21,48 @@ pub async fn account_offers(
         .expect("missing account id");
 
     if let Some(create_offer_matches) = offers_matches.subcommand_matches("create") {
-        let public_key: &String = account_matches
-            .get_one("PUBLIC_KEY")
-            .expect("missing public key");
-        let secret_key: &String = account_matches
-            .get_one("SECRET_KEY")
-            .expect("missing secret key");
-
-        create_offer(account, public_key, secret_key, create_offer_matches).await?;
+        let keypair = keypair(account, account_matches)?;
+
+        create_offer(account, &keypair, create_offer_matches).await?;
     } else if let Some(remove_offer_matches) = offers_matches.subcommand_matches("remove") {
-        let public_key: &String = account_matches
-            .get_one("PUBLIC_KEY")
-            .expect("missing public key");
-        let secret_key: &String = account_matches
-            .get_one("SECRET_KEY")
-            .expect("missing secret key");
-
-        remove_offer(account, public_key, secret_key, remove_offer_matches).await?;
+        let keypair = keypair(account, account_matches)?;
+
+        remove_offer(account, &keypair, remove_offer_matches).await?;
     } else if let Some(list_offers_matches) = offers_matches.subcommand_matches("list") {
         list_offers(account, list_offers_matches).await?;
     }
 
     Ok(())
 }
+
+/// Key pair used to sign transactions of the account, derived from `--seed` or given by
+/// `--public-key` and `--secret-key`
+fn keypair(account: &str, account_matches: &ArgMatches) -> anyhow::Result<Keypair> {
+    if let Some(seed) = account_matches.get_one::<String>("SEED") {
+        let keypair = Keypair::from_seed(seed)?;
+        if keypair.account_id() != AccountId::from_address(account)? {
+            bail!(
+                "seed derives to account {}, not {}",
+                keypair.account_id().to_address(),
+                account
+            );
+        }
+        return Ok(keypair);
+    }
+
+    // #insight
+    // The secret/private key is 32 bytes (or 33 bytes prefixed with 0x00 for secp256k1 and
+    // 0xED for ed25519), the public key is 33 bytes.
+
+    let public_key: &String = account_matches
+        .get_one("PUBLIC_KEY")
+        .expect("missing public key");
+    let secret_key: &String = account_matches
+        .get_one("SECRET_KEY")
+        .expect("missing secret key");
+
+    Ok(Keypair {
+        public_key: PublicKey::from_hex(public_key)?,
+        secret_key: SecretKey::from_hex(secret_key)?,
+    })
+}
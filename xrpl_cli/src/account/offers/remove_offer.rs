@@ -1,16 +1,15 @@
 use clap::ArgMatches;
-use libsecp256k1::{PublicKey, SecretKey};
 
+use xrpl_binary_codec::keypair::Keypair;
 use xrpl_binary_codec::{serialize, sign};
 use xrpl_http_client::{Client, SubmitRequest};
 use xrpl_types::{AccountId, OfferCancelTransaction, TransactionTrait};
 
-// xrpl account <ADDRESS> --public-key="..." --secret-key="..." offers remove <OFFER_SEQUENCE>
+// xrpl account <ADDRESS> --seed="..." offers remove <OFFER_SEQUENCE>
 
 pub async fn remove_offer(
     account: impl AsRef<str>,
-    public_key: impl AsRef<str>,
-    secret_key: impl AsRef<str>,
+    keypair: &Keypair,
     remove_offer_matches: &ArgMatches,
 ) -> anyhow::Result<()> {
     let account = account.as_ref();
@@ -26,14 +25,7 @@ pub async fn remove_offer(
 
     client.prepare_transaction(tx.common_mut()).await?;
 
-    // #insight
-    // The secret/private key is 32 bytes, the public key is 33 bytes.
-
-    let secret_key = SecretKey::parse_slice(&hex::decode(secret_key.as_ref())?)?;
-    let public_key =
-        PublicKey::parse_compressed(&hex::decode(public_key.as_ref())?.as_slice().try_into()?)?;
-
-    sign::sign_transaction(&mut tx, &public_key, &secret_key)?;
+    sign::sign_canonical_transaction(&mut tx, &keypair.public_key, &keypair.secret_key)?;
 
     let tx_blob = serialize::serialize(&tx)?;
 
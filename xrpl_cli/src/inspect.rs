@@ -0,0 +1,12 @@
+use clap::ArgMatches;
+use xrpl_binary_codec::deserializer::inspect::inspect;
+
+/// Prints the fields of a hex encoded binary blob, with byte offsets
+pub fn inspect_blob(inspect_matches: &ArgMatches) -> anyhow::Result<()> {
+    let blob: &String = inspect_matches.get_one("BLOB").unwrap();
+    let bytes = hex::decode(blob.trim())?;
+
+    print!("{}", inspect(&bytes));
+
+    Ok(())
+}
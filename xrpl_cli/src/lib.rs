@@ -1,3 +1,4 @@
 pub mod account;
 pub mod fmt;
+pub mod inspect;
 pub mod ledger;
@@ -5,6 +5,7 @@ use xrpl_cli::account::{
     balances::account_balances, info::account_info, offers::account_offers,
     trustlines::account_trustlines,
 };
+use xrpl_cli::inspect::inspect_blob;
 use xrpl_cli::ledger::closed::ledger_closed;
 
 // #TODO also used WebSocket
@@ -43,6 +44,14 @@ async fn main() -> anyhow::Result<()> {
                 .required(false)
                 .action(ArgAction::Set),
         )
+        .arg(
+            Arg::new("SEED")
+                .help("The secret seed of the account, used instead of the public and secret key")
+                .long("seed")
+                .required(false)
+                .conflicts_with_all(["PUBLIC_KEY", "SECRET_KEY"])
+                .action(ArgAction::Set),
+        )
         .subcommand(
             Command::new("info")
                 .about("info")
@@ -203,6 +212,18 @@ async fn main() -> anyhow::Result<()> {
                 .action(ArgAction::SetTrue),
         );
 
+    // `inspect` subcommand.
+
+    let inspect_cmd = Command::new("inspect")
+        .about("Inspect the fields of a binary transaction or ledger object")
+        .arg(
+            // Positional argument.
+            Arg::new("BLOB")
+                .help("The hex encoded binary blob")
+                .required(true)
+                .index(1),
+        );
+
     let mut xrpl_cmd = Command::new("xrpl")
         .author("Georgios Moschovitis, george.moschovitis@gmail.com")
         .version(VERSION)
@@ -211,7 +232,8 @@ async fn main() -> anyhow::Result<()> {
             "The xrpl CLI provides access to ledger and account data on the XRP Ledger and allows for signing transactions.",
         )
         .subcommand(account_cmd)
-        .subcommand(ledger_cmd);
+        .subcommand(ledger_cmd)
+        .subcommand(inspect_cmd);
 
     let matches = xrpl_cmd.get_matches_mut();
 
@@ -228,6 +250,8 @@ async fn main() -> anyhow::Result<()> {
     } else if let Some(ledger_matches) = matches.subcommand_matches("ledger") {
         // #TODO properly handle this
         ledger_closed(ledger_matches).await?;
+    } else if let Some(inspect_matches) = matches.subcommand_matches("inspect") {
+        inspect_blob(inspect_matches)?;
     } else {
         xrpl_cmd.print_long_help().unwrap();
     }
@@ -1,4 +1,9 @@
-use crate::{AccountId, Amount, Blob, Hash128, Hash160, Hash256, UInt16, UInt32, UInt64, UInt8};
+use crate::{
+    AccountId, Amount, Blob, Currency, FieldValue, Hash128, Hash160, Hash256, PathSet, UInt16,
+    UInt32, UInt64, UInt8, XChainBridge,
+};
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::fmt;
 use core::fmt::Display;
 
@@ -50,6 +55,17 @@ pub trait Visitor {
         field_name: &str,
         array_deserializer: AD,
     ) -> Result<(), E>;
+
+    /// Visit inner object that is a field itself (and not an element in an array).
+    /// The object can be deserialized by passing the given deserializer to [`Deserialize::deserialize`].
+    /// By default, inner objects are not expected.
+    fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+        &mut self,
+        field_name: &str,
+        _deserializer: D,
+    ) -> Result<(), E> {
+        Err(E::unexpected_field(field_name))
+    }
 }
 
 /// Access to field value
@@ -75,6 +91,18 @@ pub trait FieldAccessor {
     fn deserialize_uint32(self) -> Result<UInt32, Self::Error>;
 
     fn deserialize_uint64(self) -> Result<UInt64, Self::Error>;
+
+    fn deserialize_path_set(self) -> Result<PathSet, Self::Error>;
+
+    fn deserialize_vector256(self) -> Result<Vec<Hash256>, Self::Error>;
+
+    fn deserialize_issue(self) -> Result<Currency, Self::Error>;
+
+    fn deserialize_xchain_bridge(self) -> Result<XChainBridge, Self::Error>;
+
+    /// Deserialize field value of whatever type the field has, for fields that are not
+    /// inner objects or arrays
+    fn deserialize_any(self) -> Result<FieldValue, Self::Error>;
 }
 
 /// Deserialization of array elements
@@ -87,4 +115,10 @@ pub trait ArrayDeserializer {
         &mut self,
         field_name: &str,
     ) -> Result<Option<T>, Self::Error>;
+
+    /// Deserializes a single object in the array, whatever field name the element has.
+    /// Returns the field name together with the object, or `None` at the end of the array.
+    fn deserialize_any_object<T: Deserialize>(
+        &mut self,
+    ) -> Result<Option<(String, T)>, Self::Error>;
 }
@@ -5,14 +5,13 @@
 extern crate alloc;
 extern crate core;
 
+/// Defines traits for deserialization
+pub mod deserialize;
 mod error;
 /// Defines traits for serialization
 pub mod serialize;
-/// Defines traits for deserialization
-pub mod deserialize;
 /// Types in internal canonical binary format <https://xrpl.org/serialization.html#type-list>
 pub mod types;
 
-
 pub use error::*;
 pub use types::*;
@@ -1,6 +1,9 @@
+use crate::{
+    AccountId, Amount, Blob, Currency, Hash128, Hash160, Hash256, Object, PathSet, UInt16, UInt32,
+    UInt64, UInt8, XChainBridge,
+};
 use core::fmt;
 use core::fmt::Display;
-use crate::{AccountId, Amount, Blob, Hash128, Hash160, Hash256, UInt16, UInt32, UInt64, UInt8};
 
 pub trait SerError: fmt::Debug + fmt::Display + Sized {
     fn unimplemented(msg: impl Display) -> Self;
@@ -12,6 +15,11 @@ pub trait Serialize {
     fn serialize<S: Serializer>(&self, serializer: &mut S) -> Result<(), S::Error>;
 }
 
+/// Marker for objects that serialize their fields in canonical field order
+/// <https://xrpl.org/serialization.html#canonical-field-order>, including the fields of inner objects.
+/// Such objects can be serialized in a single pass, without sorting the fields afterwards
+pub trait CanonicalSerialize: Serialize {}
+
 /// Serialize for XRPL types and objects
 pub trait Serializer {
     type Error: SerError;
@@ -43,10 +51,49 @@ pub trait Serializer {
 
     fn serialize_uint64(&mut self, field_name: &str, uint64: UInt64) -> Result<(), Self::Error>;
 
+    fn serialize_path_set(
+        &mut self,
+        field_name: &str,
+        path_set: &PathSet,
+    ) -> Result<(), Self::Error>;
+
+    fn serialize_vector256(
+        &mut self,
+        field_name: &str,
+        vector256: &[Hash256],
+    ) -> Result<(), Self::Error>;
+
+    fn serialize_issue(&mut self, field_name: &str, issue: Currency) -> Result<(), Self::Error>;
+
+    fn serialize_xchain_bridge(
+        &mut self,
+        field_name: &str,
+        xchain_bridge: &XChainBridge,
+    ) -> Result<(), Self::Error>;
+
     fn serialize_array(
         &mut self,
         field_name: &str,
     ) -> Result<Self::ArraySerializer<'_>, Self::Error>;
+
+    /// Serialize inner object that is a field itself (and not an element in an array)
+    fn serialize_object<T: Serialize>(
+        &mut self,
+        field_name: &str,
+        object: &T,
+    ) -> Result<(), Self::Error>;
+
+    /// Serialize fields that are not modelled by the serialized object, and that hence may sort
+    /// anywhere between the other fields of the object in canonical field order
+    /// <https://xrpl.org/serialization.html#canonical-field-order>. Objects implementing
+    /// [`CanonicalSerialize`] must call this before serializing their other fields, such that the
+    /// serializer can place the fields in canonical order
+    fn serialize_unordered_fields(&mut self, fields: &Object) -> Result<(), Self::Error>
+    where
+        Self: Sized,
+    {
+        fields.serialize(self)
+    }
 }
 
 pub trait ArraySerializer {
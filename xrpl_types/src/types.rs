@@ -1,13 +1,27 @@
 mod amount;
 mod currency;
 mod currency_code;
+mod flags;
+mod ledger_entry;
+mod ledger_header;
 mod ledger_timestamp;
+mod manifest;
+mod object;
+mod path;
 mod primitive;
 mod transaction;
+mod xchain_bridge;
 
 pub use amount::*;
 pub use currency::*;
 pub use currency_code::*;
+pub use flags::*;
+pub use ledger_entry::*;
+pub use ledger_header::*;
 pub use ledger_timestamp::*;
+pub use manifest::*;
+pub use object::*;
+pub use path::*;
 pub use primitive::*;
 pub use transaction::*;
+pub use xchain_bridge::*;
@@ -1,7 +1,11 @@
 use crate::alloc::{format, string::ToString};
 use crate::Error;
 use ascii::{AsciiChar, AsciiStr, AsciiString};
-use core::{fmt, fmt::{Debug, Display, Formatter}, str::FromStr};
+use core::{
+    fmt,
+    fmt::{Debug, Display, Formatter},
+    str::FromStr,
+};
 
 /// Currency code <https://xrpl.org/currency-formats.html#currency-codes>
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
@@ -171,8 +175,8 @@ fn to_3_ascii_chars(str: &str) -> Result<[AsciiChar; 3], Error> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use assert_matches::assert_matches;
     use alloc::str::FromStr;
+    use assert_matches::assert_matches;
 
     #[test]
     fn test_non_standard_code_from_bytes() {
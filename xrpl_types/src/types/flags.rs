@@ -0,0 +1,120 @@
+use crate::UInt32;
+use core::fmt;
+use core::marker::PhantomData;
+use enumflags2::{BitFlag, BitFlags};
+
+/// Value of a `Flags` field <https://xrpl.org/transaction-common-fields.html#flags-field>.
+/// Holds all bits of the field, also bits not defined by `T`, such that
+/// serializing gives the same value as was deserialized.
+pub struct Flags<T> {
+    bits: UInt32,
+    flag_type: PhantomData<T>,
+}
+
+impl<T: BitFlag<Numeric = u32>> Flags<T> {
+    pub fn from_bits(bits: UInt32) -> Self {
+        Self {
+            bits,
+            flag_type: PhantomData,
+        }
+    }
+
+    /// All bits of the field
+    pub fn bits(&self) -> UInt32 {
+        self.bits
+    }
+
+    /// The flags defined by `T`
+    pub fn flags(&self) -> BitFlags<T> {
+        BitFlags::from_bits_truncate(self.bits)
+    }
+
+    /// Bits not defined by `T`
+    pub fn unknown_bits(&self) -> UInt32 {
+        self.bits & !BitFlags::<T>::all().bits()
+    }
+
+    pub fn contains(&self, flags: impl Into<BitFlags<T>>) -> bool {
+        self.flags().contains(flags)
+    }
+
+    pub fn insert(&mut self, flags: impl Into<BitFlags<T>>) {
+        self.bits |= flags.into().bits();
+    }
+
+    pub fn remove(&mut self, flags: impl Into<BitFlags<T>>) {
+        self.bits &= !flags.into().bits();
+    }
+}
+
+impl<T: BitFlag<Numeric = u32>> From<BitFlags<T>> for Flags<T> {
+    fn from(flags: BitFlags<T>) -> Self {
+        Self::from_bits(flags.bits())
+    }
+}
+
+impl<T: BitFlag<Numeric = u32>> From<T> for Flags<T> {
+    fn from(flag: T) -> Self {
+        Self::from(BitFlags::from(flag))
+    }
+}
+
+impl<T> Default for Flags<T> {
+    fn default() -> Self {
+        Self {
+            bits: 0,
+            flag_type: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for Flags<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Flags<T> {}
+
+impl<T> PartialEq for Flags<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits
+    }
+}
+
+impl<T> Eq for Flags<T> {}
+
+impl<T: BitFlag<Numeric = u32> + fmt::Debug> fmt::Debug for Flags<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Flags")
+            .field("flags", &self.flags())
+            .field("unknown_bits", &self.unknown_bits())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use enumflags2::bitflags;
+
+    #[bitflags]
+    #[repr(u32)]
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    enum TestFlags {
+        A = 0x0000_0001,
+        B = 0x0001_0000,
+    }
+
+    #[test]
+    fn test_unknown_bits_kept() {
+        let mut flags = Flags::<TestFlags>::from_bits(0x8001_0002);
+        assert_eq!(flags.flags(), TestFlags::B);
+        assert_eq!(flags.unknown_bits(), 0x8000_0002);
+
+        flags.insert(TestFlags::A);
+        flags.remove(TestFlags::B);
+        assert!(flags.contains(TestFlags::A));
+        assert_eq!(flags.bits(), 0x8000_0003);
+    }
+}
@@ -0,0 +1,295 @@
+use super::{check_ledger_entry_type, drops_amount};
+use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
+use crate::serialize::{Serialize, Serializer};
+use crate::{
+    deserialize, AccountId, Amount, Blob, DropsAmount, Flags, Hash128, Hash256, LedgerEntryType,
+    Object, UInt32, UInt8,
+};
+use enumflags2::bitflags;
+
+/// An `AccountRoot` ledger entry <https://xrpl.org/accountroot.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AccountRoot {
+    pub account: AccountId,
+    pub account_txn_id: Option<Hash256>,
+    /// Set for the special account of an AMM
+    pub amm_id: Option<Hash256>,
+    pub balance: DropsAmount,
+    pub burned_nftokens: Option<UInt32>,
+    pub domain: Option<Blob>,
+    pub email_hash: Option<Hash128>,
+    pub first_nftoken_sequence: Option<UInt32>,
+    pub flags: Flags<AccountRootFlags>,
+    pub message_key: Option<Blob>,
+    pub minted_nftokens: Option<UInt32>,
+    pub nftoken_minter: Option<AccountId>,
+    pub owner_count: UInt32,
+    pub previous_txn_id: Hash256,
+    pub previous_txn_lgr_seq: UInt32,
+    pub regular_key: Option<AccountId>,
+    pub sequence: UInt32,
+    pub ticket_count: Option<UInt32>,
+    pub tick_size: Option<UInt8>,
+    pub transfer_rate: Option<UInt32>,
+    pub wallet_locator: Option<Hash256>,
+    pub wallet_size: Option<UInt32>,
+    /// Fields not modelled by this type, see [`Object`]
+    pub unknown_fields: Object,
+}
+
+/// `AccountRoot` flags <https://xrpl.org/accountroot.html#accountroot-flags>
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AccountRootFlags {
+    PasswordSpent = 0x00010000,
+    RequireDestTag = 0x00020000,
+    RequireAuth = 0x00040000,
+    DisallowXRP = 0x00080000,
+    DisableMaster = 0x00100000,
+    NoFreeze = 0x00200000,
+    GlobalFreeze = 0x00400000,
+    DefaultRipple = 0x00800000,
+    DepositAuth = 0x01000000,
+    AMM = 0x02000000,
+    DisallowIncomingNFTokenOffer = 0x04000000,
+    DisallowIncomingCheck = 0x08000000,
+    DisallowIncomingPayChan = 0x10000000,
+    DisallowIncomingTrustline = 0x20000000,
+    AllowTrustLineClawback = 0x80000000,
+}
+
+impl Serialize for AccountRoot {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("LedgerEntryType", LedgerEntryType::AccountRoot as u16)?;
+        s.serialize_account_id("Account", self.account)?;
+        if let Some(account_txn_id) = self.account_txn_id {
+            s.serialize_hash256("AccountTxnID", account_txn_id)?;
+        }
+        if let Some(amm_id) = self.amm_id {
+            s.serialize_hash256("AMMID", amm_id)?;
+        }
+        s.serialize_amount("Balance", Amount::Drops(self.balance))?;
+        if let Some(burned_nftokens) = self.burned_nftokens {
+            s.serialize_uint32("BurnedNFTokens", burned_nftokens)?;
+        }
+        if let Some(domain) = &self.domain {
+            s.serialize_blob("Domain", domain)?;
+        }
+        if let Some(email_hash) = self.email_hash {
+            s.serialize_hash128("EmailHash", email_hash)?;
+        }
+        if let Some(first_nftoken_sequence) = self.first_nftoken_sequence {
+            s.serialize_uint32("FirstNFTokenSequence", first_nftoken_sequence)?;
+        }
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        if let Some(message_key) = &self.message_key {
+            s.serialize_blob("MessageKey", message_key)?;
+        }
+        if let Some(minted_nftokens) = self.minted_nftokens {
+            s.serialize_uint32("MintedNFTokens", minted_nftokens)?;
+        }
+        if let Some(nftoken_minter) = self.nftoken_minter {
+            s.serialize_account_id("NFTokenMinter", nftoken_minter)?;
+        }
+        s.serialize_uint32("OwnerCount", self.owner_count)?;
+        s.serialize_hash256("PreviousTxnID", self.previous_txn_id)?;
+        s.serialize_uint32("PreviousTxnLgrSeq", self.previous_txn_lgr_seq)?;
+        if let Some(regular_key) = self.regular_key {
+            s.serialize_account_id("RegularKey", regular_key)?;
+        }
+        s.serialize_uint32("Sequence", self.sequence)?;
+        if let Some(ticket_count) = self.ticket_count {
+            s.serialize_uint32("TicketCount", ticket_count)?;
+        }
+        if let Some(tick_size) = self.tick_size {
+            s.serialize_uint8("TickSize", tick_size)?;
+        }
+        if let Some(transfer_rate) = self.transfer_rate {
+            s.serialize_uint32("TransferRate", transfer_rate)?;
+        }
+        if let Some(wallet_locator) = self.wallet_locator {
+            s.serialize_hash256("WalletLocator", wallet_locator)?;
+        }
+        if let Some(wallet_size) = self.wallet_size {
+            s.serialize_uint32("WalletSize", wallet_size)?;
+        }
+        self.unknown_fields.serialize(s)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for AccountRoot {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            account: Option<AccountId>,
+            account_txn_id: Option<Hash256>,
+            amm_id: Option<Hash256>,
+            balance: Option<DropsAmount>,
+            burned_nftokens: Option<UInt32>,
+            domain: Option<Blob>,
+            email_hash: Option<Hash128>,
+            first_nftoken_sequence: Option<UInt32>,
+            flags: Flags<AccountRootFlags>,
+            message_key: Option<Blob>,
+            minted_nftokens: Option<UInt32>,
+            nftoken_minter: Option<AccountId>,
+            owner_count: Option<UInt32>,
+            previous_txn_id: Option<Hash256>,
+            previous_txn_lgr_seq: Option<UInt32>,
+            regular_key: Option<AccountId>,
+            sequence: Option<UInt32>,
+            ticket_count: Option<UInt32>,
+            tick_size: Option<UInt8>,
+            transfer_rate: Option<UInt32>,
+            wallet_locator: Option<Hash256>,
+            wallet_size: Option<UInt32>,
+            unknown_fields: Object,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "LedgerEntryType" => {
+                        check_ledger_entry_type(field_accessor, LedgerEntryType::AccountRoot)?;
+                    }
+                    "Account" => {
+                        self.account = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    "AccountTxnID" => {
+                        self.account_txn_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "AMMID" => {
+                        self.amm_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "Balance" => {
+                        self.balance = Some(drops_amount(
+                            field_name,
+                            field_accessor.deserialize_amount()?,
+                        )?);
+                    }
+                    "BurnedNFTokens" => {
+                        self.burned_nftokens = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "Domain" => {
+                        self.domain = Some(field_accessor.deserialize_blob()?);
+                    }
+                    "EmailHash" => {
+                        self.email_hash = Some(field_accessor.deserialize_hash128()?);
+                    }
+                    "FirstNFTokenSequence" => {
+                        self.first_nftoken_sequence = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "Flags" => {
+                        self.flags = Flags::from_bits(field_accessor.deserialize_uint32()?);
+                    }
+                    "MessageKey" => {
+                        self.message_key = Some(field_accessor.deserialize_blob()?);
+                    }
+                    "MintedNFTokens" => {
+                        self.minted_nftokens = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "NFTokenMinter" => {
+                        self.nftoken_minter = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    "OwnerCount" => {
+                        self.owner_count = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "PreviousTxnID" => {
+                        self.previous_txn_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "PreviousTxnLgrSeq" => {
+                        self.previous_txn_lgr_seq = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "RegularKey" => {
+                        self.regular_key = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    "Sequence" => {
+                        self.sequence = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "TicketCount" => {
+                        self.ticket_count = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "TickSize" => {
+                        self.tick_size = Some(field_accessor.deserialize_uint8()?);
+                    }
+                    "TransferRate" => {
+                        self.transfer_rate = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "WalletLocator" => {
+                        self.wallet_locator = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "WalletSize" => {
+                        self.wallet_size = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_field(field_name, field_accessor)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                array_deserializer: AD,
+            ) -> Result<(), E> {
+                self.unknown_fields
+                    .visit_array(field_name, array_deserializer)
+            }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.unknown_fields.visit_object(field_name, deserializer)
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(AccountRoot {
+            account: S::Error::unwrap_field_value("Account", visitor.account)?,
+            account_txn_id: visitor.account_txn_id,
+            amm_id: visitor.amm_id,
+            balance: S::Error::unwrap_field_value("Balance", visitor.balance)?,
+            burned_nftokens: visitor.burned_nftokens,
+            domain: visitor.domain,
+            email_hash: visitor.email_hash,
+            first_nftoken_sequence: visitor.first_nftoken_sequence,
+            flags: visitor.flags,
+            message_key: visitor.message_key,
+            minted_nftokens: visitor.minted_nftokens,
+            nftoken_minter: visitor.nftoken_minter,
+            owner_count: S::Error::unwrap_field_value("OwnerCount", visitor.owner_count)?,
+            previous_txn_id: S::Error::unwrap_field_value(
+                "PreviousTxnID",
+                visitor.previous_txn_id,
+            )?,
+            previous_txn_lgr_seq: S::Error::unwrap_field_value(
+                "PreviousTxnLgrSeq",
+                visitor.previous_txn_lgr_seq,
+            )?,
+            regular_key: visitor.regular_key,
+            sequence: S::Error::unwrap_field_value("Sequence", visitor.sequence)?,
+            ticket_count: visitor.ticket_count,
+            tick_size: visitor.tick_size,
+            transfer_rate: visitor.transfer_rate,
+            wallet_locator: visitor.wallet_locator,
+            wallet_size: visitor.wallet_size,
+            unknown_fields: visitor.unknown_fields,
+        })
+    }
+}
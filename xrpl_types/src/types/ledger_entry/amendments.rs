@@ -0,0 +1,200 @@
+use super::check_ledger_entry_type;
+use crate::alloc::vec::Vec;
+use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
+use crate::serialize::{ArraySerializer, Serialize, Serializer};
+use crate::{deserialize, Hash256, LedgerEntryType, Object, UInt32};
+
+/// The `Amendments` ledger entry, a singleton holding the status of amendments
+/// <https://xrpl.org/amendments-object.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Amendments {
+    /// Enabled amendments
+    pub amendments: Vec<Hash256>,
+    pub flags: UInt32,
+    /// Amendments that currently have majority support, but are not enabled yet
+    pub majorities: Vec<Majority>,
+    pub previous_txn_id: Option<Hash256>,
+    pub previous_txn_lgr_seq: Option<UInt32>,
+    /// Fields not modelled by this type, see [`Object`]
+    pub unknown_fields: Object,
+}
+
+/// Amendment with majority support <https://xrpl.org/amendments-object.html#amendments-fields>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Majority {
+    pub amendment: Hash256,
+    /// Time the amendment gained majority support
+    pub close_time: UInt32,
+}
+
+impl Serialize for Amendments {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("LedgerEntryType", LedgerEntryType::Amendments as u16)?;
+        if !self.amendments.is_empty() {
+            s.serialize_vector256("Amendments", &self.amendments)?;
+        }
+        s.serialize_uint32("Flags", self.flags)?;
+        if !self.majorities.is_empty() {
+            let mut array = s.serialize_array("Majorities")?;
+            for majority in &self.majorities {
+                array.serialize_object("Majority", majority)?;
+            }
+            array.end()?;
+        }
+        if let Some(previous_txn_id) = self.previous_txn_id {
+            s.serialize_hash256("PreviousTxnID", previous_txn_id)?;
+        }
+        if let Some(previous_txn_lgr_seq) = self.previous_txn_lgr_seq {
+            s.serialize_uint32("PreviousTxnLgrSeq", previous_txn_lgr_seq)?;
+        }
+        self.unknown_fields.serialize(s)?;
+        Ok(())
+    }
+}
+
+impl Serialize for Majority {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_hash256("Amendment", self.amendment)?;
+        s.serialize_uint32("CloseTime", self.close_time)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for Amendments {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            amendments: Vec<Hash256>,
+            flags: UInt32,
+            majorities: Vec<Majority>,
+            previous_txn_id: Option<Hash256>,
+            previous_txn_lgr_seq: Option<UInt32>,
+            unknown_fields: Object,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "LedgerEntryType" => {
+                        check_ledger_entry_type(field_accessor, LedgerEntryType::Amendments)?;
+                    }
+                    "Amendments" => {
+                        self.amendments = field_accessor.deserialize_vector256()?;
+                    }
+                    "Flags" => {
+                        self.flags = field_accessor.deserialize_uint32()?;
+                    }
+                    "PreviousTxnID" => {
+                        self.previous_txn_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "PreviousTxnLgrSeq" => {
+                        self.previous_txn_lgr_seq = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_field(field_name, field_accessor)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                mut array_deserializer: AD,
+            ) -> Result<(), E> {
+                match field_name {
+                    "Majorities" => {
+                        while let Some(majority) =
+                            array_deserializer.deserialize_object("Majority")?
+                        {
+                            self.majorities.push(majority);
+                        }
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_array(field_name, array_deserializer)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.unknown_fields.visit_object(field_name, deserializer)
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(Amendments {
+            amendments: visitor.amendments,
+            flags: visitor.flags,
+            majorities: visitor.majorities,
+            previous_txn_id: visitor.previous_txn_id,
+            previous_txn_lgr_seq: visitor.previous_txn_lgr_seq,
+            unknown_fields: visitor.unknown_fields,
+        })
+    }
+}
+
+impl Deserialize for Majority {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            amendment: Option<Hash256>,
+            close_time: Option<UInt32>,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "Amendment" => {
+                        self.amendment = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "CloseTime" => {
+                        self.close_time = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    _ => return Err(E::unexpected_field(field_name)),
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                _array_deserializer: AD,
+            ) -> Result<(), E> {
+                Err(E::unexpected_field(field_name))
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(Majority {
+            amendment: S::Error::unwrap_field_value("Amendment", visitor.amendment)?,
+            close_time: S::Error::unwrap_field_value("CloseTime", visitor.close_time)?,
+        })
+    }
+}
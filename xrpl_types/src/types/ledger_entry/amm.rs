@@ -0,0 +1,430 @@
+use super::{check_ledger_entry_type, issued_amount};
+use crate::alloc::vec::Vec;
+use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
+use crate::serialize::{ArraySerializer, Serialize, Serializer};
+use crate::{
+    deserialize, AccountId, Amount, Currency, Hash256, IssuedAmount, LedgerEntryType, Object,
+    UInt16, UInt32, UInt64,
+};
+
+/// An `AMM` ledger entry, describing an automated market maker instance
+/// <https://xrpl.org/amm.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Amm {
+    /// Special account that holds the assets of the AMM
+    pub account: AccountId,
+    pub asset: Currency,
+    pub asset2: Currency,
+    pub auction_slot: Option<AuctionSlot>,
+    pub flags: UInt32,
+    /// Total outstanding balance of liquidity provider tokens
+    pub lp_token_balance: IssuedAmount,
+    pub owner_node: Option<UInt64>,
+    pub previous_txn_id: Option<Hash256>,
+    pub previous_txn_lgr_seq: Option<UInt32>,
+    /// Trading fee in units of 1/100000. Omitted in the binary format when zero.
+    pub trading_fee: UInt16,
+    pub vote_slots: Vec<VoteEntry>,
+    /// Fields not modelled by this type, see [`Object`]
+    pub unknown_fields: Object,
+}
+
+/// Auction slot of an AMM <https://xrpl.org/amm.html#auction-slot-object>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AuctionSlot {
+    /// Current owner of the auction slot
+    pub account: AccountId,
+    /// Additional accounts that can trade at the discounted fee
+    pub auth_accounts: Vec<AccountId>,
+    /// Omitted in the binary format when zero
+    pub discounted_fee: UInt16,
+    pub expiration: UInt32,
+    pub price: IssuedAmount,
+}
+
+/// Vote on the trading fee of an AMM <https://xrpl.org/amm.html#vote-slots>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VoteEntry {
+    pub account: AccountId,
+    /// Omitted in the binary format when zero
+    pub trading_fee: UInt16,
+    pub vote_weight: UInt32,
+}
+
+/// Element of the `AuthAccounts` array
+struct AuthAccount(AccountId);
+
+impl Serialize for Amm {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("LedgerEntryType", LedgerEntryType::AMM as u16)?;
+        s.serialize_account_id("Account", self.account)?;
+        s.serialize_issue("Asset", self.asset)?;
+        s.serialize_issue("Asset2", self.asset2)?;
+        if let Some(auction_slot) = &self.auction_slot {
+            s.serialize_object("AuctionSlot", auction_slot)?;
+        }
+        s.serialize_uint32("Flags", self.flags)?;
+        s.serialize_amount("LPTokenBalance", Amount::Issued(self.lp_token_balance))?;
+        if let Some(owner_node) = self.owner_node {
+            s.serialize_uint64("OwnerNode", owner_node)?;
+        }
+        if let Some(previous_txn_id) = self.previous_txn_id {
+            s.serialize_hash256("PreviousTxnID", previous_txn_id)?;
+        }
+        if let Some(previous_txn_lgr_seq) = self.previous_txn_lgr_seq {
+            s.serialize_uint32("PreviousTxnLgrSeq", previous_txn_lgr_seq)?;
+        }
+        if self.trading_fee != 0 {
+            s.serialize_uint16("TradingFee", self.trading_fee)?;
+        }
+        if !self.vote_slots.is_empty() {
+            let mut array = s.serialize_array("VoteSlots")?;
+            for vote_entry in &self.vote_slots {
+                array.serialize_object("VoteEntry", vote_entry)?;
+            }
+            array.end()?;
+        }
+        self.unknown_fields.serialize(s)?;
+        Ok(())
+    }
+}
+
+impl Serialize for AuctionSlot {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_account_id("Account", self.account)?;
+        if !self.auth_accounts.is_empty() {
+            let mut array = s.serialize_array("AuthAccounts")?;
+            for account in &self.auth_accounts {
+                array.serialize_object("AuthAccount", &AuthAccount(*account))?;
+            }
+            array.end()?;
+        }
+        if self.discounted_fee != 0 {
+            s.serialize_uint16("DiscountedFee", self.discounted_fee)?;
+        }
+        s.serialize_uint32("Expiration", self.expiration)?;
+        s.serialize_amount("Price", Amount::Issued(self.price))?;
+        Ok(())
+    }
+}
+
+impl Serialize for VoteEntry {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_account_id("Account", self.account)?;
+        if self.trading_fee != 0 {
+            s.serialize_uint16("TradingFee", self.trading_fee)?;
+        }
+        s.serialize_uint32("VoteWeight", self.vote_weight)?;
+        Ok(())
+    }
+}
+
+impl Serialize for AuthAccount {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_account_id("Account", self.0)
+    }
+}
+
+impl Deserialize for Amm {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            account: Option<AccountId>,
+            asset: Option<Currency>,
+            asset2: Option<Currency>,
+            auction_slot: Option<AuctionSlot>,
+            flags: UInt32,
+            lp_token_balance: Option<IssuedAmount>,
+            owner_node: Option<UInt64>,
+            previous_txn_id: Option<Hash256>,
+            previous_txn_lgr_seq: Option<UInt32>,
+            trading_fee: UInt16,
+            vote_slots: Vec<VoteEntry>,
+            unknown_fields: Object,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "LedgerEntryType" => {
+                        check_ledger_entry_type(field_accessor, LedgerEntryType::AMM)?;
+                    }
+                    "Account" => {
+                        self.account = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    "Asset" => {
+                        self.asset = Some(field_accessor.deserialize_issue()?);
+                    }
+                    "Asset2" => {
+                        self.asset2 = Some(field_accessor.deserialize_issue()?);
+                    }
+                    "Flags" => {
+                        self.flags = field_accessor.deserialize_uint32()?;
+                    }
+                    "LPTokenBalance" => {
+                        self.lp_token_balance = Some(issued_amount(
+                            field_name,
+                            field_accessor.deserialize_amount()?,
+                        )?);
+                    }
+                    "OwnerNode" => {
+                        self.owner_node = Some(field_accessor.deserialize_uint64()?);
+                    }
+                    "PreviousTxnID" => {
+                        self.previous_txn_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "PreviousTxnLgrSeq" => {
+                        self.previous_txn_lgr_seq = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "TradingFee" => {
+                        self.trading_fee = field_accessor.deserialize_uint16()?;
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_field(field_name, field_accessor)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                mut array_deserializer: AD,
+            ) -> Result<(), E> {
+                match field_name {
+                    "VoteSlots" => {
+                        while let Some(vote_entry) =
+                            array_deserializer.deserialize_object("VoteEntry")?
+                        {
+                            self.vote_slots.push(vote_entry);
+                        }
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_array(field_name, array_deserializer)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                match field_name {
+                    "AuctionSlot" => {
+                        self.auction_slot = Some(AuctionSlot::deserialize(deserializer)?);
+                    }
+                    _ => {
+                        self.unknown_fields.visit_object(field_name, deserializer)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(Amm {
+            account: S::Error::unwrap_field_value("Account", visitor.account)?,
+            asset: S::Error::unwrap_field_value("Asset", visitor.asset)?,
+            asset2: S::Error::unwrap_field_value("Asset2", visitor.asset2)?,
+            auction_slot: visitor.auction_slot,
+            flags: visitor.flags,
+            lp_token_balance: S::Error::unwrap_field_value(
+                "LPTokenBalance",
+                visitor.lp_token_balance,
+            )?,
+            owner_node: visitor.owner_node,
+            previous_txn_id: visitor.previous_txn_id,
+            previous_txn_lgr_seq: visitor.previous_txn_lgr_seq,
+            trading_fee: visitor.trading_fee,
+            vote_slots: visitor.vote_slots,
+            unknown_fields: visitor.unknown_fields,
+        })
+    }
+}
+
+impl Deserialize for AuctionSlot {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            account: Option<AccountId>,
+            auth_accounts: Vec<AccountId>,
+            discounted_fee: UInt16,
+            expiration: Option<UInt32>,
+            price: Option<IssuedAmount>,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "Account" => {
+                        self.account = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    "DiscountedFee" => {
+                        self.discounted_fee = field_accessor.deserialize_uint16()?;
+                    }
+                    "Expiration" => {
+                        self.expiration = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "Price" => {
+                        self.price = Some(issued_amount(
+                            field_name,
+                            field_accessor.deserialize_amount()?,
+                        )?);
+                    }
+                    _ => return Err(E::unexpected_field(field_name)),
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                mut array_deserializer: AD,
+            ) -> Result<(), E> {
+                match field_name {
+                    "AuthAccounts" => {
+                        while let Some(AuthAccount(account)) =
+                            array_deserializer.deserialize_object("AuthAccount")?
+                        {
+                            self.auth_accounts.push(account);
+                        }
+                    }
+                    _ => return Err(E::unexpected_field(field_name)),
+                }
+                Ok(())
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(AuctionSlot {
+            account: S::Error::unwrap_field_value("Account", visitor.account)?,
+            auth_accounts: visitor.auth_accounts,
+            discounted_fee: visitor.discounted_fee,
+            expiration: S::Error::unwrap_field_value("Expiration", visitor.expiration)?,
+            price: S::Error::unwrap_field_value("Price", visitor.price)?,
+        })
+    }
+}
+
+impl Deserialize for VoteEntry {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            account: Option<AccountId>,
+            trading_fee: UInt16,
+            vote_weight: Option<UInt32>,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "Account" => {
+                        self.account = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    "TradingFee" => {
+                        self.trading_fee = field_accessor.deserialize_uint16()?;
+                    }
+                    "VoteWeight" => {
+                        self.vote_weight = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    _ => return Err(E::unexpected_field(field_name)),
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                _array_deserializer: AD,
+            ) -> Result<(), E> {
+                Err(E::unexpected_field(field_name))
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(VoteEntry {
+            account: S::Error::unwrap_field_value("Account", visitor.account)?,
+            trading_fee: visitor.trading_fee,
+            vote_weight: S::Error::unwrap_field_value("VoteWeight", visitor.vote_weight)?,
+        })
+    }
+}
+
+impl Deserialize for AuthAccount {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            account: Option<AccountId>,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "Account" => {
+                        self.account = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    _ => return Err(E::unexpected_field(field_name)),
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                _array_deserializer: AD,
+            ) -> Result<(), E> {
+                Err(E::unexpected_field(field_name))
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(AuthAccount(S::Error::unwrap_field_value(
+            "Account",
+            visitor.account,
+        )?))
+    }
+}
@@ -0,0 +1,185 @@
+use super::check_ledger_entry_type;
+use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
+use crate::serialize::{Serialize, Serializer};
+use crate::{deserialize, AccountId, Amount, Hash256, LedgerEntryType, Object, UInt32, UInt64};
+
+/// A `Check` ledger entry <https://xrpl.org/check.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Check {
+    /// Sender of the check
+    pub account: AccountId,
+    pub destination: AccountId,
+    pub destination_node: UInt64,
+    pub destination_tag: Option<UInt32>,
+    pub expiration: Option<UInt32>,
+    pub flags: UInt32,
+    pub invoice_id: Option<Hash256>,
+    pub owner_node: UInt64,
+    pub previous_txn_id: Hash256,
+    pub previous_txn_lgr_seq: UInt32,
+    /// Maximum amount that can be debited from the sender
+    pub send_max: Amount,
+    pub sequence: UInt32,
+    pub source_tag: Option<UInt32>,
+    /// Fields not modelled by this type, see [`Object`]
+    pub unknown_fields: Object,
+}
+
+impl Serialize for Check {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("LedgerEntryType", LedgerEntryType::Check as u16)?;
+        s.serialize_account_id("Account", self.account)?;
+        s.serialize_account_id("Destination", self.destination)?;
+        s.serialize_uint64("DestinationNode", self.destination_node)?;
+        if let Some(destination_tag) = self.destination_tag {
+            s.serialize_uint32("DestinationTag", destination_tag)?;
+        }
+        if let Some(expiration) = self.expiration {
+            s.serialize_uint32("Expiration", expiration)?;
+        }
+        s.serialize_uint32("Flags", self.flags)?;
+        if let Some(invoice_id) = self.invoice_id {
+            s.serialize_hash256("InvoiceID", invoice_id)?;
+        }
+        s.serialize_uint64("OwnerNode", self.owner_node)?;
+        s.serialize_hash256("PreviousTxnID", self.previous_txn_id)?;
+        s.serialize_uint32("PreviousTxnLgrSeq", self.previous_txn_lgr_seq)?;
+        s.serialize_amount("SendMax", self.send_max)?;
+        s.serialize_uint32("Sequence", self.sequence)?;
+        if let Some(source_tag) = self.source_tag {
+            s.serialize_uint32("SourceTag", source_tag)?;
+        }
+        self.unknown_fields.serialize(s)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for Check {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            account: Option<AccountId>,
+            destination: Option<AccountId>,
+            destination_node: Option<UInt64>,
+            destination_tag: Option<UInt32>,
+            expiration: Option<UInt32>,
+            flags: UInt32,
+            invoice_id: Option<Hash256>,
+            owner_node: Option<UInt64>,
+            previous_txn_id: Option<Hash256>,
+            previous_txn_lgr_seq: Option<UInt32>,
+            send_max: Option<Amount>,
+            sequence: Option<UInt32>,
+            source_tag: Option<UInt32>,
+            unknown_fields: Object,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "LedgerEntryType" => {
+                        check_ledger_entry_type(field_accessor, LedgerEntryType::Check)?;
+                    }
+                    "Account" => {
+                        self.account = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    "Destination" => {
+                        self.destination = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    "DestinationNode" => {
+                        self.destination_node = Some(field_accessor.deserialize_uint64()?);
+                    }
+                    "DestinationTag" => {
+                        self.destination_tag = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "Expiration" => {
+                        self.expiration = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "Flags" => {
+                        self.flags = field_accessor.deserialize_uint32()?;
+                    }
+                    "InvoiceID" => {
+                        self.invoice_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "OwnerNode" => {
+                        self.owner_node = Some(field_accessor.deserialize_uint64()?);
+                    }
+                    "PreviousTxnID" => {
+                        self.previous_txn_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "PreviousTxnLgrSeq" => {
+                        self.previous_txn_lgr_seq = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "SendMax" => {
+                        self.send_max = Some(field_accessor.deserialize_amount()?);
+                    }
+                    "Sequence" => {
+                        self.sequence = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "SourceTag" => {
+                        self.source_tag = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_field(field_name, field_accessor)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                array_deserializer: AD,
+            ) -> Result<(), E> {
+                self.unknown_fields
+                    .visit_array(field_name, array_deserializer)
+            }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.unknown_fields.visit_object(field_name, deserializer)
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(Check {
+            account: S::Error::unwrap_field_value("Account", visitor.account)?,
+            destination: S::Error::unwrap_field_value("Destination", visitor.destination)?,
+            destination_node: S::Error::unwrap_field_value(
+                "DestinationNode",
+                visitor.destination_node,
+            )?,
+            destination_tag: visitor.destination_tag,
+            expiration: visitor.expiration,
+            flags: visitor.flags,
+            invoice_id: visitor.invoice_id,
+            owner_node: S::Error::unwrap_field_value("OwnerNode", visitor.owner_node)?,
+            previous_txn_id: S::Error::unwrap_field_value(
+                "PreviousTxnID",
+                visitor.previous_txn_id,
+            )?,
+            previous_txn_lgr_seq: S::Error::unwrap_field_value(
+                "PreviousTxnLgrSeq",
+                visitor.previous_txn_lgr_seq,
+            )?,
+            send_max: S::Error::unwrap_field_value("SendMax", visitor.send_max)?,
+            sequence: S::Error::unwrap_field_value("Sequence", visitor.sequence)?,
+            source_tag: visitor.source_tag,
+            unknown_fields: visitor.unknown_fields,
+        })
+    }
+}
@@ -0,0 +1,126 @@
+use super::check_ledger_entry_type;
+use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
+use crate::serialize::{Serialize, Serializer};
+use crate::{deserialize, AccountId, Hash256, LedgerEntryType, Object, UInt32, UInt64};
+
+/// A `DepositPreauth` ledger entry, a preauthorization to deliver payments to an account that
+/// requires deposit authorization <https://xrpl.org/depositpreauth-object.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DepositPreauth {
+    /// Account that granted the preauthorization
+    pub account: AccountId,
+    /// Account that received the preauthorization
+    pub authorize: AccountId,
+    pub flags: UInt32,
+    pub owner_node: UInt64,
+    pub previous_txn_id: Hash256,
+    pub previous_txn_lgr_seq: UInt32,
+    /// Fields not modelled by this type, see [`Object`]
+    pub unknown_fields: Object,
+}
+
+impl Serialize for DepositPreauth {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("LedgerEntryType", LedgerEntryType::DepositPreauth as u16)?;
+        s.serialize_account_id("Account", self.account)?;
+        s.serialize_account_id("Authorize", self.authorize)?;
+        s.serialize_uint32("Flags", self.flags)?;
+        s.serialize_uint64("OwnerNode", self.owner_node)?;
+        s.serialize_hash256("PreviousTxnID", self.previous_txn_id)?;
+        s.serialize_uint32("PreviousTxnLgrSeq", self.previous_txn_lgr_seq)?;
+        self.unknown_fields.serialize(s)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for DepositPreauth {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            account: Option<AccountId>,
+            authorize: Option<AccountId>,
+            flags: UInt32,
+            owner_node: Option<UInt64>,
+            previous_txn_id: Option<Hash256>,
+            previous_txn_lgr_seq: Option<UInt32>,
+            unknown_fields: Object,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "LedgerEntryType" => {
+                        check_ledger_entry_type(field_accessor, LedgerEntryType::DepositPreauth)?;
+                    }
+                    "Account" => {
+                        self.account = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    "Authorize" => {
+                        self.authorize = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    "Flags" => {
+                        self.flags = field_accessor.deserialize_uint32()?;
+                    }
+                    "OwnerNode" => {
+                        self.owner_node = Some(field_accessor.deserialize_uint64()?);
+                    }
+                    "PreviousTxnID" => {
+                        self.previous_txn_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "PreviousTxnLgrSeq" => {
+                        self.previous_txn_lgr_seq = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_field(field_name, field_accessor)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                array_deserializer: AD,
+            ) -> Result<(), E> {
+                self.unknown_fields
+                    .visit_array(field_name, array_deserializer)
+            }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.unknown_fields.visit_object(field_name, deserializer)
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(DepositPreauth {
+            account: S::Error::unwrap_field_value("Account", visitor.account)?,
+            authorize: S::Error::unwrap_field_value("Authorize", visitor.authorize)?,
+            flags: visitor.flags,
+            owner_node: S::Error::unwrap_field_value("OwnerNode", visitor.owner_node)?,
+            previous_txn_id: S::Error::unwrap_field_value(
+                "PreviousTxnID",
+                visitor.previous_txn_id,
+            )?,
+            previous_txn_lgr_seq: S::Error::unwrap_field_value(
+                "PreviousTxnLgrSeq",
+                visitor.previous_txn_lgr_seq,
+            )?,
+            unknown_fields: visitor.unknown_fields,
+        })
+    }
+}
@@ -0,0 +1,202 @@
+use super::check_ledger_entry_type;
+use crate::alloc::vec::Vec;
+use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
+use crate::serialize::{Serialize, Serializer};
+use crate::{deserialize, AccountId, Hash160, Hash256, LedgerEntryType, Object, UInt32, UInt64};
+
+/// A `DirectoryNode` ledger entry, a page of an owner directory or an offer directory
+/// <https://xrpl.org/directorynode.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DirectoryNode {
+    /// Exchange rate of the offers in an offer directory
+    pub exchange_rate: Option<UInt64>,
+    pub flags: UInt32,
+    /// Ledger objects in the page
+    pub indexes: Vec<Hash256>,
+    pub index_next: Option<UInt64>,
+    pub index_previous: Option<UInt64>,
+    /// NFToken of the offers in an NFToken offer directory
+    pub nftoken_id: Option<Hash256>,
+    /// Owner of the objects in an owner directory
+    pub owner: Option<AccountId>,
+    pub previous_txn_id: Option<Hash256>,
+    pub previous_txn_lgr_seq: Option<UInt32>,
+    /// First page of the directory
+    pub root_index: Hash256,
+    pub taker_gets_currency: Option<Hash160>,
+    pub taker_gets_issuer: Option<Hash160>,
+    pub taker_pays_currency: Option<Hash160>,
+    pub taker_pays_issuer: Option<Hash160>,
+    /// Fields not modelled by this type, see [`Object`]
+    pub unknown_fields: Object,
+}
+
+impl Serialize for DirectoryNode {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("LedgerEntryType", LedgerEntryType::DirectoryNode as u16)?;
+        if let Some(exchange_rate) = self.exchange_rate {
+            s.serialize_uint64("ExchangeRate", exchange_rate)?;
+        }
+        s.serialize_uint32("Flags", self.flags)?;
+        s.serialize_vector256("Indexes", &self.indexes)?;
+        if let Some(index_next) = self.index_next {
+            s.serialize_uint64("IndexNext", index_next)?;
+        }
+        if let Some(index_previous) = self.index_previous {
+            s.serialize_uint64("IndexPrevious", index_previous)?;
+        }
+        if let Some(nftoken_id) = self.nftoken_id {
+            s.serialize_hash256("NFTokenID", nftoken_id)?;
+        }
+        if let Some(owner) = self.owner {
+            s.serialize_account_id("Owner", owner)?;
+        }
+        if let Some(previous_txn_id) = self.previous_txn_id {
+            s.serialize_hash256("PreviousTxnID", previous_txn_id)?;
+        }
+        if let Some(previous_txn_lgr_seq) = self.previous_txn_lgr_seq {
+            s.serialize_uint32("PreviousTxnLgrSeq", previous_txn_lgr_seq)?;
+        }
+        s.serialize_hash256("RootIndex", self.root_index)?;
+        if let Some(taker_gets_currency) = self.taker_gets_currency {
+            s.serialize_hash160("TakerGetsCurrency", taker_gets_currency)?;
+        }
+        if let Some(taker_gets_issuer) = self.taker_gets_issuer {
+            s.serialize_hash160("TakerGetsIssuer", taker_gets_issuer)?;
+        }
+        if let Some(taker_pays_currency) = self.taker_pays_currency {
+            s.serialize_hash160("TakerPaysCurrency", taker_pays_currency)?;
+        }
+        if let Some(taker_pays_issuer) = self.taker_pays_issuer {
+            s.serialize_hash160("TakerPaysIssuer", taker_pays_issuer)?;
+        }
+        self.unknown_fields.serialize(s)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for DirectoryNode {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            exchange_rate: Option<UInt64>,
+            flags: UInt32,
+            indexes: Option<Vec<Hash256>>,
+            index_next: Option<UInt64>,
+            index_previous: Option<UInt64>,
+            nftoken_id: Option<Hash256>,
+            owner: Option<AccountId>,
+            previous_txn_id: Option<Hash256>,
+            previous_txn_lgr_seq: Option<UInt32>,
+            root_index: Option<Hash256>,
+            taker_gets_currency: Option<Hash160>,
+            taker_gets_issuer: Option<Hash160>,
+            taker_pays_currency: Option<Hash160>,
+            taker_pays_issuer: Option<Hash160>,
+            unknown_fields: Object,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "LedgerEntryType" => {
+                        check_ledger_entry_type(field_accessor, LedgerEntryType::DirectoryNode)?;
+                    }
+                    "ExchangeRate" => {
+                        self.exchange_rate = Some(field_accessor.deserialize_uint64()?);
+                    }
+                    "Flags" => {
+                        self.flags = field_accessor.deserialize_uint32()?;
+                    }
+                    "Indexes" => {
+                        self.indexes = Some(field_accessor.deserialize_vector256()?);
+                    }
+                    "IndexNext" => {
+                        self.index_next = Some(field_accessor.deserialize_uint64()?);
+                    }
+                    "IndexPrevious" => {
+                        self.index_previous = Some(field_accessor.deserialize_uint64()?);
+                    }
+                    "NFTokenID" => {
+                        self.nftoken_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "Owner" => {
+                        self.owner = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    "PreviousTxnID" => {
+                        self.previous_txn_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "PreviousTxnLgrSeq" => {
+                        self.previous_txn_lgr_seq = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "RootIndex" => {
+                        self.root_index = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "TakerGetsCurrency" => {
+                        self.taker_gets_currency = Some(field_accessor.deserialize_hash160()?);
+                    }
+                    "TakerGetsIssuer" => {
+                        self.taker_gets_issuer = Some(field_accessor.deserialize_hash160()?);
+                    }
+                    "TakerPaysCurrency" => {
+                        self.taker_pays_currency = Some(field_accessor.deserialize_hash160()?);
+                    }
+                    "TakerPaysIssuer" => {
+                        self.taker_pays_issuer = Some(field_accessor.deserialize_hash160()?);
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_field(field_name, field_accessor)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                array_deserializer: AD,
+            ) -> Result<(), E> {
+                self.unknown_fields
+                    .visit_array(field_name, array_deserializer)
+            }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.unknown_fields.visit_object(field_name, deserializer)
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(DirectoryNode {
+            exchange_rate: visitor.exchange_rate,
+            flags: visitor.flags,
+            indexes: S::Error::unwrap_field_value("Indexes", visitor.indexes)?,
+            index_next: visitor.index_next,
+            index_previous: visitor.index_previous,
+            nftoken_id: visitor.nftoken_id,
+            owner: visitor.owner,
+            previous_txn_id: visitor.previous_txn_id,
+            previous_txn_lgr_seq: visitor.previous_txn_lgr_seq,
+            root_index: S::Error::unwrap_field_value("RootIndex", visitor.root_index)?,
+            taker_gets_currency: visitor.taker_gets_currency,
+            taker_gets_issuer: visitor.taker_gets_issuer,
+            taker_pays_currency: visitor.taker_pays_currency,
+            taker_pays_issuer: visitor.taker_pays_issuer,
+            unknown_fields: visitor.unknown_fields,
+        })
+    }
+}
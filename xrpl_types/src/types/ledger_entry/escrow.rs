@@ -0,0 +1,188 @@
+use super::check_ledger_entry_type;
+use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
+use crate::serialize::{Serialize, Serializer};
+use crate::{
+    deserialize, AccountId, Amount, Blob, Hash256, LedgerEntryType, Object, UInt32, UInt64,
+};
+
+/// An `Escrow` ledger entry <https://xrpl.org/escrow-object.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Escrow {
+    /// Owner of the escrow, which funded it
+    pub account: AccountId,
+    pub amount: Amount,
+    pub cancel_after: Option<UInt32>,
+    /// Crypto-condition that must be fulfilled to finish the escrow
+    pub condition: Option<Blob>,
+    pub destination: AccountId,
+    pub destination_node: Option<UInt64>,
+    pub destination_tag: Option<UInt32>,
+    pub finish_after: Option<UInt32>,
+    pub flags: UInt32,
+    pub owner_node: UInt64,
+    pub previous_txn_id: Hash256,
+    pub previous_txn_lgr_seq: UInt32,
+    pub source_tag: Option<UInt32>,
+    /// Fields not modelled by this type, see [`Object`]
+    pub unknown_fields: Object,
+}
+
+impl Serialize for Escrow {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("LedgerEntryType", LedgerEntryType::Escrow as u16)?;
+        s.serialize_account_id("Account", self.account)?;
+        s.serialize_amount("Amount", self.amount)?;
+        if let Some(cancel_after) = self.cancel_after {
+            s.serialize_uint32("CancelAfter", cancel_after)?;
+        }
+        if let Some(condition) = &self.condition {
+            s.serialize_blob("Condition", condition)?;
+        }
+        s.serialize_account_id("Destination", self.destination)?;
+        if let Some(destination_node) = self.destination_node {
+            s.serialize_uint64("DestinationNode", destination_node)?;
+        }
+        if let Some(destination_tag) = self.destination_tag {
+            s.serialize_uint32("DestinationTag", destination_tag)?;
+        }
+        if let Some(finish_after) = self.finish_after {
+            s.serialize_uint32("FinishAfter", finish_after)?;
+        }
+        s.serialize_uint32("Flags", self.flags)?;
+        s.serialize_uint64("OwnerNode", self.owner_node)?;
+        s.serialize_hash256("PreviousTxnID", self.previous_txn_id)?;
+        s.serialize_uint32("PreviousTxnLgrSeq", self.previous_txn_lgr_seq)?;
+        if let Some(source_tag) = self.source_tag {
+            s.serialize_uint32("SourceTag", source_tag)?;
+        }
+        self.unknown_fields.serialize(s)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for Escrow {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            account: Option<AccountId>,
+            amount: Option<Amount>,
+            cancel_after: Option<UInt32>,
+            condition: Option<Blob>,
+            destination: Option<AccountId>,
+            destination_node: Option<UInt64>,
+            destination_tag: Option<UInt32>,
+            finish_after: Option<UInt32>,
+            flags: UInt32,
+            owner_node: Option<UInt64>,
+            previous_txn_id: Option<Hash256>,
+            previous_txn_lgr_seq: Option<UInt32>,
+            source_tag: Option<UInt32>,
+            unknown_fields: Object,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "LedgerEntryType" => {
+                        check_ledger_entry_type(field_accessor, LedgerEntryType::Escrow)?;
+                    }
+                    "Account" => {
+                        self.account = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    "Amount" => {
+                        self.amount = Some(field_accessor.deserialize_amount()?);
+                    }
+                    "CancelAfter" => {
+                        self.cancel_after = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "Condition" => {
+                        self.condition = Some(field_accessor.deserialize_blob()?);
+                    }
+                    "Destination" => {
+                        self.destination = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    "DestinationNode" => {
+                        self.destination_node = Some(field_accessor.deserialize_uint64()?);
+                    }
+                    "DestinationTag" => {
+                        self.destination_tag = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "FinishAfter" => {
+                        self.finish_after = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "Flags" => {
+                        self.flags = field_accessor.deserialize_uint32()?;
+                    }
+                    "OwnerNode" => {
+                        self.owner_node = Some(field_accessor.deserialize_uint64()?);
+                    }
+                    "PreviousTxnID" => {
+                        self.previous_txn_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "PreviousTxnLgrSeq" => {
+                        self.previous_txn_lgr_seq = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "SourceTag" => {
+                        self.source_tag = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_field(field_name, field_accessor)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                array_deserializer: AD,
+            ) -> Result<(), E> {
+                self.unknown_fields
+                    .visit_array(field_name, array_deserializer)
+            }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.unknown_fields.visit_object(field_name, deserializer)
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(Escrow {
+            account: S::Error::unwrap_field_value("Account", visitor.account)?,
+            amount: S::Error::unwrap_field_value("Amount", visitor.amount)?,
+            cancel_after: visitor.cancel_after,
+            condition: visitor.condition,
+            destination: S::Error::unwrap_field_value("Destination", visitor.destination)?,
+            destination_node: visitor.destination_node,
+            destination_tag: visitor.destination_tag,
+            finish_after: visitor.finish_after,
+            flags: visitor.flags,
+            owner_node: S::Error::unwrap_field_value("OwnerNode", visitor.owner_node)?,
+            previous_txn_id: S::Error::unwrap_field_value(
+                "PreviousTxnID",
+                visitor.previous_txn_id,
+            )?,
+            previous_txn_lgr_seq: S::Error::unwrap_field_value(
+                "PreviousTxnLgrSeq",
+                visitor.previous_txn_lgr_seq,
+            )?,
+            source_tag: visitor.source_tag,
+            unknown_fields: visitor.unknown_fields,
+        })
+    }
+}
@@ -0,0 +1,177 @@
+use super::{check_ledger_entry_type, drops_amount};
+use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
+use crate::serialize::{Serialize, Serializer};
+use crate::{deserialize, Amount, DropsAmount, Hash256, LedgerEntryType, Object, UInt32, UInt64};
+
+/// The `FeeSettings` ledger entry, a singleton holding the current transaction cost and reserve
+/// requirements <https://xrpl.org/feesettings.html>. Depending on whether the `XRPFees`
+/// amendment is enabled, either the legacy fields or the `...Drops` fields are present.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FeeSettings {
+    pub base_fee: Option<UInt64>,
+    pub base_fee_drops: Option<DropsAmount>,
+    pub flags: UInt32,
+    pub previous_txn_id: Option<Hash256>,
+    pub previous_txn_lgr_seq: Option<UInt32>,
+    pub reference_fee_units: Option<UInt32>,
+    pub reserve_base: Option<UInt32>,
+    pub reserve_base_drops: Option<DropsAmount>,
+    pub reserve_increment: Option<UInt32>,
+    pub reserve_increment_drops: Option<DropsAmount>,
+    /// Fields not modelled by this type, see [`Object`]
+    pub unknown_fields: Object,
+}
+
+impl Serialize for FeeSettings {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("LedgerEntryType", LedgerEntryType::FeeSettings as u16)?;
+        if let Some(base_fee) = self.base_fee {
+            s.serialize_uint64("BaseFee", base_fee)?;
+        }
+        if let Some(base_fee_drops) = self.base_fee_drops {
+            s.serialize_amount("BaseFeeDrops", Amount::Drops(base_fee_drops))?;
+        }
+        s.serialize_uint32("Flags", self.flags)?;
+        if let Some(previous_txn_id) = self.previous_txn_id {
+            s.serialize_hash256("PreviousTxnID", previous_txn_id)?;
+        }
+        if let Some(previous_txn_lgr_seq) = self.previous_txn_lgr_seq {
+            s.serialize_uint32("PreviousTxnLgrSeq", previous_txn_lgr_seq)?;
+        }
+        if let Some(reference_fee_units) = self.reference_fee_units {
+            s.serialize_uint32("ReferenceFeeUnits", reference_fee_units)?;
+        }
+        if let Some(reserve_base) = self.reserve_base {
+            s.serialize_uint32("ReserveBase", reserve_base)?;
+        }
+        if let Some(reserve_base_drops) = self.reserve_base_drops {
+            s.serialize_amount("ReserveBaseDrops", Amount::Drops(reserve_base_drops))?;
+        }
+        if let Some(reserve_increment) = self.reserve_increment {
+            s.serialize_uint32("ReserveIncrement", reserve_increment)?;
+        }
+        if let Some(reserve_increment_drops) = self.reserve_increment_drops {
+            s.serialize_amount(
+                "ReserveIncrementDrops",
+                Amount::Drops(reserve_increment_drops),
+            )?;
+        }
+        self.unknown_fields.serialize(s)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for FeeSettings {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            base_fee: Option<UInt64>,
+            base_fee_drops: Option<DropsAmount>,
+            flags: UInt32,
+            previous_txn_id: Option<Hash256>,
+            previous_txn_lgr_seq: Option<UInt32>,
+            reference_fee_units: Option<UInt32>,
+            reserve_base: Option<UInt32>,
+            reserve_base_drops: Option<DropsAmount>,
+            reserve_increment: Option<UInt32>,
+            reserve_increment_drops: Option<DropsAmount>,
+            unknown_fields: Object,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "LedgerEntryType" => {
+                        check_ledger_entry_type(field_accessor, LedgerEntryType::FeeSettings)?;
+                    }
+                    "BaseFee" => {
+                        self.base_fee = Some(field_accessor.deserialize_uint64()?);
+                    }
+                    "BaseFeeDrops" => {
+                        self.base_fee_drops = Some(drops_amount(
+                            field_name,
+                            field_accessor.deserialize_amount()?,
+                        )?);
+                    }
+                    "Flags" => {
+                        self.flags = field_accessor.deserialize_uint32()?;
+                    }
+                    "PreviousTxnID" => {
+                        self.previous_txn_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "PreviousTxnLgrSeq" => {
+                        self.previous_txn_lgr_seq = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "ReferenceFeeUnits" => {
+                        self.reference_fee_units = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "ReserveBase" => {
+                        self.reserve_base = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "ReserveBaseDrops" => {
+                        self.reserve_base_drops = Some(drops_amount(
+                            field_name,
+                            field_accessor.deserialize_amount()?,
+                        )?);
+                    }
+                    "ReserveIncrement" => {
+                        self.reserve_increment = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "ReserveIncrementDrops" => {
+                        self.reserve_increment_drops = Some(drops_amount(
+                            field_name,
+                            field_accessor.deserialize_amount()?,
+                        )?);
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_field(field_name, field_accessor)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                array_deserializer: AD,
+            ) -> Result<(), E> {
+                self.unknown_fields
+                    .visit_array(field_name, array_deserializer)
+            }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.unknown_fields.visit_object(field_name, deserializer)
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(FeeSettings {
+            base_fee: visitor.base_fee,
+            base_fee_drops: visitor.base_fee_drops,
+            flags: visitor.flags,
+            previous_txn_id: visitor.previous_txn_id,
+            previous_txn_lgr_seq: visitor.previous_txn_lgr_seq,
+            reference_fee_units: visitor.reference_fee_units,
+            reserve_base: visitor.reserve_base,
+            reserve_base_drops: visitor.reserve_base_drops,
+            reserve_increment: visitor.reserve_increment,
+            reserve_increment_drops: visitor.reserve_increment_drops,
+            unknown_fields: visitor.unknown_fields,
+        })
+    }
+}
@@ -0,0 +1,110 @@
+use super::check_ledger_entry_type;
+use crate::alloc::vec::Vec;
+use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
+use crate::serialize::{Serialize, Serializer};
+use crate::{deserialize, Hash256, LedgerEntryType, Object, UInt32};
+
+/// A `LedgerHashes` ledger entry, holding the hashes of previous ledgers
+/// <https://xrpl.org/ledgerhashes.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LedgerHashes {
+    pub first_ledger_sequence: Option<UInt32>,
+    pub flags: UInt32,
+    pub hashes: Vec<Hash256>,
+    /// Index of the last ledger whose hash is in `hashes`
+    pub last_ledger_sequence: Option<UInt32>,
+    /// Fields not modelled by this type, see [`Object`]
+    pub unknown_fields: Object,
+}
+
+impl Serialize for LedgerHashes {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("LedgerEntryType", LedgerEntryType::LedgerHashes as u16)?;
+        if let Some(first_ledger_sequence) = self.first_ledger_sequence {
+            s.serialize_uint32("FirstLedgerSequence", first_ledger_sequence)?;
+        }
+        s.serialize_uint32("Flags", self.flags)?;
+        s.serialize_vector256("Hashes", &self.hashes)?;
+        if let Some(last_ledger_sequence) = self.last_ledger_sequence {
+            s.serialize_uint32("LastLedgerSequence", last_ledger_sequence)?;
+        }
+        self.unknown_fields.serialize(s)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for LedgerHashes {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            first_ledger_sequence: Option<UInt32>,
+            flags: UInt32,
+            hashes: Option<Vec<Hash256>>,
+            last_ledger_sequence: Option<UInt32>,
+            unknown_fields: Object,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "LedgerEntryType" => {
+                        check_ledger_entry_type(field_accessor, LedgerEntryType::LedgerHashes)?;
+                    }
+                    "FirstLedgerSequence" => {
+                        self.first_ledger_sequence = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "Flags" => {
+                        self.flags = field_accessor.deserialize_uint32()?;
+                    }
+                    "Hashes" => {
+                        self.hashes = Some(field_accessor.deserialize_vector256()?);
+                    }
+                    "LastLedgerSequence" => {
+                        self.last_ledger_sequence = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_field(field_name, field_accessor)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                array_deserializer: AD,
+            ) -> Result<(), E> {
+                self.unknown_fields
+                    .visit_array(field_name, array_deserializer)
+            }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.unknown_fields.visit_object(field_name, deserializer)
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(LedgerHashes {
+            first_ledger_sequence: visitor.first_ledger_sequence,
+            flags: visitor.flags,
+            hashes: S::Error::unwrap_field_value("Hashes", visitor.hashes)?,
+            last_ledger_sequence: visitor.last_ledger_sequence,
+            unknown_fields: visitor.unknown_fields,
+        })
+    }
+}
@@ -0,0 +1,269 @@
+mod account_root;
+mod amendments;
+mod amm;
+mod check;
+mod deposit_preauth;
+mod directory_node;
+mod escrow;
+mod fee_settings;
+mod ledger_hashes;
+mod nftoken_offer;
+mod nftoken_page;
+mod offer;
+mod pay_channel;
+mod ripple_state;
+mod signer_list;
+mod ticket;
+
+use crate::deserialize::FieldAccessor;
+use crate::deserialize::{DeserError, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::{Amount, DropsAmount, IssuedAmount, Object, UInt16};
+pub use account_root::*;
+use alloc::format;
+pub use amendments::*;
+pub use amm::*;
+pub use check::*;
+pub use deposit_preauth::*;
+pub use directory_node::*;
+pub use escrow::*;
+pub use fee_settings::*;
+pub use ledger_hashes::*;
+pub use nftoken_offer::*;
+pub use nftoken_page::*;
+pub use offer::*;
+pub use pay_channel::*;
+pub use ripple_state::*;
+pub use signer_list::*;
+pub use ticket::*;
+
+#[repr(u16)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, serde::Serialize, serde::Deserialize)]
+pub enum LedgerEntryType {
+    // Discriminant values can be found at https://github.com/XRPLF/xrpl.js/blob/main/packages/ripple-binary-codec/src/enums/definitions.json
+    NFTokenOffer = 55,
+    Check = 67,
+    DID = 73,
+    NegativeUNL = 78,
+    NFTokenPage = 80,
+    SignerList = 83,
+    Ticket = 84,
+    AccountRoot = 97,
+    DirectoryNode = 100,
+    Amendments = 102,
+    LedgerHashes = 104,
+    Bridge = 105,
+    Offer = 111,
+    DepositPreauth = 112,
+    XChainOwnedClaimID = 113,
+    RippleState = 114,
+    FeeSettings = 115,
+    XChainOwnedCreateAccountClaimID = 116,
+    Escrow = 117,
+    PayChannel = 120,
+    AMM = 121,
+}
+
+impl LedgerEntryType {
+    pub fn from_discriminant_opt(disc: u16) -> Option<Self> {
+        match disc {
+            55 => Some(Self::NFTokenOffer),
+            67 => Some(Self::Check),
+            73 => Some(Self::DID),
+            78 => Some(Self::NegativeUNL),
+            80 => Some(Self::NFTokenPage),
+            83 => Some(Self::SignerList),
+            84 => Some(Self::Ticket),
+            97 => Some(Self::AccountRoot),
+            100 => Some(Self::DirectoryNode),
+            102 => Some(Self::Amendments),
+            104 => Some(Self::LedgerHashes),
+            105 => Some(Self::Bridge),
+            111 => Some(Self::Offer),
+            112 => Some(Self::DepositPreauth),
+            113 => Some(Self::XChainOwnedClaimID),
+            114 => Some(Self::RippleState),
+            115 => Some(Self::FeeSettings),
+            116 => Some(Self::XChainOwnedCreateAccountClaimID),
+            117 => Some(Self::Escrow),
+            120 => Some(Self::PayChannel),
+            121 => Some(Self::AMM),
+            _ => None,
+        }
+    }
+}
+
+/// Object in the ledger state tree. See <https://xrpl.org/ledger-object-types.html>
+///
+/// All bits of the `Flags` field are kept, also bits that are not modelled. Entries with
+/// flags modelled by this crate hold them as [`Flags`](crate::Flags), the other entries hold the
+/// raw `UInt32`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum LedgerEntry {
+    AccountRoot(AccountRoot),
+    Amendments(Amendments),
+    AMM(Amm),
+    Check(Check),
+    DepositPreauth(DepositPreauth),
+    DirectoryNode(DirectoryNode),
+    Escrow(Escrow),
+    FeeSettings(FeeSettings),
+    LedgerHashes(LedgerHashes),
+    NFTokenOffer(NFTokenOffer),
+    NFTokenPage(NFTokenPage),
+    Offer(Offer),
+    PayChannel(PayChannel),
+    RippleState(RippleState),
+    SignerList(SignerList),
+    Ticket(Ticket),
+    /// Ledger entry of a type that is not modelled, or of a ledger entry type that is not known.
+    /// All fields other than `LedgerEntryType` are kept in `fields`
+    Unknown {
+        /// Value of the `LedgerEntryType` field
+        ledger_entry_type: UInt16,
+        fields: Object,
+    },
+}
+
+impl LedgerEntry {
+    /// Ledger entry type, if it is known
+    pub fn ledger_entry_type(&self) -> Option<LedgerEntryType> {
+        Some(match self {
+            LedgerEntry::AccountRoot(_) => LedgerEntryType::AccountRoot,
+            LedgerEntry::Amendments(_) => LedgerEntryType::Amendments,
+            LedgerEntry::AMM(_) => LedgerEntryType::AMM,
+            LedgerEntry::Check(_) => LedgerEntryType::Check,
+            LedgerEntry::DepositPreauth(_) => LedgerEntryType::DepositPreauth,
+            LedgerEntry::DirectoryNode(_) => LedgerEntryType::DirectoryNode,
+            LedgerEntry::Escrow(_) => LedgerEntryType::Escrow,
+            LedgerEntry::FeeSettings(_) => LedgerEntryType::FeeSettings,
+            LedgerEntry::LedgerHashes(_) => LedgerEntryType::LedgerHashes,
+            LedgerEntry::NFTokenOffer(_) => LedgerEntryType::NFTokenOffer,
+            LedgerEntry::NFTokenPage(_) => LedgerEntryType::NFTokenPage,
+            LedgerEntry::Offer(_) => LedgerEntryType::Offer,
+            LedgerEntry::PayChannel(_) => LedgerEntryType::PayChannel,
+            LedgerEntry::RippleState(_) => LedgerEntryType::RippleState,
+            LedgerEntry::SignerList(_) => LedgerEntryType::SignerList,
+            LedgerEntry::Ticket(_) => LedgerEntryType::Ticket,
+            LedgerEntry::Unknown {
+                ledger_entry_type, ..
+            } => return LedgerEntryType::from_discriminant_opt(*ledger_entry_type),
+        })
+    }
+}
+
+impl Serialize for LedgerEntry {
+    fn serialize<S: Serializer>(&self, serializer: &mut S) -> Result<(), S::Error> {
+        match self {
+            LedgerEntry::AccountRoot(entry) => entry.serialize(serializer),
+            LedgerEntry::Amendments(entry) => entry.serialize(serializer),
+            LedgerEntry::AMM(entry) => entry.serialize(serializer),
+            LedgerEntry::Check(entry) => entry.serialize(serializer),
+            LedgerEntry::DepositPreauth(entry) => entry.serialize(serializer),
+            LedgerEntry::DirectoryNode(entry) => entry.serialize(serializer),
+            LedgerEntry::Escrow(entry) => entry.serialize(serializer),
+            LedgerEntry::FeeSettings(entry) => entry.serialize(serializer),
+            LedgerEntry::LedgerHashes(entry) => entry.serialize(serializer),
+            LedgerEntry::NFTokenOffer(entry) => entry.serialize(serializer),
+            LedgerEntry::NFTokenPage(entry) => entry.serialize(serializer),
+            LedgerEntry::Offer(entry) => entry.serialize(serializer),
+            LedgerEntry::PayChannel(entry) => entry.serialize(serializer),
+            LedgerEntry::RippleState(entry) => entry.serialize(serializer),
+            LedgerEntry::SignerList(entry) => entry.serialize(serializer),
+            LedgerEntry::Ticket(entry) => entry.serialize(serializer),
+            LedgerEntry::Unknown {
+                ledger_entry_type,
+                fields,
+            } => {
+                serializer.serialize_uint16("LedgerEntryType", *ledger_entry_type)?;
+                fields.serialize(serializer)
+            }
+        }
+    }
+}
+
+impl Deserialize for LedgerEntry {
+    fn deserialize<S: Deserializer>(mut deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        let ledger_entry_type = deserializer
+            .deserialize_single_field("LedgerEntryType")?
+            .deserialize_uint16()?;
+        let Some(entry_type) = LedgerEntryType::from_discriminant_opt(ledger_entry_type) else {
+            return Ok(Self::Unknown {
+                ledger_entry_type,
+                fields: Object::deserialize(deserializer)?,
+            });
+        };
+        Ok(match entry_type {
+            LedgerEntryType::AccountRoot => {
+                Self::AccountRoot(AccountRoot::deserialize(deserializer)?)
+            }
+            LedgerEntryType::Amendments => Self::Amendments(Amendments::deserialize(deserializer)?),
+            LedgerEntryType::AMM => Self::AMM(Amm::deserialize(deserializer)?),
+            LedgerEntryType::Check => Self::Check(Check::deserialize(deserializer)?),
+            LedgerEntryType::DepositPreauth => {
+                Self::DepositPreauth(DepositPreauth::deserialize(deserializer)?)
+            }
+            LedgerEntryType::DirectoryNode => {
+                Self::DirectoryNode(DirectoryNode::deserialize(deserializer)?)
+            }
+            LedgerEntryType::Escrow => Self::Escrow(Escrow::deserialize(deserializer)?),
+            LedgerEntryType::FeeSettings => {
+                Self::FeeSettings(FeeSettings::deserialize(deserializer)?)
+            }
+            LedgerEntryType::LedgerHashes => {
+                Self::LedgerHashes(LedgerHashes::deserialize(deserializer)?)
+            }
+            LedgerEntryType::NFTokenOffer => {
+                Self::NFTokenOffer(NFTokenOffer::deserialize(deserializer)?)
+            }
+            LedgerEntryType::NFTokenPage => {
+                Self::NFTokenPage(NFTokenPage::deserialize(deserializer)?)
+            }
+            LedgerEntryType::Offer => Self::Offer(Offer::deserialize(deserializer)?),
+            LedgerEntryType::PayChannel => Self::PayChannel(PayChannel::deserialize(deserializer)?),
+            LedgerEntryType::RippleState => {
+                Self::RippleState(RippleState::deserialize(deserializer)?)
+            }
+            LedgerEntryType::SignerList => Self::SignerList(SignerList::deserialize(deserializer)?),
+            LedgerEntryType::Ticket => Self::Ticket(Ticket::deserialize(deserializer)?),
+            _ => Self::Unknown {
+                ledger_entry_type,
+                fields: Object::deserialize(deserializer)?,
+            },
+        })
+    }
+}
+
+/// Checks the value of the `LedgerEntryType` field when deserializing a specific ledger entry
+fn check_ledger_entry_type<E: DeserError, F: FieldAccessor<Error = E>>(
+    field_accessor: F,
+    expected: LedgerEntryType,
+) -> Result<(), E> {
+    if field_accessor.deserialize_uint16()? != expected as u16 {
+        return Err(E::invalid_value("Wrong ledger entry type"));
+    }
+    Ok(())
+}
+
+fn drops_amount<E: DeserError>(field_name: &str, amount: Amount) -> Result<DropsAmount, E> {
+    match amount {
+        Amount::Drops(drops) => Ok(drops),
+        Amount::Issued(_) => Err(E::invalid_value(format!(
+            "{} must be XRP amount",
+            field_name
+        ))),
+    }
+}
+
+fn issued_amount<E: DeserError>(field_name: &str, amount: Amount) -> Result<IssuedAmount, E> {
+    match amount {
+        Amount::Issued(amount) => Ok(amount),
+        Amount::Drops(_) => Err(E::invalid_value(format!(
+            "{} must be issued token amount",
+            field_name
+        ))),
+    }
+}
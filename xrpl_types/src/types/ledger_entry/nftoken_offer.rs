@@ -0,0 +1,170 @@
+use super::check_ledger_entry_type;
+use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
+use crate::serialize::{Serialize, Serializer};
+use crate::{
+    deserialize, AccountId, Amount, Flags, Hash256, LedgerEntryType, Object, UInt32, UInt64,
+};
+use enumflags2::bitflags;
+
+/// An `NFTokenOffer` ledger entry, an offer to buy or sell an NFToken
+/// <https://xrpl.org/nftokenoffer.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NFTokenOffer {
+    pub amount: Amount,
+    /// Only this account can accept the offer, if set
+    pub destination: Option<AccountId>,
+    pub expiration: Option<UInt32>,
+    pub flags: Flags<NFTokenOfferFlags>,
+    pub nftoken_id: Hash256,
+    pub nftoken_offer_node: Option<UInt64>,
+    pub owner: AccountId,
+    pub owner_node: UInt64,
+    pub previous_txn_id: Hash256,
+    pub previous_txn_lgr_seq: UInt32,
+    /// Fields not modelled by this type, see [`Object`]
+    pub unknown_fields: Object,
+}
+
+/// `NFTokenOffer` flags <https://xrpl.org/nftokenoffer.html#nftokenoffer-flags>
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NFTokenOfferFlags {
+    SellNFToken = 0x00000001,
+}
+
+impl Serialize for NFTokenOffer {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("LedgerEntryType", LedgerEntryType::NFTokenOffer as u16)?;
+        s.serialize_amount("Amount", self.amount)?;
+        if let Some(destination) = self.destination {
+            s.serialize_account_id("Destination", destination)?;
+        }
+        if let Some(expiration) = self.expiration {
+            s.serialize_uint32("Expiration", expiration)?;
+        }
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_hash256("NFTokenID", self.nftoken_id)?;
+        if let Some(nftoken_offer_node) = self.nftoken_offer_node {
+            s.serialize_uint64("NFTokenOfferNode", nftoken_offer_node)?;
+        }
+        s.serialize_account_id("Owner", self.owner)?;
+        s.serialize_uint64("OwnerNode", self.owner_node)?;
+        s.serialize_hash256("PreviousTxnID", self.previous_txn_id)?;
+        s.serialize_uint32("PreviousTxnLgrSeq", self.previous_txn_lgr_seq)?;
+        self.unknown_fields.serialize(s)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for NFTokenOffer {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            amount: Option<Amount>,
+            destination: Option<AccountId>,
+            expiration: Option<UInt32>,
+            flags: Flags<NFTokenOfferFlags>,
+            nftoken_id: Option<Hash256>,
+            nftoken_offer_node: Option<UInt64>,
+            owner: Option<AccountId>,
+            owner_node: Option<UInt64>,
+            previous_txn_id: Option<Hash256>,
+            previous_txn_lgr_seq: Option<UInt32>,
+            unknown_fields: Object,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "LedgerEntryType" => {
+                        check_ledger_entry_type(field_accessor, LedgerEntryType::NFTokenOffer)?;
+                    }
+                    "Amount" => {
+                        self.amount = Some(field_accessor.deserialize_amount()?);
+                    }
+                    "Destination" => {
+                        self.destination = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    "Expiration" => {
+                        self.expiration = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "Flags" => {
+                        self.flags = Flags::from_bits(field_accessor.deserialize_uint32()?);
+                    }
+                    "NFTokenID" => {
+                        self.nftoken_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "NFTokenOfferNode" => {
+                        self.nftoken_offer_node = Some(field_accessor.deserialize_uint64()?);
+                    }
+                    "Owner" => {
+                        self.owner = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    "OwnerNode" => {
+                        self.owner_node = Some(field_accessor.deserialize_uint64()?);
+                    }
+                    "PreviousTxnID" => {
+                        self.previous_txn_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "PreviousTxnLgrSeq" => {
+                        self.previous_txn_lgr_seq = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_field(field_name, field_accessor)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                array_deserializer: AD,
+            ) -> Result<(), E> {
+                self.unknown_fields
+                    .visit_array(field_name, array_deserializer)
+            }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.unknown_fields.visit_object(field_name, deserializer)
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(NFTokenOffer {
+            amount: S::Error::unwrap_field_value("Amount", visitor.amount)?,
+            destination: visitor.destination,
+            expiration: visitor.expiration,
+            flags: visitor.flags,
+            nftoken_id: S::Error::unwrap_field_value("NFTokenID", visitor.nftoken_id)?,
+            nftoken_offer_node: visitor.nftoken_offer_node,
+            owner: S::Error::unwrap_field_value("Owner", visitor.owner)?,
+            owner_node: S::Error::unwrap_field_value("OwnerNode", visitor.owner_node)?,
+            previous_txn_id: S::Error::unwrap_field_value(
+                "PreviousTxnID",
+                visitor.previous_txn_id,
+            )?,
+            previous_txn_lgr_seq: S::Error::unwrap_field_value(
+                "PreviousTxnLgrSeq",
+                visitor.previous_txn_lgr_seq,
+            )?,
+            unknown_fields: visitor.unknown_fields,
+        })
+    }
+}
@@ -0,0 +1,208 @@
+use super::check_ledger_entry_type;
+use crate::alloc::vec::Vec;
+use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
+use crate::serialize::{ArraySerializer, Serialize, Serializer};
+use crate::{deserialize, Blob, Hash256, LedgerEntryType, Object, UInt32};
+
+/// An `NFTokenPage` ledger entry, holding NFTokens owned by an account
+/// <https://xrpl.org/nftokenpage.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NFTokenPage {
+    pub flags: UInt32,
+    pub next_page_min: Option<Hash256>,
+    pub nftokens: Vec<NFToken>,
+    pub previous_page_min: Option<Hash256>,
+    pub previous_txn_id: Hash256,
+    pub previous_txn_lgr_seq: UInt32,
+    /// Fields not modelled by this type, see [`Object`]
+    pub unknown_fields: Object,
+}
+
+/// NFToken in an `NFTokenPage` <https://xrpl.org/nftokenpage.html#nftokens>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NFToken {
+    pub nftoken_id: Hash256,
+    pub uri: Option<Blob>,
+}
+
+impl Serialize for NFTokenPage {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("LedgerEntryType", LedgerEntryType::NFTokenPage as u16)?;
+        s.serialize_uint32("Flags", self.flags)?;
+        if let Some(next_page_min) = self.next_page_min {
+            s.serialize_hash256("NextPageMin", next_page_min)?;
+        }
+        let mut array = s.serialize_array("NFTokens")?;
+        for nftoken in &self.nftokens {
+            array.serialize_object("NFToken", nftoken)?;
+        }
+        array.end()?;
+        if let Some(previous_page_min) = self.previous_page_min {
+            s.serialize_hash256("PreviousPageMin", previous_page_min)?;
+        }
+        s.serialize_hash256("PreviousTxnID", self.previous_txn_id)?;
+        s.serialize_uint32("PreviousTxnLgrSeq", self.previous_txn_lgr_seq)?;
+        self.unknown_fields.serialize(s)?;
+        Ok(())
+    }
+}
+
+impl Serialize for NFToken {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_hash256("NFTokenID", self.nftoken_id)?;
+        if let Some(uri) = &self.uri {
+            s.serialize_blob("URI", uri)?;
+        }
+        Ok(())
+    }
+}
+
+impl Deserialize for NFTokenPage {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            flags: UInt32,
+            next_page_min: Option<Hash256>,
+            nftokens: Vec<NFToken>,
+            previous_page_min: Option<Hash256>,
+            previous_txn_id: Option<Hash256>,
+            previous_txn_lgr_seq: Option<UInt32>,
+            unknown_fields: Object,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "LedgerEntryType" => {
+                        check_ledger_entry_type(field_accessor, LedgerEntryType::NFTokenPage)?;
+                    }
+                    "Flags" => {
+                        self.flags = field_accessor.deserialize_uint32()?;
+                    }
+                    "NextPageMin" => {
+                        self.next_page_min = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "PreviousPageMin" => {
+                        self.previous_page_min = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "PreviousTxnID" => {
+                        self.previous_txn_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "PreviousTxnLgrSeq" => {
+                        self.previous_txn_lgr_seq = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_field(field_name, field_accessor)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                mut array_deserializer: AD,
+            ) -> Result<(), E> {
+                match field_name {
+                    "NFTokens" => {
+                        while let Some(nftoken) =
+                            array_deserializer.deserialize_object("NFToken")?
+                        {
+                            self.nftokens.push(nftoken);
+                        }
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_array(field_name, array_deserializer)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.unknown_fields.visit_object(field_name, deserializer)
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(NFTokenPage {
+            flags: visitor.flags,
+            next_page_min: visitor.next_page_min,
+            nftokens: visitor.nftokens,
+            previous_page_min: visitor.previous_page_min,
+            previous_txn_id: S::Error::unwrap_field_value(
+                "PreviousTxnID",
+                visitor.previous_txn_id,
+            )?,
+            previous_txn_lgr_seq: S::Error::unwrap_field_value(
+                "PreviousTxnLgrSeq",
+                visitor.previous_txn_lgr_seq,
+            )?,
+            unknown_fields: visitor.unknown_fields,
+        })
+    }
+}
+
+impl Deserialize for NFToken {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            nftoken_id: Option<Hash256>,
+            uri: Option<Blob>,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "NFTokenID" => {
+                        self.nftoken_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "URI" => {
+                        self.uri = Some(field_accessor.deserialize_blob()?);
+                    }
+                    _ => return Err(E::unexpected_field(field_name)),
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                _array_deserializer: AD,
+            ) -> Result<(), E> {
+                Err(E::unexpected_field(field_name))
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(NFToken {
+            nftoken_id: S::Error::unwrap_field_value("NFTokenID", visitor.nftoken_id)?,
+            uri: visitor.uri,
+        })
+    }
+}
@@ -0,0 +1,173 @@
+use super::check_ledger_entry_type;
+use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
+use crate::serialize::{Serialize, Serializer};
+use crate::{
+    deserialize, AccountId, Amount, Flags, Hash256, LedgerEntryType, Object, UInt32, UInt64,
+};
+use enumflags2::bitflags;
+
+/// An `Offer` ledger entry <https://xrpl.org/offer.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Offer {
+    pub account: AccountId,
+    /// Offer directory that links to the offer
+    pub book_directory: Hash256,
+    pub book_node: UInt64,
+    pub expiration: Option<UInt32>,
+    pub flags: Flags<OfferFlags>,
+    pub owner_node: UInt64,
+    pub previous_txn_id: Hash256,
+    pub previous_txn_lgr_seq: UInt32,
+    pub sequence: UInt32,
+    pub taker_gets: Amount,
+    pub taker_pays: Amount,
+    /// Fields not modelled by this type, see [`Object`]
+    pub unknown_fields: Object,
+}
+
+/// `Offer` flags <https://xrpl.org/offer.html#offer-flags>
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum OfferFlags {
+    Passive = 0x00010000,
+    Sell = 0x00020000,
+}
+
+impl Serialize for Offer {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("LedgerEntryType", LedgerEntryType::Offer as u16)?;
+        s.serialize_account_id("Account", self.account)?;
+        s.serialize_hash256("BookDirectory", self.book_directory)?;
+        s.serialize_uint64("BookNode", self.book_node)?;
+        if let Some(expiration) = self.expiration {
+            s.serialize_uint32("Expiration", expiration)?;
+        }
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_uint64("OwnerNode", self.owner_node)?;
+        s.serialize_hash256("PreviousTxnID", self.previous_txn_id)?;
+        s.serialize_uint32("PreviousTxnLgrSeq", self.previous_txn_lgr_seq)?;
+        s.serialize_uint32("Sequence", self.sequence)?;
+        s.serialize_amount("TakerGets", self.taker_gets)?;
+        s.serialize_amount("TakerPays", self.taker_pays)?;
+        self.unknown_fields.serialize(s)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for Offer {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            account: Option<AccountId>,
+            book_directory: Option<Hash256>,
+            book_node: Option<UInt64>,
+            expiration: Option<UInt32>,
+            flags: Flags<OfferFlags>,
+            owner_node: Option<UInt64>,
+            previous_txn_id: Option<Hash256>,
+            previous_txn_lgr_seq: Option<UInt32>,
+            sequence: Option<UInt32>,
+            taker_gets: Option<Amount>,
+            taker_pays: Option<Amount>,
+            unknown_fields: Object,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "LedgerEntryType" => {
+                        check_ledger_entry_type(field_accessor, LedgerEntryType::Offer)?;
+                    }
+                    "Account" => {
+                        self.account = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    "BookDirectory" => {
+                        self.book_directory = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "BookNode" => {
+                        self.book_node = Some(field_accessor.deserialize_uint64()?);
+                    }
+                    "Expiration" => {
+                        self.expiration = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "Flags" => {
+                        self.flags = Flags::from_bits(field_accessor.deserialize_uint32()?);
+                    }
+                    "OwnerNode" => {
+                        self.owner_node = Some(field_accessor.deserialize_uint64()?);
+                    }
+                    "PreviousTxnID" => {
+                        self.previous_txn_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "PreviousTxnLgrSeq" => {
+                        self.previous_txn_lgr_seq = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "Sequence" => {
+                        self.sequence = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "TakerGets" => {
+                        self.taker_gets = Some(field_accessor.deserialize_amount()?);
+                    }
+                    "TakerPays" => {
+                        self.taker_pays = Some(field_accessor.deserialize_amount()?);
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_field(field_name, field_accessor)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                array_deserializer: AD,
+            ) -> Result<(), E> {
+                self.unknown_fields
+                    .visit_array(field_name, array_deserializer)
+            }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.unknown_fields.visit_object(field_name, deserializer)
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(Offer {
+            account: S::Error::unwrap_field_value("Account", visitor.account)?,
+            book_directory: S::Error::unwrap_field_value("BookDirectory", visitor.book_directory)?,
+            book_node: S::Error::unwrap_field_value("BookNode", visitor.book_node)?,
+            expiration: visitor.expiration,
+            flags: visitor.flags,
+            owner_node: S::Error::unwrap_field_value("OwnerNode", visitor.owner_node)?,
+            previous_txn_id: S::Error::unwrap_field_value(
+                "PreviousTxnID",
+                visitor.previous_txn_id,
+            )?,
+            previous_txn_lgr_seq: S::Error::unwrap_field_value(
+                "PreviousTxnLgrSeq",
+                visitor.previous_txn_lgr_seq,
+            )?,
+            sequence: S::Error::unwrap_field_value("Sequence", visitor.sequence)?,
+            taker_gets: S::Error::unwrap_field_value("TakerGets", visitor.taker_gets)?,
+            taker_pays: S::Error::unwrap_field_value("TakerPays", visitor.taker_pays)?,
+            unknown_fields: visitor.unknown_fields,
+        })
+    }
+}
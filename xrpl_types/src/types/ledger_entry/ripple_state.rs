@@ -0,0 +1,212 @@
+use super::{check_ledger_entry_type, issued_amount};
+use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
+use crate::serialize::{Serialize, Serializer};
+use crate::{
+    deserialize, Amount, Flags, Hash256, IssuedAmount, LedgerEntryType, Object, UInt32, UInt64,
+};
+use enumflags2::bitflags;
+
+/// A `RippleState` ledger entry, a trust line between two accounts
+/// <https://xrpl.org/ripplestate.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RippleState {
+    /// Balance from the perspective of the low account. The issuer is the special
+    /// `ACCOUNT_ONE` address.
+    pub balance: IssuedAmount,
+    pub flags: Flags<RippleStateFlags>,
+    /// Limit set by the high account, which is the issuer of the amount
+    pub high_limit: IssuedAmount,
+    pub high_node: Option<UInt64>,
+    pub high_quality_in: Option<UInt32>,
+    pub high_quality_out: Option<UInt32>,
+    /// Limit set by the low account, which is the issuer of the amount
+    pub low_limit: IssuedAmount,
+    pub low_node: Option<UInt64>,
+    pub low_quality_in: Option<UInt32>,
+    pub low_quality_out: Option<UInt32>,
+    pub previous_txn_id: Hash256,
+    pub previous_txn_lgr_seq: UInt32,
+    /// Fields not modelled by this type, see [`Object`]
+    pub unknown_fields: Object,
+}
+
+/// `RippleState` flags <https://xrpl.org/ripplestate.html#ripplestate-flags>
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RippleStateFlags {
+    LowReserve = 0x00010000,
+    HighReserve = 0x00020000,
+    LowAuth = 0x00040000,
+    HighAuth = 0x00080000,
+    LowNoRipple = 0x00100000,
+    HighNoRipple = 0x00200000,
+    LowFreeze = 0x00400000,
+    HighFreeze = 0x00800000,
+    AMMNode = 0x01000000,
+    LowDeepFreeze = 0x02000000,
+    HighDeepFreeze = 0x04000000,
+}
+
+impl Serialize for RippleState {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("LedgerEntryType", LedgerEntryType::RippleState as u16)?;
+        s.serialize_amount("Balance", Amount::Issued(self.balance))?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_amount("HighLimit", Amount::Issued(self.high_limit))?;
+        if let Some(high_node) = self.high_node {
+            s.serialize_uint64("HighNode", high_node)?;
+        }
+        if let Some(high_quality_in) = self.high_quality_in {
+            s.serialize_uint32("HighQualityIn", high_quality_in)?;
+        }
+        if let Some(high_quality_out) = self.high_quality_out {
+            s.serialize_uint32("HighQualityOut", high_quality_out)?;
+        }
+        s.serialize_amount("LowLimit", Amount::Issued(self.low_limit))?;
+        if let Some(low_node) = self.low_node {
+            s.serialize_uint64("LowNode", low_node)?;
+        }
+        if let Some(low_quality_in) = self.low_quality_in {
+            s.serialize_uint32("LowQualityIn", low_quality_in)?;
+        }
+        if let Some(low_quality_out) = self.low_quality_out {
+            s.serialize_uint32("LowQualityOut", low_quality_out)?;
+        }
+        s.serialize_hash256("PreviousTxnID", self.previous_txn_id)?;
+        s.serialize_uint32("PreviousTxnLgrSeq", self.previous_txn_lgr_seq)?;
+        self.unknown_fields.serialize(s)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for RippleState {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            balance: Option<IssuedAmount>,
+            flags: Flags<RippleStateFlags>,
+            high_limit: Option<IssuedAmount>,
+            high_node: Option<UInt64>,
+            high_quality_in: Option<UInt32>,
+            high_quality_out: Option<UInt32>,
+            low_limit: Option<IssuedAmount>,
+            low_node: Option<UInt64>,
+            low_quality_in: Option<UInt32>,
+            low_quality_out: Option<UInt32>,
+            previous_txn_id: Option<Hash256>,
+            previous_txn_lgr_seq: Option<UInt32>,
+            unknown_fields: Object,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "LedgerEntryType" => {
+                        check_ledger_entry_type(field_accessor, LedgerEntryType::RippleState)?;
+                    }
+                    "Balance" => {
+                        self.balance = Some(issued_amount(
+                            field_name,
+                            field_accessor.deserialize_amount()?,
+                        )?);
+                    }
+                    "Flags" => {
+                        self.flags = Flags::from_bits(field_accessor.deserialize_uint32()?);
+                    }
+                    "HighLimit" => {
+                        self.high_limit = Some(issued_amount(
+                            field_name,
+                            field_accessor.deserialize_amount()?,
+                        )?);
+                    }
+                    "HighNode" => {
+                        self.high_node = Some(field_accessor.deserialize_uint64()?);
+                    }
+                    "HighQualityIn" => {
+                        self.high_quality_in = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "HighQualityOut" => {
+                        self.high_quality_out = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "LowLimit" => {
+                        self.low_limit = Some(issued_amount(
+                            field_name,
+                            field_accessor.deserialize_amount()?,
+                        )?);
+                    }
+                    "LowNode" => {
+                        self.low_node = Some(field_accessor.deserialize_uint64()?);
+                    }
+                    "LowQualityIn" => {
+                        self.low_quality_in = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "LowQualityOut" => {
+                        self.low_quality_out = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "PreviousTxnID" => {
+                        self.previous_txn_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "PreviousTxnLgrSeq" => {
+                        self.previous_txn_lgr_seq = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_field(field_name, field_accessor)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                array_deserializer: AD,
+            ) -> Result<(), E> {
+                self.unknown_fields
+                    .visit_array(field_name, array_deserializer)
+            }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.unknown_fields.visit_object(field_name, deserializer)
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(RippleState {
+            balance: S::Error::unwrap_field_value("Balance", visitor.balance)?,
+            flags: visitor.flags,
+            high_limit: S::Error::unwrap_field_value("HighLimit", visitor.high_limit)?,
+            high_node: visitor.high_node,
+            high_quality_in: visitor.high_quality_in,
+            high_quality_out: visitor.high_quality_out,
+            low_limit: S::Error::unwrap_field_value("LowLimit", visitor.low_limit)?,
+            low_node: visitor.low_node,
+            low_quality_in: visitor.low_quality_in,
+            low_quality_out: visitor.low_quality_out,
+            previous_txn_id: S::Error::unwrap_field_value(
+                "PreviousTxnID",
+                visitor.previous_txn_id,
+            )?,
+            previous_txn_lgr_seq: S::Error::unwrap_field_value(
+                "PreviousTxnLgrSeq",
+                visitor.previous_txn_lgr_seq,
+            )?,
+            unknown_fields: visitor.unknown_fields,
+        })
+    }
+}
@@ -0,0 +1,231 @@
+use super::check_ledger_entry_type;
+use crate::alloc::vec::Vec;
+use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
+use crate::serialize::{ArraySerializer, Serialize, Serializer};
+use crate::{
+    deserialize, AccountId, Flags, Hash256, LedgerEntryType, Object, UInt16, UInt32, UInt64,
+};
+use enumflags2::bitflags;
+
+/// A `SignerList` ledger entry, the signers that can multi-sign for an account
+/// <https://xrpl.org/signerlist.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SignerList {
+    pub flags: Flags<SignerListFlags>,
+    pub owner_node: UInt64,
+    pub previous_txn_id: Hash256,
+    pub previous_txn_lgr_seq: UInt32,
+    pub signer_entries: Vec<SignerEntry>,
+    /// Always 0
+    pub signer_list_id: UInt32,
+    /// Sum of signer weights required for a valid multi-signature
+    pub signer_quorum: UInt32,
+    /// Fields not modelled by this type, see [`Object`]
+    pub unknown_fields: Object,
+}
+
+/// `SignerList` flags <https://xrpl.org/signerlist.html#signerlist-flags>
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SignerListFlags {
+    OneOwnerCount = 0x00010000,
+}
+
+/// Signer in a `SignerList` <https://xrpl.org/signerlist.html#signer-entry-object>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SignerEntry {
+    pub account: AccountId,
+    pub signer_weight: UInt16,
+    pub wallet_locator: Option<Hash256>,
+}
+
+impl Serialize for SignerList {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("LedgerEntryType", LedgerEntryType::SignerList as u16)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_uint64("OwnerNode", self.owner_node)?;
+        s.serialize_hash256("PreviousTxnID", self.previous_txn_id)?;
+        s.serialize_uint32("PreviousTxnLgrSeq", self.previous_txn_lgr_seq)?;
+        let mut array = s.serialize_array("SignerEntries")?;
+        for signer_entry in &self.signer_entries {
+            array.serialize_object("SignerEntry", signer_entry)?;
+        }
+        array.end()?;
+        s.serialize_uint32("SignerListID", self.signer_list_id)?;
+        s.serialize_uint32("SignerQuorum", self.signer_quorum)?;
+        self.unknown_fields.serialize(s)?;
+        Ok(())
+    }
+}
+
+impl Serialize for SignerEntry {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_account_id("Account", self.account)?;
+        s.serialize_uint16("SignerWeight", self.signer_weight)?;
+        if let Some(wallet_locator) = self.wallet_locator {
+            s.serialize_hash256("WalletLocator", wallet_locator)?;
+        }
+        Ok(())
+    }
+}
+
+impl Deserialize for SignerList {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            flags: Flags<SignerListFlags>,
+            owner_node: Option<UInt64>,
+            previous_txn_id: Option<Hash256>,
+            previous_txn_lgr_seq: Option<UInt32>,
+            signer_entries: Vec<SignerEntry>,
+            signer_list_id: Option<UInt32>,
+            signer_quorum: Option<UInt32>,
+            unknown_fields: Object,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "LedgerEntryType" => {
+                        check_ledger_entry_type(field_accessor, LedgerEntryType::SignerList)?;
+                    }
+                    "Flags" => {
+                        self.flags = Flags::from_bits(field_accessor.deserialize_uint32()?);
+                    }
+                    "OwnerNode" => {
+                        self.owner_node = Some(field_accessor.deserialize_uint64()?);
+                    }
+                    "PreviousTxnID" => {
+                        self.previous_txn_id = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    "PreviousTxnLgrSeq" => {
+                        self.previous_txn_lgr_seq = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "SignerListID" => {
+                        self.signer_list_id = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "SignerQuorum" => {
+                        self.signer_quorum = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_field(field_name, field_accessor)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                mut array_deserializer: AD,
+            ) -> Result<(), E> {
+                match field_name {
+                    "SignerEntries" => {
+                        while let Some(signer_entry) =
+                            array_deserializer.deserialize_object("SignerEntry")?
+                        {
+                            self.signer_entries.push(signer_entry);
+                        }
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_array(field_name, array_deserializer)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.unknown_fields.visit_object(field_name, deserializer)
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(SignerList {
+            flags: visitor.flags,
+            owner_node: S::Error::unwrap_field_value("OwnerNode", visitor.owner_node)?,
+            previous_txn_id: S::Error::unwrap_field_value(
+                "PreviousTxnID",
+                visitor.previous_txn_id,
+            )?,
+            previous_txn_lgr_seq: S::Error::unwrap_field_value(
+                "PreviousTxnLgrSeq",
+                visitor.previous_txn_lgr_seq,
+            )?,
+            signer_entries: visitor.signer_entries,
+            signer_list_id: S::Error::unwrap_field_value("SignerListID", visitor.signer_list_id)?,
+            signer_quorum: S::Error::unwrap_field_value("SignerQuorum", visitor.signer_quorum)?,
+            unknown_fields: visitor.unknown_fields,
+        })
+    }
+}
+
+impl Deserialize for SignerEntry {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            account: Option<AccountId>,
+            signer_weight: Option<UInt16>,
+            wallet_locator: Option<Hash256>,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "Account" => {
+                        self.account = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    "SignerWeight" => {
+                        self.signer_weight = Some(field_accessor.deserialize_uint16()?);
+                    }
+                    "WalletLocator" => {
+                        self.wallet_locator = Some(field_accessor.deserialize_hash256()?);
+                    }
+                    _ => return Err(E::unexpected_field(field_name)),
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                _array_deserializer: AD,
+            ) -> Result<(), E> {
+                Err(E::unexpected_field(field_name))
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(SignerEntry {
+            account: S::Error::unwrap_field_value("Account", visitor.account)?,
+            signer_weight: S::Error::unwrap_field_value("SignerWeight", visitor.signer_weight)?,
+            wallet_locator: visitor.wallet_locator,
+        })
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{Hash256, LedgerTimestamp, UInt32, UInt64, UInt8};
+
+/// Header of a ledger version <https://xrpl.org/ledger-header.html>
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct LedgerHeader {
+    pub ledger_index: UInt32,
+    /// Total number of XRP drops in existence
+    pub total_coins: UInt64,
+    pub parent_hash: Hash256,
+    /// Root hash of the transaction tree of this ledger
+    pub transaction_hash: Hash256,
+    /// Root hash of the state tree of this ledger
+    pub account_hash: Hash256,
+    pub parent_close_time: LedgerTimestamp,
+    pub close_time: LedgerTimestamp,
+    /// Number of seconds that close times are rounded to
+    pub close_time_resolution: UInt8,
+    /// Flags, see <https://xrpl.org/ledger-header.html#close-flags>
+    pub close_flags: UInt8,
+}
+
+impl LedgerHeader {
+    /// Returns true if the validators did not agree on a close time, which is the
+    /// `sLCF_NoConsensusTime` close flag
+    pub fn no_consensus_time(&self) -> bool {
+        self.close_flags & 0x01 != 0
+    }
+}
@@ -0,0 +1,129 @@
+use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
+use crate::serialize::{Serialize, Serializer};
+use crate::{deserialize, Blob, UInt16, UInt32};
+
+/// Validator manifest, which binds the ephemeral signing key of a validator to its master key
+/// <https://xrpl.org/manifest.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Manifest {
+    /// Master public key of the validator
+    pub public_key: Blob,
+    pub sequence: UInt32,
+    /// Ephemeral public key used to sign validations. Omitted in revocations.
+    pub signing_pub_key: Option<Blob>,
+    /// Signature by the ephemeral key. Omitted in revocations.
+    pub signature: Option<Blob>,
+    /// Signature by the master key
+    pub master_signature: Blob,
+    /// Domain of the validator, as ASCII
+    pub domain: Option<Blob>,
+    pub version: Option<UInt16>,
+}
+
+impl Manifest {
+    /// Sequence of a manifest that revokes the master key
+    pub const REVOCATION_SEQUENCE: UInt32 = UInt32::MAX;
+
+    /// Returns true if the manifest revokes the master key
+    pub fn is_revocation(&self) -> bool {
+        self.sequence == Self::REVOCATION_SEQUENCE
+    }
+}
+
+impl Serialize for Manifest {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_blob("PublicKey", &self.public_key)?;
+        s.serialize_uint32("Sequence", self.sequence)?;
+        if let Some(signing_pub_key) = &self.signing_pub_key {
+            s.serialize_blob("SigningPubKey", signing_pub_key)?;
+        }
+        if let Some(signature) = &self.signature {
+            s.serialize_blob("Signature", signature)?;
+        }
+        s.serialize_blob("MasterSignature", &self.master_signature)?;
+        if let Some(domain) = &self.domain {
+            s.serialize_blob("Domain", domain)?;
+        }
+        if let Some(version) = self.version {
+            s.serialize_uint16("Version", version)?;
+        }
+        Ok(())
+    }
+}
+
+impl Deserialize for Manifest {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            public_key: Option<Blob>,
+            sequence: Option<UInt32>,
+            signing_pub_key: Option<Blob>,
+            signature: Option<Blob>,
+            master_signature: Option<Blob>,
+            domain: Option<Blob>,
+            version: Option<UInt16>,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "PublicKey" => {
+                        self.public_key = Some(field_accessor.deserialize_blob()?);
+                    }
+                    "Sequence" => {
+                        self.sequence = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "SigningPubKey" => {
+                        self.signing_pub_key = Some(field_accessor.deserialize_blob()?);
+                    }
+                    "Signature" => {
+                        self.signature = Some(field_accessor.deserialize_blob()?);
+                    }
+                    "MasterSignature" => {
+                        self.master_signature = Some(field_accessor.deserialize_blob()?);
+                    }
+                    "Domain" => {
+                        self.domain = Some(field_accessor.deserialize_blob()?);
+                    }
+                    "Version" => {
+                        self.version = Some(field_accessor.deserialize_uint16()?);
+                    }
+                    _ => return Err(E::unexpected_field(field_name)),
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                _array_deserializer: AD,
+            ) -> Result<(), E> {
+                Err(E::unexpected_field(field_name))
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(Manifest {
+            public_key: S::Error::unwrap_field_value("PublicKey", visitor.public_key)?,
+            sequence: S::Error::unwrap_field_value("Sequence", visitor.sequence)?,
+            signing_pub_key: visitor.signing_pub_key,
+            signature: visitor.signature,
+            master_signature: S::Error::unwrap_field_value(
+                "MasterSignature",
+                visitor.master_signature,
+            )?,
+            domain: visitor.domain,
+            version: visitor.version,
+        })
+    }
+}
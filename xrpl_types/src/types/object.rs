@@ -0,0 +1,139 @@
+use crate::alloc::string::{String, ToString};
+use crate::alloc::vec::Vec;
+use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
+use crate::serialize::{ArraySerializer, Serialize, Serializer};
+use crate::{
+    deserialize, AccountId, Amount, Blob, Currency, Hash128, Hash160, Hash256, PathSet, UInt16,
+    UInt32, UInt64, UInt8, XChainBridge,
+};
+
+/// Value of a field of any type <https://xrpl.org/serialization.html#type-list>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum FieldValue {
+    AccountId(AccountId),
+    Amount(Amount),
+    Blob(Blob),
+    Hash128(Hash128),
+    Hash160(Hash160),
+    Hash256(Hash256),
+    UInt8(UInt8),
+    UInt16(UInt16),
+    UInt32(UInt32),
+    UInt64(UInt64),
+    PathSet(PathSet),
+    Vector256(Vec<Hash256>),
+    Issue(Currency),
+    XChainBridge(XChainBridge),
+    Object(Object),
+    /// Array elements, each given by the field name of the element and the element object
+    Array(Vec<(String, Object)>),
+}
+
+/// Object with fields of any type, for objects that are not modelled by a specific type.
+/// Fields are kept in the order they are deserialized.
+///
+/// Modelled transactions and ledger entries keep the fields they do not model in an `Object`
+/// named `unknown_fields`, such that serializing them again gives the bytes that were deserialized.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct Object(pub Vec<(String, FieldValue)>);
+
+impl FieldValue {
+    /// Serializes the value as the field with the given name
+    pub fn serialize_field<S: Serializer>(
+        &self,
+        field_name: &str,
+        s: &mut S,
+    ) -> Result<(), S::Error> {
+        match self {
+            FieldValue::AccountId(value) => s.serialize_account_id(field_name, *value),
+            FieldValue::Amount(value) => s.serialize_amount(field_name, *value),
+            FieldValue::Blob(value) => s.serialize_blob(field_name, value),
+            FieldValue::Hash128(value) => s.serialize_hash128(field_name, *value),
+            FieldValue::Hash160(value) => s.serialize_hash160(field_name, *value),
+            FieldValue::Hash256(value) => s.serialize_hash256(field_name, *value),
+            FieldValue::UInt8(value) => s.serialize_uint8(field_name, *value),
+            FieldValue::UInt16(value) => s.serialize_uint16(field_name, *value),
+            FieldValue::UInt32(value) => s.serialize_uint32(field_name, *value),
+            FieldValue::UInt64(value) => s.serialize_uint64(field_name, *value),
+            FieldValue::PathSet(value) => s.serialize_path_set(field_name, value),
+            FieldValue::Vector256(value) => s.serialize_vector256(field_name, value),
+            FieldValue::Issue(value) => s.serialize_issue(field_name, *value),
+            FieldValue::XChainBridge(value) => s.serialize_xchain_bridge(field_name, value),
+            FieldValue::Object(value) => s.serialize_object(field_name, value),
+            FieldValue::Array(elements) => {
+                let mut array = s.serialize_array(field_name)?;
+                for (element_name, element) in elements {
+                    array.serialize_object(element_name, element)?;
+                }
+                array.end()
+            }
+        }
+    }
+}
+
+impl Object {
+    /// Value of the field with the given name
+    pub fn get(&self, field_name: &str) -> Option<&FieldValue> {
+        self.0
+            .iter()
+            .find(|(name, _)| name == field_name)
+            .map(|(_, value)| value)
+    }
+}
+
+impl Serialize for Object {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        for (field_name, value) in &self.0 {
+            value.serialize_field(field_name, s)?;
+        }
+        Ok(())
+    }
+}
+
+impl deserialize::Visitor for Object {
+    fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+        &mut self,
+        field_name: &str,
+        field_accessor: F,
+    ) -> Result<(), E> {
+        self.0
+            .push((field_name.to_string(), field_accessor.deserialize_any()?));
+        Ok(())
+    }
+
+    fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+        &mut self,
+        field_name: &str,
+        mut array_deserializer: AD,
+    ) -> Result<(), E> {
+        let mut elements = Vec::new();
+        while let Some(element) = array_deserializer.deserialize_any_object()? {
+            elements.push(element);
+        }
+        self.0
+            .push((field_name.to_string(), FieldValue::Array(elements)));
+        Ok(())
+    }
+
+    fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+        &mut self,
+        field_name: &str,
+        deserializer: D,
+    ) -> Result<(), E> {
+        let object = Object::deserialize(deserializer)?;
+        self.0
+            .push((field_name.to_string(), FieldValue::Object(object)));
+        Ok(())
+    }
+}
+
+impl Deserialize for Object {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        let mut object = Object::default();
+        deserializer.deserialize(&mut object)?;
+        Ok(object)
+    }
+}
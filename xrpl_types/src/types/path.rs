@@ -0,0 +1,47 @@
+use crate::alloc::vec::Vec;
+use crate::{AccountId, CurrencyCode};
+
+/// Step in a payment path <https://xrpl.org/paths.html#path-specifications>.
+/// A step either specifies an account to ripple through, or a currency and/or issuer
+/// to convert through using an order book. At least one of the fields must be set.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct PathStep {
+    pub account: Option<AccountId>,
+    pub currency: Option<CurrencyCode>,
+    pub issuer: Option<AccountId>,
+}
+
+impl PathStep {
+    /// Step rippling through the given account
+    pub fn account(account: AccountId) -> Self {
+        Self {
+            account: Some(account),
+            ..Default::default()
+        }
+    }
+
+    /// Step converting to the given currency through an order book
+    pub fn currency(currency: CurrencyCode, issuer: Option<AccountId>) -> Self {
+        Self {
+            currency: Some(currency),
+            issuer,
+            ..Default::default()
+        }
+    }
+
+    /// Step changing the issuer of the current currency through an order book
+    pub fn issuer(issuer: AccountId) -> Self {
+        Self {
+            issuer: Some(issuer),
+            ..Default::default()
+        }
+    }
+}
+
+/// Payment path <https://xrpl.org/paths.html>
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct Path(pub Vec<PathStep>);
+
+/// Set of alternative payment paths, as in the `Paths` field <https://xrpl.org/serialization.html#pathset-fields>
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct PathSet(pub Vec<Path>);
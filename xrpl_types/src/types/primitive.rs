@@ -1,6 +1,14 @@
-use crate::alloc::{format, string::{String, ToString}, vec::Vec};
+use crate::alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 use crate::Error;
-use core::{fmt, fmt::{Debug, Formatter}};
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+};
+use xrpl_address_codec::AddressCodecError;
 
 #[derive(Clone, Copy, Default, Eq, PartialEq, Hash)]
 pub struct AccountId(pub [u8; 20]);
@@ -8,17 +16,11 @@ pub struct AccountId(pub [u8; 20]);
 impl AccountId {
     /// Decodes account id from address, see <https://xrpl.org/accounts.html#address-encoding>
     pub fn from_address(address: &str) -> Result<Self, Error> {
-        let decoded = bs58::decode(address)
-            .with_alphabet(bs58::Alphabet::RIPPLE)
-            .with_check(Some(0u8))
-            .into_vec()
-            .map_err(|err| Error::InvalidData(format!("invalid address: {}", err)))?;
-
-        // Skip the 0x00 ('r') version prefix
-        let decoded = &decoded[1..];
-
-        let bytes: [u8; 20] = decoded.try_into().map_err(|_| {
-            Error::InvalidData("address does not encode exactly 20 bytes".to_string())
+        let bytes = xrpl_address_codec::decode_account_id(address).map_err(|err| match err {
+            AddressCodecError::InvalidLength(_) => {
+                Error::InvalidData("address does not encode exactly 20 bytes".to_string())
+            }
+            err => Error::InvalidData(format!("invalid address: {}", err)),
         })?;
 
         Ok(Self(bytes))
@@ -26,10 +28,57 @@ impl AccountId {
 
     /// Encodes account id to address, see <https://xrpl.org/accounts.html#address-encoding>
     pub fn to_address(&self) -> String {
-        bs58::encode(&self.0)
-            .with_alphabet(bs58::Alphabet::RIPPLE)
-            .with_check_version(0u8) // Add the 0x00 ('r') version prefix
-            .into_string()
+        xrpl_address_codec::encode_account_id(&self.0)
+    }
+
+    /// Decodes account id and tag from X-address, see <https://xrpl.org/addresses.html#x-addresses>
+    pub fn from_x_address(x_address: &str) -> Result<(Self, Option<UInt32>), Error> {
+        let decoded = xrpl_address_codec::decode_x_address(x_address)
+            .map_err(|err| Error::InvalidData(format!("invalid X-address: {}", err)))?;
+
+        Ok((Self(decoded.account_id), decoded.tag))
+    }
+
+    /// Encodes account id and tag to X-address, see <https://xrpl.org/addresses.html#x-addresses>
+    pub fn to_x_address(&self, tag: Option<UInt32>, is_test_network: bool) -> String {
+        xrpl_address_codec::encode_x_address(&self.0, tag, is_test_network)
+    }
+
+    /// Decodes account id and tag from either a classic address and the given tag, or from an
+    /// X-address. An error is returned if the X-address is not for the expected network, given
+    /// by `is_test_network`, or if it contains a tag and is given together with a different tag.
+    pub fn from_address_and_tag(
+        address: &str,
+        tag: Option<UInt32>,
+        is_test_network: bool,
+    ) -> Result<(Self, Option<UInt32>), Error> {
+        if !xrpl_address_codec::is_valid_x_address(address) {
+            return Ok((Self::from_address(address)?, tag));
+        }
+
+        let decoded = xrpl_address_codec::decode_x_address(address)
+            .map_err(|err| Error::InvalidData(format!("invalid X-address: {}", err)))?;
+        if decoded.is_test_network != is_test_network {
+            return Err(Error::InvalidData(format!(
+                "X-address is for {}, expected {}",
+                network_name(decoded.is_test_network),
+                network_name(is_test_network)
+            )));
+        }
+        match (decoded.tag, tag) {
+            (Some(x_address_tag), Some(tag)) if x_address_tag != tag => Err(Error::InvalidData(
+                format!("X-address tag {} does not match tag {}", x_address_tag, tag),
+            )),
+            (x_address_tag, tag) => Ok((Self(decoded.account_id), x_address_tag.or(tag))),
+        }
+    }
+}
+
+fn network_name(is_test_network: bool) -> &'static str {
+    if is_test_network {
+        "test network"
+    } else {
+        "main network"
     }
 }
 
@@ -243,6 +292,101 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_account_id_from_x_address() {
+        let (account_id, tag) =
+            AccountId::from_x_address("XVLhHMPHU98es4dbozjVtdWzVrDjtV18pX8yuPT7y4xaEHi").unwrap();
+        assert_eq!(
+            account_id.to_address(),
+            "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf"
+        );
+        assert_eq!(tag, Some(u32::MAX));
+    }
+
+    #[test]
+    fn test_account_id_to_x_address() {
+        let account_id = AccountId::from_address("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf").unwrap();
+        assert_eq!(
+            account_id.to_x_address(None, false),
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb"
+        );
+        assert_eq!(
+            account_id.to_x_address(Some(1), true),
+            "TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw"
+        );
+    }
+
+    #[test]
+    fn test_account_id_from_address_and_tag() {
+        let expected = AccountId::from_address("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf").unwrap();
+
+        let result =
+            AccountId::from_address_and_tag("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf", Some(2), false);
+        assert_eq!(result, Ok((expected, Some(2))));
+
+        let result = AccountId::from_address_and_tag(
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC",
+            None,
+            false,
+        );
+        assert_eq!(result, Ok((expected, Some(1))));
+
+        let result = AccountId::from_address_and_tag(
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC",
+            Some(1),
+            false,
+        );
+        assert_eq!(result, Ok((expected, Some(1))));
+
+        let result = AccountId::from_address_and_tag(
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb",
+            Some(3),
+            false,
+        );
+        assert_eq!(result, Ok((expected, Some(3))));
+    }
+
+    /// X-address with embedded tag and a different explicit tag is contradictory
+    #[test]
+    fn test_account_id_from_address_and_tag_mismatch() {
+        let result = AccountId::from_address_and_tag(
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC",
+            Some(2),
+            false,
+        );
+        assert_matches!(result, Err(Error::InvalidData(message)) => {
+           assert!(message.contains("X-address tag 1 does not match tag 2"), "message: {message}")
+        });
+    }
+
+    /// X-address of the main network is rejected when the test network is expected, and vice versa
+    #[test]
+    fn test_account_id_from_address_and_tag_network_mismatch() {
+        let result = AccountId::from_address_and_tag(
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC",
+            None,
+            true,
+        );
+        assert_matches!(result, Err(Error::InvalidData(message)) => {
+           assert!(message.contains("X-address is for main network, expected test network"), "message: {message}")
+        });
+
+        let result = AccountId::from_address_and_tag(
+            "TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw",
+            None,
+            false,
+        );
+        assert_matches!(result, Err(Error::InvalidData(_)));
+
+        let expected = AccountId::from_address("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf").unwrap();
+        let result = AccountId::from_address_and_tag(
+            "TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw",
+            None,
+            true,
+        );
+        assert_eq!(result, Ok((expected, Some(1))));
+    }
+
     #[test]
     fn test_hash128_from_hex() {
         let hash = Hash128::from_hex("A00000000000000000000000000000A1").unwrap();
@@ -1,13 +1,28 @@
 use crate::alloc::vec::Vec;
 use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
-use crate::serialize::{ArraySerializer, Serialize, Serializer};
-use crate::{deserialize, AccountId, Amount, Blob, DropsAmount, Hash256, UInt32};
+use crate::serialize::{ArraySerializer, CanonicalSerialize, Serialize, Serializer};
+use crate::{
+    deserialize, AccountId, Amount, Blob, DropsAmount, Hash256, Object, TransactionType, UInt32,
+};
 
-#[derive(Debug, Clone)]
+/// Memo of a transaction <https://xrpl.org/transaction-common-fields.html#memos-field>
+#[derive(Debug, Clone, Default)]
 pub struct Memo {
-    pub memo_type: Blob,
-    pub memo_data: Blob,
+    pub memo_type: Option<Blob>,
+    pub memo_data: Option<Blob>,
     pub memo_format: Option<Blob>,
+    /// Fields not modelled by this type, see [`Object`]
+    pub unknown_fields: Object,
+}
+
+/// Signature of a single signer of a multi-signed transaction <https://xrpl.org/transaction-common-fields.html#signers-field>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signer {
+    pub account: AccountId,
+    pub txn_signature: Blob,
+    pub signing_pub_key: Blob,
+    /// Fields not modelled by this type, see [`Object`]
+    pub unknown_fields: Object,
 }
 
 /// A ledger transaction <https://xrpl.org/transaction-formats.html>
@@ -22,8 +37,11 @@ pub struct TransactionCommon {
     pub network_id: Option<UInt32>,
     pub source_tag: Option<UInt32>,
     pub signing_pub_key: Option<Blob>,
+    pub signers: Vec<Signer>,
     pub ticket_sequence: Option<UInt32>,
     pub txn_signature: Option<Blob>,
+    /// Fields not modelled by this type, see [`Object`]
+    pub unknown_fields: Object,
 }
 
 impl TransactionCommon {
@@ -38,10 +56,105 @@ impl TransactionCommon {
             network_id: None,
             source_tag: None,
             signing_pub_key: None,
+            signers: Vec::default(),
             ticket_sequence: None,
             txn_signature: None,
+            unknown_fields: Object::default(),
         }
     }
+
+    /// Serializes the transaction type, the flags and the common fields in canonical field order
+    /// <https://xrpl.org/serialization.html#canonical-field-order>, for transactions implementing
+    /// [`CanonicalSerialize`]. Before each common field, `serialize_fields` is called with that
+    /// field to serialize the fields of the transaction type that sort before it, also if the
+    /// common field is absent. It is called with `None` last, for the fields that sort after all
+    /// common fields. The unknown fields are passed to [`Serializer::serialize_unordered_fields`]
+    pub(crate) fn serialize_canonical<S: Serializer>(
+        &self,
+        s: &mut S,
+        transaction_type: TransactionType,
+        flags: Option<UInt32>,
+        mut serialize_fields: impl FnMut(&mut S, Option<CommonField>) -> Result<(), S::Error>,
+    ) -> Result<(), S::Error> {
+        s.serialize_unordered_fields(&self.unknown_fields)?;
+        s.serialize_uint16("TransactionType", transaction_type as u16)?;
+        serialize_fields(s, Some(CommonField::NetworkID))?;
+        if let Some(network_id) = self.network_id {
+            s.serialize_uint32("NetworkID", network_id)?;
+        }
+        if let Some(flags) = flags {
+            s.serialize_uint32("Flags", flags)?;
+        }
+        serialize_fields(s, Some(CommonField::SourceTag))?;
+        if let Some(source_tag) = self.source_tag {
+            s.serialize_uint32("SourceTag", source_tag)?;
+        }
+        serialize_fields(s, Some(CommonField::Sequence))?;
+        if let Some(sequence) = self.sequence {
+            s.serialize_uint32("Sequence", sequence)?;
+        }
+        serialize_fields(s, Some(CommonField::LastLedgerSequence))?;
+        if let Some(last_ledger_sequence) = self.last_ledger_sequence {
+            s.serialize_uint32("LastLedgerSequence", last_ledger_sequence)?;
+        }
+        serialize_fields(s, Some(CommonField::TicketSequence))?;
+        if let Some(ticket_sequence) = self.ticket_sequence {
+            s.serialize_uint32("TicketSequence", ticket_sequence)?;
+        }
+        serialize_fields(s, Some(CommonField::AccountTxnID))?;
+        if let Some(account_txn_id) = self.account_txn_id {
+            s.serialize_hash256("AccountTxnID", account_txn_id)?;
+        }
+        serialize_fields(s, Some(CommonField::Fee))?;
+        if let Some(fee) = self.fee {
+            s.serialize_amount("Fee", Amount::Drops(fee))?;
+        }
+        serialize_fields(s, Some(CommonField::SigningPubKey))?;
+        if let Some(signing_pub_key) = self.signing_pub_key.as_ref() {
+            s.serialize_blob("SigningPubKey", signing_pub_key)?;
+        }
+        serialize_fields(s, Some(CommonField::TxnSignature))?;
+        if let Some(txn_signature) = self.txn_signature.as_ref() {
+            s.serialize_blob("TxnSignature", txn_signature)?;
+        }
+        serialize_fields(s, Some(CommonField::Account))?;
+        s.serialize_account_id("Account", self.account)?;
+        serialize_fields(s, Some(CommonField::Signers))?;
+        if !self.signers.is_empty() {
+            let mut array = s.serialize_array("Signers")?;
+            for signer in &self.signers {
+                array.serialize_object("Signer", signer)?;
+            }
+            array.end()?;
+        }
+        serialize_fields(s, Some(CommonField::Memos))?;
+        if !self.memos.is_empty() {
+            let mut array = s.serialize_array("Memos")?;
+            for memo in &self.memos {
+                array.serialize_object("Memo", memo)?;
+            }
+            array.end()?;
+        }
+        serialize_fields(s, None)
+    }
+}
+
+/// Common transaction fields in canonical field order, except `Flags` which sorts right after
+/// `NetworkID`. See [`TransactionCommon::serialize_canonical`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CommonField {
+    NetworkID,
+    SourceTag,
+    Sequence,
+    LastLedgerSequence,
+    TicketSequence,
+    AccountTxnID,
+    Fee,
+    SigningPubKey,
+    TxnSignature,
+    Account,
+    Signers,
+    Memos,
 }
 
 impl Serialize for TransactionCommon {
@@ -65,6 +178,13 @@ impl Serialize for TransactionCommon {
             }
             array.end()?;
         }
+        if !self.signers.is_empty() {
+            let mut array = s.serialize_array("Signers")?;
+            for signer in &self.signers {
+                array.serialize_object("Signer", signer)?;
+            }
+            array.end()?;
+        }
         if let Some(ticket_sequence) = self.ticket_sequence {
             s.serialize_uint32("TicketSequence", ticket_sequence)?;
         }
@@ -81,14 +201,20 @@ impl Serialize for TransactionCommon {
             s.serialize_blob("TxnSignature", txn_signature)?;
         }
         s.serialize_account_id("Account", self.account)?;
+        self.unknown_fields.serialize(s)?;
         Ok(())
     }
 }
 
 impl Serialize for Memo {
     fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
-        s.serialize_blob("MemoType", &self.memo_type)?;
-        s.serialize_blob("MemoData", &self.memo_data)?;
+        s.serialize_unordered_fields(&self.unknown_fields)?;
+        if let Some(memo_type) = self.memo_type.as_ref() {
+            s.serialize_blob("MemoType", memo_type)?;
+        }
+        if let Some(memo_data) = self.memo_data.as_ref() {
+            s.serialize_blob("MemoData", memo_data)?;
+        }
         if let Some(memo_format) = self.memo_format.as_ref() {
             s.serialize_blob("MemoFormat", memo_format)?;
         }
@@ -96,6 +222,91 @@ impl Serialize for Memo {
     }
 }
 
+impl CanonicalSerialize for Memo {}
+
+impl Serialize for Signer {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_unordered_fields(&self.unknown_fields)?;
+        s.serialize_blob("SigningPubKey", &self.signing_pub_key)?;
+        s.serialize_blob("TxnSignature", &self.txn_signature)?;
+        s.serialize_account_id("Account", self.account)?;
+        Ok(())
+    }
+}
+
+impl CanonicalSerialize for Signer {}
+
+impl Deserialize for Signer {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            pub account: Option<AccountId>,
+            pub txn_signature: Option<Blob>,
+            pub signing_pub_key: Option<Blob>,
+            pub unknown_fields: Object,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "Account" => {
+                        self.account = Some(field_accessor.deserialize_account_id()?);
+                    }
+                    "TxnSignature" => {
+                        self.txn_signature = Some(field_accessor.deserialize_blob()?);
+                    }
+                    "SigningPubKey" => {
+                        self.signing_pub_key = Some(field_accessor.deserialize_blob()?);
+                    }
+                    _ => {
+                        self.unknown_fields
+                            .visit_field(field_name, field_accessor)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                array_deserializer: AD,
+            ) -> Result<(), E> {
+                self.unknown_fields
+                    .visit_array(field_name, array_deserializer)
+            }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.unknown_fields.visit_object(field_name, deserializer)
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(Signer {
+            account: S::Error::unwrap_field_value("Account", visitor.account)?,
+            txn_signature: S::Error::unwrap_field_value("TxnSignature", visitor.txn_signature)?,
+            signing_pub_key: S::Error::unwrap_field_value(
+                "SigningPubKey",
+                visitor.signing_pub_key,
+            )?,
+            unknown_fields: visitor.unknown_fields,
+        })
+    }
+}
+
 impl Deserialize for Memo {
     fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
     where
@@ -106,6 +317,7 @@ impl Deserialize for Memo {
             pub memo_type: Option<Blob>,
             pub memo_data: Option<Blob>,
             pub memo_format: Option<Blob>,
+            pub unknown_fields: Object,
         }
 
         impl deserialize::Visitor for Visitor {
@@ -124,17 +336,29 @@ impl Deserialize for Memo {
                     "MemoFormat" => {
                         self.memo_format = Some(field_accessor.deserialize_blob()?);
                     }
-                    _ => return Err(E::unexpected_field(field_name)),
+                    _ => {
+                        self.unknown_fields
+                            .visit_field(field_name, field_accessor)?;
+                    }
                 }
                 Ok(())
             }
 
-            fn visit_array<E: DeserError, AD: ArrayDeserializer>(
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
                 &mut self,
                 field_name: &str,
-                _array_deserializer: AD,
+                array_deserializer: AD,
             ) -> Result<(), E> {
-                Err(E::unexpected_field(field_name))
+                self.unknown_fields
+                    .visit_array(field_name, array_deserializer)
+            }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.unknown_fields.visit_object(field_name, deserializer)
             }
         }
 
@@ -143,9 +367,10 @@ impl Deserialize for Memo {
         deserializer.deserialize(&mut visitor)?;
 
         Ok(Memo {
-            memo_type: S::Error::unwrap_field_value("MemoType", visitor.memo_type)?,
-            memo_data: S::Error::unwrap_field_value("MemoData", visitor.memo_data)?,
+            memo_type: visitor.memo_type,
+            memo_data: visitor.memo_data,
             memo_format: visitor.memo_format,
+            unknown_fields: visitor.unknown_fields,
         })
     }
 }
@@ -161,8 +386,10 @@ pub struct TransactionCommonVisitor {
     pub network_id: Option<UInt32>,
     pub source_tag: Option<UInt32>,
     pub signing_pub_key: Option<Blob>,
+    pub signers: Vec<Signer>,
     pub ticket_sequence: Option<UInt32>,
     pub txn_signature: Option<Blob>,
+    pub unknown_fields: Object,
 }
 
 impl deserialize::Visitor for TransactionCommonVisitor {
@@ -207,7 +434,10 @@ impl deserialize::Visitor for TransactionCommonVisitor {
             "Account" => {
                 self.account = Some(field_accessor.deserialize_account_id()?);
             }
-            _ => return Err(E::unexpected_field(field_name)),
+            _ => {
+                self.unknown_fields
+                    .visit_field(field_name, field_accessor)?;
+            }
         }
         Ok(())
     }
@@ -223,10 +453,26 @@ impl deserialize::Visitor for TransactionCommonVisitor {
                     self.memos.push(memo);
                 }
             }
-            _ => return Err(E::unexpected_field(field_name)),
+            "Signers" => {
+                while let Some(signer) = array_deserializer.deserialize_object("Signer")? {
+                    self.signers.push(signer);
+                }
+            }
+            _ => {
+                self.unknown_fields
+                    .visit_array(field_name, array_deserializer)?;
+            }
         }
         Ok(())
     }
+
+    fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+        &mut self,
+        field_name: &str,
+        deserializer: D,
+    ) -> Result<(), E> {
+        self.unknown_fields.visit_object(field_name, deserializer)
+    }
 }
 
 impl TransactionCommonVisitor {
@@ -241,8 +487,10 @@ impl TransactionCommonVisitor {
             network_id: self.network_id,
             source_tag: self.source_tag,
             signing_pub_key: self.signing_pub_key,
+            signers: self.signers,
             ticket_sequence: self.ticket_sequence,
             txn_signature: self.txn_signature,
+            unknown_fields: self.unknown_fields,
         })
     }
 }
@@ -0,0 +1,348 @@
+use crate::alloc::format;
+use crate::alloc::vec::Vec;
+use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
+use crate::serialize::{ArraySerializer, Serialize, Serializer};
+use crate::{deserialize, Amount, Hash256, Object, UInt16, UInt32, UInt8};
+
+/// Metadata of a transaction in a ledger, describing the outcome of the transaction
+/// <https://xrpl.org/transaction-metadata.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TransactionMetaData {
+    pub affected_nodes: Vec<AffectedNode>,
+    pub transaction_index: UInt32,
+    /// Transaction result code, either `tesSUCCESS` (0) or a `tec` code <https://xrpl.org/tec-codes.html>
+    pub transaction_result: UInt8,
+    pub delivered_amount: Option<Amount>,
+}
+
+impl TransactionMetaData {
+    /// Returns true if the transaction result is `tesSUCCESS`
+    pub fn is_success(&self) -> bool {
+        self.transaction_result == 0
+    }
+}
+
+/// Ledger object created, modified or deleted by a transaction
+/// <https://xrpl.org/transaction-metadata.html#affectednodes>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum AffectedNode {
+    CreatedNode {
+        ledger_entry_type: UInt16,
+        ledger_index: Hash256,
+        new_fields: Option<Object>,
+    },
+    ModifiedNode {
+        ledger_entry_type: UInt16,
+        ledger_index: Hash256,
+        final_fields: Option<Object>,
+        previous_fields: Option<Object>,
+        previous_txn_id: Option<Hash256>,
+        previous_txn_lgr_seq: Option<UInt32>,
+    },
+    DeletedNode {
+        ledger_entry_type: UInt16,
+        ledger_index: Hash256,
+        final_fields: Option<Object>,
+        previous_fields: Option<Object>,
+    },
+}
+
+impl AffectedNode {
+    pub fn ledger_entry_type(&self) -> UInt16 {
+        match self {
+            AffectedNode::CreatedNode {
+                ledger_entry_type, ..
+            }
+            | AffectedNode::ModifiedNode {
+                ledger_entry_type, ..
+            }
+            | AffectedNode::DeletedNode {
+                ledger_entry_type, ..
+            } => *ledger_entry_type,
+        }
+    }
+
+    pub fn ledger_index(&self) -> Hash256 {
+        match self {
+            AffectedNode::CreatedNode { ledger_index, .. }
+            | AffectedNode::ModifiedNode { ledger_index, .. }
+            | AffectedNode::DeletedNode { ledger_index, .. } => *ledger_index,
+        }
+    }
+
+    fn field_name(&self) -> &'static str {
+        match self {
+            AffectedNode::CreatedNode { .. } => "CreatedNode",
+            AffectedNode::ModifiedNode { .. } => "ModifiedNode",
+            AffectedNode::DeletedNode { .. } => "DeletedNode",
+        }
+    }
+}
+
+impl Serialize for TransactionMetaData {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint32("TransactionIndex", self.transaction_index)?;
+        let mut array = s.serialize_array("AffectedNodes")?;
+        for affected_node in &self.affected_nodes {
+            array.serialize_object(affected_node.field_name(), affected_node)?;
+        }
+        array.end()?;
+        s.serialize_uint8("TransactionResult", self.transaction_result)?;
+        if let Some(delivered_amount) = self.delivered_amount {
+            s.serialize_amount("DeliveredAmount", delivered_amount)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for AffectedNode {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("LedgerEntryType", self.ledger_entry_type())?;
+        s.serialize_hash256("LedgerIndex", self.ledger_index())?;
+        match self {
+            AffectedNode::CreatedNode { new_fields, .. } => {
+                if let Some(new_fields) = new_fields {
+                    s.serialize_object("NewFields", new_fields)?;
+                }
+            }
+            AffectedNode::ModifiedNode {
+                final_fields,
+                previous_fields,
+                previous_txn_id,
+                previous_txn_lgr_seq,
+                ..
+            } => {
+                if let Some(final_fields) = final_fields {
+                    s.serialize_object("FinalFields", final_fields)?;
+                }
+                if let Some(previous_fields) = previous_fields {
+                    s.serialize_object("PreviousFields", previous_fields)?;
+                }
+                if let Some(previous_txn_id) = previous_txn_id {
+                    s.serialize_hash256("PreviousTxnID", *previous_txn_id)?;
+                }
+                if let Some(previous_txn_lgr_seq) = previous_txn_lgr_seq {
+                    s.serialize_uint32("PreviousTxnLgrSeq", *previous_txn_lgr_seq)?;
+                }
+            }
+            AffectedNode::DeletedNode {
+                final_fields,
+                previous_fields,
+                ..
+            } => {
+                if let Some(final_fields) = final_fields {
+                    s.serialize_object("FinalFields", final_fields)?;
+                }
+                if let Some(previous_fields) = previous_fields {
+                    s.serialize_object("PreviousFields", previous_fields)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Deserialize for TransactionMetaData {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            affected_nodes: Vec<AffectedNode>,
+            transaction_index: Option<UInt32>,
+            transaction_result: Option<UInt8>,
+            delivered_amount: Option<Amount>,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "TransactionIndex" => {
+                        self.transaction_index = Some(field_accessor.deserialize_uint32()?);
+                    }
+                    "TransactionResult" => {
+                        self.transaction_result = Some(field_accessor.deserialize_uint8()?);
+                    }
+                    "DeliveredAmount" => {
+                        self.delivered_amount = Some(field_accessor.deserialize_amount()?);
+                    }
+                    _ => return Err(E::unexpected_field(field_name)),
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                mut array_deserializer: AD,
+            ) -> Result<(), E> {
+                match field_name {
+                    "AffectedNodes" => {
+                        while let Some((node_name, node)) =
+                            array_deserializer.deserialize_any_object::<AffectedNodeVisitor>()?
+                        {
+                            self.affected_nodes
+                                .push(node.into_affected_node(&node_name)?);
+                        }
+                    }
+                    _ => return Err(E::unexpected_field(field_name)),
+                }
+                Ok(())
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(TransactionMetaData {
+            affected_nodes: visitor.affected_nodes,
+            transaction_index: S::Error::unwrap_field_value(
+                "TransactionIndex",
+                visitor.transaction_index,
+            )?,
+            transaction_result: S::Error::unwrap_field_value(
+                "TransactionResult",
+                visitor.transaction_result,
+            )?,
+            delivered_amount: visitor.delivered_amount,
+        })
+    }
+}
+
+/// Fields of any kind of affected node, since the kind is only known from the field name
+/// of the array element
+#[derive(Default)]
+struct AffectedNodeVisitor {
+    ledger_entry_type: Option<UInt16>,
+    ledger_index: Option<Hash256>,
+    new_fields: Option<Object>,
+    final_fields: Option<Object>,
+    previous_fields: Option<Object>,
+    previous_txn_id: Option<Hash256>,
+    previous_txn_lgr_seq: Option<UInt32>,
+}
+
+impl AffectedNodeVisitor {
+    fn into_affected_node<E: DeserError>(self, node_name: &str) -> Result<AffectedNode, E> {
+        let ledger_entry_type = || E::unwrap_field_value("LedgerEntryType", self.ledger_entry_type);
+        let ledger_index = || E::unwrap_field_value("LedgerIndex", self.ledger_index);
+        let unexpected = |field_name: &str, present: bool| {
+            if present {
+                Err(E::unexpected_field(field_name))
+            } else {
+                Ok(())
+            }
+        };
+        Ok(match node_name {
+            "CreatedNode" => {
+                unexpected("FinalFields", self.final_fields.is_some())?;
+                unexpected("PreviousFields", self.previous_fields.is_some())?;
+                unexpected("PreviousTxnID", self.previous_txn_id.is_some())?;
+                unexpected("PreviousTxnLgrSeq", self.previous_txn_lgr_seq.is_some())?;
+                AffectedNode::CreatedNode {
+                    ledger_entry_type: ledger_entry_type()?,
+                    ledger_index: ledger_index()?,
+                    new_fields: self.new_fields,
+                }
+            }
+            "ModifiedNode" => {
+                unexpected("NewFields", self.new_fields.is_some())?;
+                AffectedNode::ModifiedNode {
+                    ledger_entry_type: ledger_entry_type()?,
+                    ledger_index: ledger_index()?,
+                    final_fields: self.final_fields,
+                    previous_fields: self.previous_fields,
+                    previous_txn_id: self.previous_txn_id,
+                    previous_txn_lgr_seq: self.previous_txn_lgr_seq,
+                }
+            }
+            "DeletedNode" => {
+                unexpected("NewFields", self.new_fields.is_some())?;
+                unexpected("PreviousTxnID", self.previous_txn_id.is_some())?;
+                unexpected("PreviousTxnLgrSeq", self.previous_txn_lgr_seq.is_some())?;
+                AffectedNode::DeletedNode {
+                    ledger_entry_type: ledger_entry_type()?,
+                    ledger_index: ledger_index()?,
+                    final_fields: self.final_fields,
+                    previous_fields: self.previous_fields,
+                }
+            }
+            _ => {
+                return Err(E::invalid_value(format!(
+                    "Unknown affected node: {}",
+                    node_name
+                )))
+            }
+        })
+    }
+}
+
+impl deserialize::Visitor for AffectedNodeVisitor {
+    fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+        &mut self,
+        field_name: &str,
+        field_accessor: F,
+    ) -> Result<(), E> {
+        match field_name {
+            "LedgerEntryType" => {
+                self.ledger_entry_type = Some(field_accessor.deserialize_uint16()?);
+            }
+            "LedgerIndex" => {
+                self.ledger_index = Some(field_accessor.deserialize_hash256()?);
+            }
+            "PreviousTxnID" => {
+                self.previous_txn_id = Some(field_accessor.deserialize_hash256()?);
+            }
+            "PreviousTxnLgrSeq" => {
+                self.previous_txn_lgr_seq = Some(field_accessor.deserialize_uint32()?);
+            }
+            _ => return Err(E::unexpected_field(field_name)),
+        }
+        Ok(())
+    }
+
+    fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+        &mut self,
+        field_name: &str,
+        _array_deserializer: AD,
+    ) -> Result<(), E> {
+        Err(E::unexpected_field(field_name))
+    }
+
+    fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+        &mut self,
+        field_name: &str,
+        deserializer: D,
+    ) -> Result<(), E> {
+        match field_name {
+            "NewFields" => {
+                self.new_fields = Some(Object::deserialize(deserializer)?);
+            }
+            "FinalFields" => {
+                self.final_fields = Some(Object::deserialize(deserializer)?);
+            }
+            "PreviousFields" => {
+                self.previous_fields = Some(Object::deserialize(deserializer)?);
+            }
+            _ => return Err(E::unexpected_field(field_name)),
+        }
+        Ok(())
+    }
+}
+
+impl Deserialize for AffectedNodeVisitor {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        let mut visitor = AffectedNodeVisitor::default();
+        deserializer.deserialize(&mut visitor)?;
+        Ok(visitor)
+    }
+}
@@ -1,11 +1,12 @@
 mod common;
+mod metadata;
 mod variants;
 
 use crate::deserialize::FieldAccessor;
-use crate::deserialize::{DeserError, Deserialize, Deserializer};
-use crate::serialize::{SerError, Serialize, Serializer};
-use alloc::format;
+use crate::deserialize::{Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
 pub use common::*;
+pub use metadata::*;
 pub use variants::*;
 
 /// XRPL transaction
@@ -152,6 +153,8 @@ pub enum Transaction {
     SignerListSet(TransactionCommon),
     TicketCreate(TransactionCommon),
     TrustSet(TrustSetTransaction),
+    /// Transaction of a type that is not modelled
+    Unknown(UnknownTransaction),
 }
 
 impl TransactionTrait for Transaction {
@@ -181,6 +184,7 @@ impl TransactionTrait for Transaction {
             Transaction::SignerListSet(txn) => txn,
             Transaction::TicketCreate(txn) => txn,
             Transaction::TrustSet(txn) => &txn.common,
+            Transaction::Unknown(txn) => &txn.common,
         }
     }
 
@@ -210,6 +214,7 @@ impl TransactionTrait for Transaction {
             Transaction::SignerListSet(txn) => txn,
             Transaction::TicketCreate(txn) => txn,
             Transaction::TrustSet(txn) => &mut txn.common,
+            Transaction::Unknown(txn) => &mut txn.common,
         }
     }
 }
@@ -219,28 +224,94 @@ impl Serialize for Transaction {
         match self {
             Transaction::AccountDelete(txn) => txn.serialize(serializer),
             Transaction::AccountSet(txn) => txn.serialize(serializer),
+            Transaction::CheckCancel(common) => {
+                serialize_common(serializer, TransactionType::CheckCancel, common)
+            }
+            Transaction::CheckCash(common) => {
+                serialize_common(serializer, TransactionType::CheckCash, common)
+            }
+            Transaction::CheckCreate(common) => {
+                serialize_common(serializer, TransactionType::CheckCreate, common)
+            }
+            Transaction::DepositPreauth(common) => {
+                serialize_common(serializer, TransactionType::DepositPreauth, common)
+            }
+            Transaction::EscrowCancel(common) => {
+                serialize_common(serializer, TransactionType::EscrowCancel, common)
+            }
+            Transaction::EscrowCreate(common) => {
+                serialize_common(serializer, TransactionType::EscrowCreate, common)
+            }
+            Transaction::EscrowFinish(common) => {
+                serialize_common(serializer, TransactionType::EscrowFinish, common)
+            }
+            Transaction::NFTokenAcceptOffer(common) => {
+                serialize_common(serializer, TransactionType::NFTokenAcceptOffer, common)
+            }
+            Transaction::NFTokenBurn(common) => {
+                serialize_common(serializer, TransactionType::NFTokenBurn, common)
+            }
+            Transaction::NFTokenCancelOffer(common) => {
+                serialize_common(serializer, TransactionType::NFTokenCancelOffer, common)
+            }
+            Transaction::NFTokenCreateOffer(common) => {
+                serialize_common(serializer, TransactionType::NFTokenCreateOffer, common)
+            }
+            Transaction::NFTokenMint(common) => {
+                serialize_common(serializer, TransactionType::NFTokenMint, common)
+            }
             Transaction::OfferCancel(txn) => txn.serialize(serializer),
             Transaction::OfferCreate(txn) => txn.serialize(serializer),
             Transaction::Payment(txn) => txn.serialize(serializer),
+            Transaction::PaymentChannelClaim(common) => {
+                serialize_common(serializer, TransactionType::PaymentChannelClaim, common)
+            }
+            Transaction::PaymentChannelCreate(common) => {
+                serialize_common(serializer, TransactionType::PaymentChannelCreate, common)
+            }
+            Transaction::PaymentChannelFund(common) => {
+                serialize_common(serializer, TransactionType::PaymentChannelFund, common)
+            }
+            Transaction::SetRegularKey(common) => {
+                serialize_common(serializer, TransactionType::SetRegularKey, common)
+            }
+            Transaction::SignerListSet(common) => {
+                serialize_common(serializer, TransactionType::SignerListSet, common)
+            }
+            Transaction::TicketCreate(common) => {
+                serialize_common(serializer, TransactionType::TicketCreate, common)
+            }
             Transaction::TrustSet(txn) => txn.serialize(serializer),
-            _ => Err(S::Error::unimplemented(
-                "serialization not implemented for transaction type",
-            )),
+            Transaction::Unknown(txn) => txn.serialize(serializer),
         }
     }
 }
 
+/// Serializes transaction that is modelled by the common fields only. The fields specific to the
+/// transaction type are serialized from [`TransactionCommon::unknown_fields`]
+fn serialize_common<S: Serializer>(
+    serializer: &mut S,
+    transaction_type: TransactionType,
+    common: &TransactionCommon,
+) -> Result<(), S::Error> {
+    serializer.serialize_uint16("TransactionType", transaction_type as u16)?;
+    common.serialize(serializer)
+}
+
 impl Deserialize for Transaction {
     fn deserialize<S: Deserializer>(mut deserializer: S) -> Result<Self, S::Error>
     where
         Self: Sized,
     {
-        let txn_type = deserializer
+        let transaction_type = deserializer
             .deserialize_single_field("TransactionType")?
             .deserialize_uint16()?;
-        let txn_type = TransactionType::from_discriminant_opt(txn_type).ok_or_else(|| {
-            S::Error::invalid_value(format!("Unknown transaction type: {}", txn_type))
-        })?;
+        let Some(txn_type) = TransactionType::from_discriminant_opt(transaction_type) else {
+            return Ok(Self::Unknown(UnknownTransaction {
+                transaction_type,
+                common: TransactionCommon::deserialize(deserializer)?,
+            }));
+        };
         Ok(match txn_type {
             TransactionType::Payment => {
                 Self::Payment(PaymentTransaction::deserialize(deserializer)?)
@@ -314,12 +385,10 @@ impl Deserialize for Transaction {
             TransactionType::NFTokenAcceptOffer => {
                 Self::NFTokenAcceptOffer(TransactionCommon::deserialize(deserializer)?)
             }
-            _ => {
-                return Err(S::Error::invalid_value(format!(
-                    "Unknown transaction type: {:?}",
-                    txn_type
-                )))
-            }
+            _ => Self::Unknown(UnknownTransaction {
+                transaction_type,
+                common: TransactionCommon::deserialize(deserializer)?,
+            }),
         })
     }
 }
@@ -1,16 +1,17 @@
 use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
 use crate::serialize::{Serialize, Serializer};
 use crate::{
-    deserialize, AccountId, TransactionCommon, TransactionCommonVisitor, TransactionTrait,
+    deserialize, AccountId, Flags, TransactionCommon, TransactionCommonVisitor, TransactionTrait,
     TransactionType, UInt32,
 };
-use enumflags2::{bitflags, BitFlags};
+use enumflags2::bitflags;
 
 /// An `AccountDelete` transaction <https://xrpl.org/accountdelete.html>
 #[derive(Debug, Clone)]
 pub struct AccountDeleteTransaction {
     pub common: TransactionCommon,
-    pub flags: BitFlags<AccountDeleteFlags>,
+    /// Transaction flags, `None` if the transaction has no `Flags` field
+    pub flags: Option<Flags<AccountDeleteFlags>>,
     pub destination: AccountId,
     pub destination_tag: Option<UInt32>,
 }
@@ -19,7 +20,7 @@ impl AccountDeleteTransaction {
     pub fn new(account_id: AccountId, destination: AccountId) -> Self {
         Self {
             common: TransactionCommon::new(account_id),
-            flags: Default::default(),
+            flags: Some(Flags::default()),
             destination,
             destination_tag: None,
         }
@@ -47,7 +48,9 @@ impl Serialize for AccountDeleteTransaction {
     fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
         s.serialize_uint16("TransactionType", TransactionType::AccountDelete as u16)?;
         self.common.serialize(s)?;
-        s.serialize_uint32("Flags", self.flags.bits())?;
+        if let Some(flags) = self.flags {
+            s.serialize_uint32("Flags", flags.bits())?;
+        }
         s.serialize_account_id("Destination", self.destination)?;
         if let Some(destination_tag) = self.destination_tag {
             s.serialize_uint32("DestinationTag", destination_tag)?;
@@ -64,7 +67,7 @@ impl Deserialize for AccountDeleteTransaction {
         #[derive(Default)]
         struct Visitor {
             common: TransactionCommonVisitor,
-            flags: BitFlags<AccountDeleteFlags>,
+            flags: Option<Flags<AccountDeleteFlags>>,
             destination: Option<AccountId>,
             destination_tag: Option<UInt32>,
         }
@@ -84,8 +87,7 @@ impl Deserialize for AccountDeleteTransaction {
                         }
                     }
                     "Flags" => {
-                        self.flags = BitFlags::from_bits(field_accessor.deserialize_uint32()?)
-                            .map_err(E::invalid_value)?;
+                        self.flags = Some(Flags::from_bits(field_accessor.deserialize_uint32()?));
                     }
                     "Destination" => {
                         self.destination = Some(field_accessor.deserialize_account_id()?);
@@ -107,6 +109,14 @@ impl Deserialize for AccountDeleteTransaction {
             ) -> Result<(), E> {
                 self.common.visit_array(field_name, array_deserializer)
             }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.common.visit_object(field_name, deserializer)
+            }
         }
 
         let mut visitor = Visitor::default();
@@ -1,17 +1,18 @@
 use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
 use crate::serialize::{Serialize, Serializer};
 use crate::{
-    deserialize, AccountId, Blob, Hash128, Hash256, TransactionCommon, TransactionCommonVisitor,
-    TransactionTrait, TransactionType, UInt32, UInt8,
+    deserialize, AccountId, Blob, Flags, Hash128, Hash256, TransactionCommon,
+    TransactionCommonVisitor, TransactionTrait, TransactionType, UInt32, UInt8,
 };
 use alloc::format;
-use enumflags2::{bitflags, BitFlags};
+use enumflags2::bitflags;
 
 /// An `AccountSet` transaction <https://xrpl.org/accountset.html>
 #[derive(Debug, Clone)]
 pub struct AccountSetTransaction {
     pub common: TransactionCommon,
-    pub flags: BitFlags<AccountSetTransactionFlags>,
+    /// Transaction flags, `None` if the transaction has no `Flags` field
+    pub flags: Option<Flags<AccountSetTransactionFlags>>,
     pub clear_flag: Option<AccountSetFlag>,
     pub domain: Option<Blob>,
     pub email_hash: Option<Hash128>,
@@ -28,7 +29,7 @@ impl AccountSetTransaction {
     pub fn new(account_id: AccountId) -> Self {
         Self {
             common: TransactionCommon::new(account_id),
-            flags: Default::default(),
+            flags: Some(Flags::default()),
             clear_flag: None,
             domain: None,
             email_hash: None,
@@ -115,7 +116,9 @@ impl Serialize for AccountSetTransaction {
     fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
         s.serialize_uint16("TransactionType", TransactionType::AccountSet as u16)?;
         self.common.serialize(s)?;
-        s.serialize_uint32("Flags", self.flags.bits())?;
+        if let Some(flags) = self.flags {
+            s.serialize_uint32("Flags", flags.bits())?;
+        }
         if let Some(clear_flag) = self.clear_flag {
             s.serialize_uint32("ClearFlag", clear_flag as UInt32)?;
         }
@@ -158,7 +161,7 @@ impl Deserialize for AccountSetTransaction {
         #[derive(Default)]
         struct Visitor {
             common: TransactionCommonVisitor,
-            flags: BitFlags<AccountSetTransactionFlags>,
+            flags: Option<Flags<AccountSetTransactionFlags>>,
             clear_flag: Option<AccountSetFlag>,
             domain: Option<Blob>,
             email_hash: Option<Hash128>,
@@ -186,8 +189,7 @@ impl Deserialize for AccountSetTransaction {
                         }
                     }
                     "Flags" => {
-                        self.flags = BitFlags::from_bits(field_accessor.deserialize_uint32()?)
-                            .map_err(E::invalid_value)?;
+                        self.flags = Some(Flags::from_bits(field_accessor.deserialize_uint32()?));
                     }
                     "ClearFlag" => {
                         let clear_flag = field_accessor.deserialize_uint32()?;
@@ -246,6 +248,14 @@ impl Deserialize for AccountSetTransaction {
             ) -> Result<(), E> {
                 self.common.visit_array(field_name, array_deserializer)
             }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.common.visit_object(field_name, deserializer)
+            }
         }
 
         let mut visitor = Visitor::default();
@@ -4,6 +4,7 @@ mod offer_cancel;
 mod offer_create;
 mod payment;
 mod trust_set;
+mod unknown;
 
 pub use account_delete::*;
 pub use account_set::*;
@@ -11,3 +12,4 @@ pub use offer_cancel::*;
 pub use offer_create::*;
 pub use payment::*;
 pub use trust_set::*;
+pub use unknown::*;
@@ -1,16 +1,17 @@
 use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
-use crate::serialize::{Serialize, Serializer};
+use crate::serialize::{CanonicalSerialize, Serialize, Serializer};
 use crate::{
-    deserialize, AccountId, TransactionCommon, TransactionCommonVisitor, TransactionTrait,
-    TransactionType, UInt32,
+    deserialize, AccountId, CommonField, Flags, TransactionCommon, TransactionCommonVisitor,
+    TransactionTrait, TransactionType, UInt32,
 };
-use enumflags2::{bitflags, BitFlags};
+use enumflags2::bitflags;
 
 /// An `OfferCancel` transaction <https://xrpl.org/offercancel.html>
 #[derive(Debug, Clone)]
 pub struct OfferCancelTransaction {
     pub common: TransactionCommon,
-    pub flags: BitFlags<OfferCancelFlags>,
+    /// Transaction flags, `None` if the transaction has no `Flags` field
+    pub flags: Option<Flags<OfferCancelFlags>>,
     pub offer_sequence: UInt32,
 }
 
@@ -18,7 +19,7 @@ impl OfferCancelTransaction {
     pub fn new(account_id: AccountId, offer_sequence: UInt32) -> Self {
         Self {
             common: TransactionCommon::new(account_id),
-            flags: Default::default(),
+            flags: Some(Flags::default()),
             offer_sequence,
         }
     }
@@ -42,15 +43,21 @@ pub enum OfferCancelFlags {
 }
 
 impl Serialize for OfferCancelTransaction {
+    /// Serializes the fields in canonical field order, interleaving the common fields
     fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
-        s.serialize_uint16("TransactionType", TransactionType::OfferCancel as u16)?;
-        self.common.serialize(s)?;
-        s.serialize_uint32("Flags", self.flags.bits())?;
-        s.serialize_uint32("OfferSequence", self.offer_sequence)?;
-        Ok(())
+        let flags = self.flags.map(|flags| flags.bits());
+        self.common
+            .serialize_canonical(s, TransactionType::OfferCancel, flags, |s, next| {
+                if next == Some(CommonField::LastLedgerSequence) {
+                    s.serialize_uint32("OfferSequence", self.offer_sequence)?;
+                }
+                Ok(())
+            })
     }
 }
 
+impl CanonicalSerialize for OfferCancelTransaction {}
+
 impl Deserialize for OfferCancelTransaction {
     fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
     where
@@ -59,7 +66,7 @@ impl Deserialize for OfferCancelTransaction {
         #[derive(Default)]
         struct Visitor {
             common: TransactionCommonVisitor,
-            flags: BitFlags<OfferCancelFlags>,
+            flags: Option<Flags<OfferCancelFlags>>,
             offer_sequence: Option<UInt32>,
         }
 
@@ -78,8 +85,7 @@ impl Deserialize for OfferCancelTransaction {
                         }
                     }
                     "Flags" => {
-                        self.flags = BitFlags::from_bits(field_accessor.deserialize_uint32()?)
-                            .map_err(E::invalid_value)?;
+                        self.flags = Some(Flags::from_bits(field_accessor.deserialize_uint32()?));
                     }
                     "OfferSequence" => {
                         self.offer_sequence = Some(field_accessor.deserialize_uint32()?);
@@ -98,6 +104,14 @@ impl Deserialize for OfferCancelTransaction {
             ) -> Result<(), E> {
                 self.common.visit_array(field_name, array_deserializer)
             }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.common.visit_object(field_name, deserializer)
+            }
         }
 
         let mut visitor = Visitor::default();
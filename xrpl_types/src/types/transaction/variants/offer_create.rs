@@ -1,16 +1,17 @@
 use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
-use crate::serialize::{Serialize, Serializer};
+use crate::serialize::{CanonicalSerialize, Serialize, Serializer};
 use crate::{
-    deserialize, AccountId, Amount, TransactionCommon, TransactionCommonVisitor, TransactionTrait,
-    TransactionType, UInt32,
+    deserialize, AccountId, Amount, CommonField, Flags, TransactionCommon,
+    TransactionCommonVisitor, TransactionTrait, TransactionType, UInt32,
 };
-use enumflags2::{bitflags, BitFlags};
+use enumflags2::bitflags;
 
 /// An `OfferCreate` transaction <https://xrpl.org/offercreate.html>
 #[derive(Debug, Clone)]
 pub struct OfferCreateTransaction {
     pub common: TransactionCommon,
-    pub flags: BitFlags<OfferCreateFlags>,
+    /// Transaction flags, `None` if the transaction has no `Flags` field
+    pub flags: Option<Flags<OfferCreateFlags>>,
     pub expiration: Option<UInt32>,
     pub offer_sequence: Option<UInt32>,
     pub taker_gets: Amount,
@@ -21,7 +22,7 @@ impl OfferCreateTransaction {
     pub fn new(account_id: AccountId, taker_gets: Amount, taker_pays: Amount) -> Self {
         Self {
             common: TransactionCommon::new(account_id),
-            flags: Default::default(),
+            flags: Some(Flags::default()),
             expiration: None,
             offer_sequence: None,
             taker_gets,
@@ -53,22 +54,33 @@ pub enum OfferCreateFlags {
 }
 
 impl Serialize for OfferCreateTransaction {
+    /// Serializes the fields in canonical field order, interleaving the common fields
     fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
-        s.serialize_uint16("TransactionType", TransactionType::OfferCreate as u16)?;
-        self.common.serialize(s)?;
-        s.serialize_uint32("Flags", self.flags.bits())?;
-        if let Some(expiration) = self.expiration {
-            s.serialize_uint32("Expiration", expiration)?;
-        }
-        if let Some(offer_sequence) = self.offer_sequence {
-            s.serialize_uint32("OfferSequence", offer_sequence)?;
-        }
-        s.serialize_amount("TakerPays", self.taker_pays)?;
-        s.serialize_amount("TakerGets", self.taker_gets)?;
-        Ok(())
+        let flags = self.flags.map(|flags| flags.bits());
+        self.common
+            .serialize_canonical(s, TransactionType::OfferCreate, flags, |s, next| {
+                match next {
+                    Some(CommonField::LastLedgerSequence) => {
+                        if let Some(expiration) = self.expiration {
+                            s.serialize_uint32("Expiration", expiration)?;
+                        }
+                        if let Some(offer_sequence) = self.offer_sequence {
+                            s.serialize_uint32("OfferSequence", offer_sequence)?;
+                        }
+                    }
+                    Some(CommonField::Fee) => {
+                        s.serialize_amount("TakerPays", self.taker_pays)?;
+                        s.serialize_amount("TakerGets", self.taker_gets)?;
+                    }
+                    _ => {}
+                }
+                Ok(())
+            })
     }
 }
 
+impl CanonicalSerialize for OfferCreateTransaction {}
+
 impl Deserialize for OfferCreateTransaction {
     fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
     where
@@ -77,7 +89,7 @@ impl Deserialize for OfferCreateTransaction {
         #[derive(Default)]
         struct Visitor {
             common: TransactionCommonVisitor,
-            flags: BitFlags<OfferCreateFlags>,
+            flags: Option<Flags<OfferCreateFlags>>,
             expiration: Option<UInt32>,
             offer_sequence: Option<UInt32>,
             taker_gets: Option<Amount>,
@@ -99,8 +111,7 @@ impl Deserialize for OfferCreateTransaction {
                         }
                     }
                     "Flags" => {
-                        self.flags = BitFlags::from_bits(field_accessor.deserialize_uint32()?)
-                            .map_err(E::invalid_value)?;
+                        self.flags = Some(Flags::from_bits(field_accessor.deserialize_uint32()?));
                     }
                     "Expiration" => {
                         self.expiration = Some(field_accessor.deserialize_uint32()?);
@@ -128,6 +139,14 @@ impl Deserialize for OfferCreateTransaction {
             ) -> Result<(), E> {
                 self.common.visit_array(field_name, array_deserializer)
             }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.common.visit_object(field_name, deserializer)
+            }
         }
 
         let mut visitor = Visitor::default();
@@ -1,37 +1,58 @@
 use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
 use crate::serialize::{Serialize, Serializer};
 use crate::{
-    deserialize, AccountId, Amount, Hash256, TransactionCommon, TransactionCommonVisitor,
-    TransactionTrait, TransactionType, UInt32,
+    deserialize, AccountId, Amount, Error, Flags, Hash256, PathSet, TransactionCommon,
+    TransactionCommonVisitor, TransactionTrait, TransactionType, UInt32,
 };
-use enumflags2::{bitflags, BitFlags};
+use enumflags2::bitflags;
 
 /// An `Payment` transaction <https://xrpl.org/payment.html>
 #[derive(Debug, Clone)]
 pub struct PaymentTransaction {
     pub common: TransactionCommon,
-    pub flags: BitFlags<PaymentFlags>,
+    /// Transaction flags, `None` if the transaction has no `Flags` field
+    pub flags: Option<Flags<PaymentFlags>>,
     pub amount: Amount,
     pub destination: AccountId,
     pub destination_tag: Option<UInt32>,
     pub invoice_id: Option<Hash256>,
     pub send_max: Option<Amount>,
     pub deliver_min: Option<Amount>,
+    pub paths: Option<PathSet>,
 }
 
 impl PaymentTransaction {
     pub fn new(account_id: AccountId, amount: Amount, destination: AccountId) -> Self {
         Self {
             common: TransactionCommon::new(account_id),
-            flags: Default::default(),
+            flags: Some(Flags::default()),
             amount,
             destination,
             destination_tag: None,
             invoice_id: None,
             send_max: None,
             deliver_min: None,
+            paths: None,
         }
     }
+
+    /// Creates payment to the given destination, which can be either a classic address or an
+    /// X-address, see <https://xrpl.org/addresses.html#x-addresses>. The destination tag is taken
+    /// from the X-address or from `destination_tag`. An error is returned if both are given and
+    /// differ, or if the X-address is not for the network given by `is_test_network`.
+    pub fn new_to_address(
+        account_id: AccountId,
+        amount: Amount,
+        destination: &str,
+        destination_tag: Option<UInt32>,
+        is_test_network: bool,
+    ) -> Result<Self, Error> {
+        let (destination, destination_tag) =
+            AccountId::from_address_and_tag(destination, destination_tag, is_test_network)?;
+        let mut payment = Self::new(account_id, amount, destination);
+        payment.destination_tag = destination_tag;
+        Ok(payment)
+    }
 }
 
 impl TransactionTrait for PaymentTransaction {
@@ -59,7 +80,9 @@ impl Serialize for PaymentTransaction {
     fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
         s.serialize_uint16("TransactionType", TransactionType::Payment as u16)?;
         self.common.serialize(s)?;
-        s.serialize_uint32("Flags", self.flags.bits())?;
+        if let Some(flags) = self.flags {
+            s.serialize_uint32("Flags", flags.bits())?;
+        }
         s.serialize_amount("Amount", self.amount)?;
         s.serialize_account_id("Destination", self.destination)?;
         if let Some(destination_tag) = self.destination_tag {
@@ -74,6 +97,9 @@ impl Serialize for PaymentTransaction {
         if let Some(deliver_min) = self.deliver_min {
             s.serialize_amount("DeliverMin", deliver_min)?;
         }
+        if let Some(paths) = self.paths.as_ref() {
+            s.serialize_path_set("Paths", paths)?;
+        }
         Ok(())
     }
 }
@@ -86,13 +112,14 @@ impl Deserialize for PaymentTransaction {
         #[derive(Default)]
         struct Visitor {
             common: TransactionCommonVisitor,
-            flags: BitFlags<PaymentFlags>,
+            flags: Option<Flags<PaymentFlags>>,
             amount: Option<Amount>,
             destination: Option<AccountId>,
             destination_tag: Option<UInt32>,
             invoice_id: Option<Hash256>,
             send_max: Option<Amount>,
             deliver_min: Option<Amount>,
+            paths: Option<PathSet>,
         }
 
         impl deserialize::Visitor for Visitor {
@@ -108,8 +135,7 @@ impl Deserialize for PaymentTransaction {
                         }
                     }
                     "Flags" => {
-                        self.flags = BitFlags::from_bits(field_accessor.deserialize_uint32()?)
-                            .map_err(E::invalid_value)?;
+                        self.flags = Some(Flags::from_bits(field_accessor.deserialize_uint32()?));
                     }
                     "Amount" => {
                         self.amount = Some(field_accessor.deserialize_amount()?);
@@ -129,6 +155,9 @@ impl Deserialize for PaymentTransaction {
                     "DeliverMin" => {
                         self.deliver_min = Some(field_accessor.deserialize_amount()?);
                     }
+                    "Paths" => {
+                        self.paths = Some(field_accessor.deserialize_path_set()?);
+                    }
                     _ => {
                         self.common.visit_field(field_name, field_accessor)?;
                     }
@@ -143,6 +172,14 @@ impl Deserialize for PaymentTransaction {
             ) -> Result<(), E> {
                 self.common.visit_array(field_name, array_deserializer)
             }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.common.visit_object(field_name, deserializer)
+            }
         }
 
         let mut visitor = Visitor::default();
@@ -158,6 +195,7 @@ impl Deserialize for PaymentTransaction {
             invoice_id: visitor.invoice_id,
             send_max: visitor.send_max,
             deliver_min: visitor.deliver_min,
+            paths: visitor.paths,
         })
     }
 }
@@ -1,16 +1,17 @@
 use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
 use crate::serialize::{Serialize, Serializer};
 use crate::{
-    deserialize, AccountId, Amount, IssuedAmount, TransactionCommon, TransactionCommonVisitor,
-    TransactionTrait, TransactionType, UInt32,
+    deserialize, AccountId, Amount, Flags, IssuedAmount, TransactionCommon,
+    TransactionCommonVisitor, TransactionTrait, TransactionType, UInt32,
 };
-use enumflags2::{bitflags, BitFlags};
+use enumflags2::bitflags;
 
 /// A `TrustSet` transaction <https://xrpl.org/trustset.html>
 #[derive(Debug, Clone)]
 pub struct TrustSetTransaction {
     pub common: TransactionCommon,
-    pub flags: BitFlags<TrustSetFlags>,
+    /// Transaction flags, `None` if the transaction has no `Flags` field
+    pub flags: Option<Flags<TrustSetFlags>>,
     pub limit_amount: IssuedAmount,
     pub quality_in: Option<UInt32>,
     pub quality_out: Option<UInt32>,
@@ -20,7 +21,7 @@ impl TrustSetTransaction {
     pub fn new(account_id: AccountId, limit_amount: IssuedAmount) -> Self {
         Self {
             common: TransactionCommon::new(account_id),
-            flags: Default::default(),
+            flags: Some(Flags::default()),
             limit_amount,
             quality_in: None,
             quality_out: None,
@@ -55,7 +56,9 @@ impl Serialize for TrustSetTransaction {
     fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
         s.serialize_uint16("TransactionType", TransactionType::TrustSet as u16)?;
         self.common.serialize(s)?;
-        s.serialize_uint32("Flags", self.flags.bits())?;
+        if let Some(flags) = self.flags {
+            s.serialize_uint32("Flags", flags.bits())?;
+        }
         s.serialize_amount("LimitAmount", Amount::Issued(self.limit_amount))?;
         if let Some(quality_in) = self.quality_in {
             s.serialize_uint32("QualityIn", quality_in)?;
@@ -75,7 +78,7 @@ impl Deserialize for TrustSetTransaction {
         #[derive(Default)]
         struct Visitor {
             common: TransactionCommonVisitor,
-            flags: BitFlags<TrustSetFlags>,
+            flags: Option<Flags<TrustSetFlags>>,
             limit_amount: Option<IssuedAmount>,
             quality_in: Option<UInt32>,
             quality_out: Option<UInt32>,
@@ -95,8 +98,7 @@ impl Deserialize for TrustSetTransaction {
                         }
                     }
                     "Flags" => {
-                        self.flags = BitFlags::from_bits(field_accessor.deserialize_uint32()?)
-                            .map_err(E::invalid_value)?;
+                        self.flags = Some(Flags::from_bits(field_accessor.deserialize_uint32()?));
                     }
                     "LimitAmount" => {
                         self.limit_amount = Some(match field_accessor.deserialize_amount()? {
@@ -126,6 +128,14 @@ impl Deserialize for TrustSetTransaction {
             ) -> Result<(), E> {
                 self.common.visit_array(field_name, array_deserializer)
             }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.common.visit_object(field_name, deserializer)
+            }
         }
 
         let mut visitor = Visitor::default();
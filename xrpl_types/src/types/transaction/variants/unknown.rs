@@ -0,0 +1,107 @@
+use crate::deserialize::{ArrayDeserializer, DeserError, Deserialize, Deserializer, FieldAccessor};
+use crate::serialize::{Serialize, Serializer};
+use crate::{
+    deserialize, AccountId, TransactionCommon, TransactionCommonVisitor, TransactionTrait,
+    TransactionType, UInt16,
+};
+
+/// Transaction of a type that is not modelled, or of a transaction type that is not known
+/// <https://xrpl.org/transaction-types.html>. The common fields are modelled, and all other
+/// fields are kept as [`TransactionCommon::unknown_fields`]
+#[derive(Debug, Clone)]
+pub struct UnknownTransaction {
+    /// Value of the `TransactionType` field
+    pub transaction_type: UInt16,
+    pub common: TransactionCommon,
+}
+
+impl UnknownTransaction {
+    pub fn new(account_id: AccountId, transaction_type: UInt16) -> Self {
+        Self {
+            transaction_type,
+            common: TransactionCommon::new(account_id),
+        }
+    }
+
+    /// Transaction type, if it is known
+    pub fn transaction_type(&self) -> Option<TransactionType> {
+        TransactionType::from_discriminant_opt(self.transaction_type)
+    }
+}
+
+impl TransactionTrait for UnknownTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+impl Serialize for UnknownTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("TransactionType", self.transaction_type)?;
+        self.common.serialize(s)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for UnknownTransaction {
+    fn deserialize<S: Deserializer>(deserializer: S) -> Result<Self, S::Error>
+    where
+        Self: Sized,
+    {
+        #[derive(Default)]
+        struct Visitor {
+            transaction_type: Option<UInt16>,
+            common: TransactionCommonVisitor,
+        }
+
+        impl deserialize::Visitor for Visitor {
+            fn visit_field<E: DeserError, F: FieldAccessor<Error = E>>(
+                &mut self,
+                field_name: &str,
+                field_accessor: F,
+            ) -> Result<(), E> {
+                match field_name {
+                    "TransactionType" => {
+                        self.transaction_type = Some(field_accessor.deserialize_uint16()?);
+                    }
+                    _ => {
+                        self.common.visit_field(field_name, field_accessor)?;
+                    }
+                }
+                Ok(())
+            }
+
+            fn visit_array<E: DeserError, AD: ArrayDeserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                array_deserializer: AD,
+            ) -> Result<(), E> {
+                self.common.visit_array(field_name, array_deserializer)
+            }
+
+            fn visit_object<E: DeserError, D: Deserializer<Error = E>>(
+                &mut self,
+                field_name: &str,
+                deserializer: D,
+            ) -> Result<(), E> {
+                self.common.visit_object(field_name, deserializer)
+            }
+        }
+
+        let mut visitor = Visitor::default();
+
+        deserializer.deserialize(&mut visitor)?;
+
+        Ok(UnknownTransaction {
+            transaction_type: S::Error::unwrap_field_value(
+                "TransactionType",
+                visitor.transaction_type,
+            )?,
+            common: visitor.common.into_transaction_common()?,
+        })
+    }
+}
@@ -0,0 +1,15 @@
+use crate::{AccountId, Currency};
+
+/// Cross-chain bridge between a locking chain and an issuing chain <https://xrpl.org/xchainbridge.html>
+/// and <https://xrpl.org/serialization.html#xchainbridge-fields>
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct XChainBridge {
+    /// Door account on the locking chain
+    pub locking_chain_door: AccountId,
+    /// Asset that is locked and unlocked on the locking chain
+    pub locking_chain_issue: Currency,
+    /// Door account on the issuing chain
+    pub issuing_chain_door: AccountId,
+    /// Asset that is minted and burned on the issuing chain
+    pub issuing_chain_issue: Currency,
+}